@@ -20,6 +20,7 @@ fn main() {
         progress_percent: Some(60),
         origin_airport: Some("NRT".to_string()),
         destination_airport: Some("HND".to_string()),
+        ..Default::default()
     };
 
     println!("Test 1: Flight arriving in 45 minutes");
@@ -45,6 +46,7 @@ fn main() {
         progress_percent: Some(85),
         origin_airport: Some("SFO".to_string()),
         destination_airport: Some("LAX".to_string()),
+        ..Default::default()
     };
 
     println!("Test 2: Flight arriving in 20 minutes");
@@ -73,6 +75,7 @@ fn main() {
         progress_percent: Some(100),
         origin_airport: Some("JFK".to_string()),
         destination_airport: Some("ORD".to_string()),
+        ..Default::default()
     };
 
     println!("Test 3: Flight already arrived");
@@ -25,6 +25,7 @@ fn main() {
         progress_percent: Some(90),
         origin_airport: Some("SFO".to_string()),
         destination_airport: Some("LAX".to_string()),
+        ..Default::default()
     };
 
     println!(
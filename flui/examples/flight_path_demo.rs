@@ -37,6 +37,7 @@ fn main() {
             progress_percent: Some(progress),
             origin_airport: Some("NRT".to_string()),
             destination_airport: Some("HND".to_string()),
+            ..Default::default()
         };
 
         // Simulate the flight path rendering
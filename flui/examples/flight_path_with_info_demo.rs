@@ -21,6 +21,7 @@ fn main() {
         progress_percent: Some(55),
         origin_airport: Some("NRT".to_string()),
         destination_airport: Some("HND".to_string()),
+        ..Default::default()
     };
 
     println!("┌────────────────── Flight Progress ──────────────────┐");
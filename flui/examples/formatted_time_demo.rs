@@ -16,6 +16,7 @@ fn main() {
         progress_percent: Some(45),
         origin_airport: Some("JFK".to_string()),
         destination_airport: Some("LAX".to_string()),
+        ..Default::default()
     };
 
     println!("Flight: {}", flight.flight_number);
@@ -24,9 +25,14 @@ fn main() {
     println!("Raw arrival time:       {:?}", flight.arrival_time());
     println!(
         "Formatted arrival time: {:?}",
-        flight.formatted_arrival_time()
+        flight.formatted_arrival_time(None)
+    );
+    println!(
+        "In Tokyo:               {:?}",
+        flight.formatted_arrival_time(Some(chrono_tz::Asia::Tokyo))
     );
     println!();
-    println!("The formatted time is converted to your system's local timezone");
-    println!("and displayed in a human-readable format!");
+    println!("The formatted time is converted to your system's local timezone, or the one");
+    println!("given, and displayed in a human-readable format, with Today or Tomorrow in");
+    println!("place of the date when it's near!");
 }
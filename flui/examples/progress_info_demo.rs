@@ -21,6 +21,7 @@ fn main() {
         progress_percent: Some(45),
         origin_airport: Some("NRT".to_string()),
         destination_airport: Some("HND".to_string()),
+        ..Default::default()
     };
 
     println!("Flight: {}", flight.flight_number);
@@ -55,6 +56,7 @@ fn main() {
         progress_percent: Some(100),
         origin_airport: Some("SFO".to_string()),
         destination_airport: Some("LAX".to_string()),
+        ..Default::default()
     };
 
     println!("Progress: {:.0}%", arrived_flight.progress_percentage());
@@ -15,6 +15,7 @@ fn main() {
         actual_departure: Some("2025-11-16T10:02:00Z".to_string()),
         actual_arrival: None,
         progress_percent: Some(45),
+        ..Default::default()
     };
 
     println!("Flight: {}", flight1.flight_number);
@@ -39,6 +40,7 @@ fn main() {
         progress_percent: Some(0),
         origin_airport: Some("JFK".to_string()),
         destination_airport: Some("LAX".to_string()),
+        ..Default::default()
     };
 
     println!("Flight: {}", flight2.flight_number);
@@ -60,6 +62,7 @@ fn main() {
         actual_departure: None,
         actual_arrival: None,
         progress_percent: None,
+        ..Default::default()
     };
 
     println!("Flight: {}", flight3.flight_number);
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Names of major airlines bundled with the binary, used when no --airlines-file is given
+const EMBEDDED_AIRLINES: &str = include_str!("../data/airline_names.txt");
+
+/// Built once, on the first lookup or when an airlines file is loaded at startup
+static AIRLINE_NAMES: OnceLock<HashMap<String, String>> = OnceLock::new();
+
+/// Parse the airlines file format: an IATA or ICAO airline code, whitespace, then the airline's
+/// name to the end of the line; blank lines and anything after a `#` are ignored
+pub fn parse_airlines(contents: &str) -> Result<HashMap<String, String>, String> {
+    let mut airlines = HashMap::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let Some((code, name)) = line.split_once(char::is_whitespace) else {
+            return Err(format!(
+                "line {}: expected an airline code and a name",
+                index + 1
+            ));
+        };
+        airlines.insert(code.to_uppercase(), name.trim().to_string());
+    }
+    Ok(airlines)
+}
+
+fn embedded_airlines() -> HashMap<String, String> {
+    parse_airlines(EMBEDDED_AIRLINES).expect("embedded airline names are valid")
+}
+
+/// Load airline names from a file on top of the embedded set, so entries in the file add
+/// airlines or override the bundled names
+/// Must be called before the first lookup
+pub fn load_airlines_file(path: &Path) -> Result<(), String> {
+    let contents = std::fs::read_to_string(path).map_err(|error| error.to_string())?;
+    let mut airlines = embedded_airlines();
+    airlines.extend(parse_airlines(&contents)?);
+
+    AIRLINE_NAMES
+        .set(airlines)
+        .map_err(|_| "airline names were already loaded".to_string())
+}
+
+/// The name of an airline by IATA or ICAO code, if it's known
+pub fn airline_name(code: &str) -> Option<&'static str> {
+    AIRLINE_NAMES
+        .get_or_init(embedded_airlines)
+        .get(&code.to_uppercase())
+        .map(String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_airline_name_for_known_carriers() {
+        assert_eq!(airline_name("AA"), Some("American Airlines"));
+        assert_eq!(airline_name("AAL"), Some("American Airlines"));
+        assert_eq!(airline_name("baw"), Some("British Airways"));
+        assert_eq!(airline_name("B6"), Some("JetBlue"));
+    }
+
+    #[test]
+    fn test_airline_name_for_unknown_code() {
+        assert_eq!(airline_name("ZZZ"), None);
+        assert_eq!(airline_name(""), None);
+    }
+
+    #[test]
+    fn test_parse_airlines_skips_blanks_and_comments() {
+        let contents = "\
+# Regional carriers
+qx Horizon Air
+
+  OO  SkyWest Airlines # United Express
+";
+
+        let airlines = parse_airlines(contents).unwrap();
+        assert_eq!(airlines.len(), 2);
+        assert_eq!(airlines["QX"], "Horizon Air");
+        assert_eq!(airlines["OO"], "SkyWest Airlines");
+    }
+
+    #[test]
+    fn test_parse_airlines_rejects_code_without_name() {
+        assert_eq!(
+            parse_airlines("AA American Airlines\nUA"),
+            Err("line 2: expected an airline code and a name".to_string())
+        );
+    }
+}
@@ -1,10 +1,10 @@
-use crate::flight_status::{FlightStatus, FlightStatusViewModel};
+use crate::flight_status::{
+    ArrivalDefinition, DepartureReference, FlightStatus, FlightStatusViewModel,
+};
 use chrono::{DateTime, Utc};
 
 impl From<&flightaware::types::BaseFlight> for FlightStatusViewModel {
     fn from(flight: &flightaware::types::BaseFlight) -> Self {
-        let status = determine_flight_status_base(flight);
-
         // Extract airport codes (prefer IATA, fallback to ICAO)
         let origin_airport = flight
             .origin
@@ -16,27 +16,61 @@ impl From<&flightaware::types::BaseFlight> for FlightStatusViewModel {
             .as_ref()
             .and_then(|d| d.code_iata.clone().or_else(|| d.code_icao.clone()));
 
-        FlightStatusViewModel {
+        let mut view_model = FlightStatusViewModel {
             flight_number: flight.ident.clone(),
-            status,
+            fa_flight_id: Some(flight.fa_flight_id.clone()),
+            ident_iata: flight.ident_iata.clone(),
+            ident_icao: flight.ident_icao.clone(),
+            atc_ident: distinct_atc_ident(&flight.ident, flight.atc_ident.as_deref()),
+            // Settled below, once the view model can tell whether the flight has arrived
+            status: FlightStatus::default(),
             scheduled_departure: datetime_to_string(flight.scheduled_off.as_ref()),
             scheduled_arrival: datetime_to_string(flight.scheduled_on.as_ref()),
             estimated_departure: datetime_to_string(flight.estimated_off.as_ref()),
             estimated_arrival: datetime_to_string(flight.estimated_on.as_ref()),
             actual_departure: datetime_to_string(flight.actual_off.as_ref()),
             actual_arrival: datetime_to_string(flight.actual_on.as_ref()),
+            scheduled_gate_departure: datetime_to_string(flight.scheduled_out.as_ref()),
+            scheduled_gate_arrival: datetime_to_string(flight.scheduled_in.as_ref()),
+            estimated_gate_departure: datetime_to_string(flight.estimated_out.as_ref()),
+            estimated_gate_arrival: datetime_to_string(flight.estimated_in.as_ref()),
+            actual_gate_departure: datetime_to_string(flight.actual_out.as_ref()),
+            actual_gate_arrival: datetime_to_string(flight.actual_in.as_ref()),
+            departure_delay: flight.departure_delay,
+            arrival_delay: flight.arrival_delay,
+            // Only the /flights response carries the Foresight flag
+            foresight_predictions_available: false,
+            baggage_claim: flight.baggage_claim.clone(),
+            gate_origin: flight.gate_origin.clone(),
+            gate_destination: flight.gate_destination.clone(),
+            terminal_destination: flight.terminal_destination.clone(),
+            inbound_fa_flight_id: flight.inbound_fa_flight_id.clone(),
+            operator_icao: flight
+                .operator_icao
+                .clone()
+                .or_else(|| flight.operator.clone()),
+            operator_iata: flight.operator_iata.clone(),
             progress_percent: flight.progress_percent,
+            route_distance: flight.route_distance,
+            seats_cabin_first: flight.seats_cabin_first,
+            seats_cabin_business: flight.seats_cabin_business,
+            seats_cabin_coach: flight.seats_cabin_coach,
+            filed_altitude: flight.filed_altitude,
             origin_airport,
             destination_airport,
-        }
+            origin_city: flight.origin.as_ref().and_then(|o| o.city.clone()),
+            destination_city: flight.destination.as_ref().and_then(|d| d.city.clone()),
+            departure_reference: DepartureReference::default(),
+            arrival_definition: ArrivalDefinition::default(),
+        };
+        view_model.status = determine_flight_status_base(flight, &view_model);
+        view_model
     }
 }
 
 // Also implement From for GetFlightResponseFlightsItem (which is actually the same as BaseFlight in structure)
 impl From<&flightaware::types::GetFlightResponseFlightsItem> for FlightStatusViewModel {
     fn from(flight: &flightaware::types::GetFlightResponseFlightsItem) -> Self {
-        let status = determine_flight_status_response_item(flight);
-
         // Extract airport codes (prefer IATA, fallback to ICAO)
         let origin_airport = flight
             .origin
@@ -48,19 +82,54 @@ impl From<&flightaware::types::GetFlightResponseFlightsItem> for FlightStatusVie
             .as_ref()
             .and_then(|d| d.code_iata.clone().or_else(|| d.code_icao.clone()));
 
-        FlightStatusViewModel {
+        let mut view_model = FlightStatusViewModel {
             flight_number: flight.ident.clone(),
-            status,
+            fa_flight_id: Some(flight.fa_flight_id.clone()),
+            ident_iata: flight.ident_iata.clone(),
+            ident_icao: flight.ident_icao.clone(),
+            atc_ident: distinct_atc_ident(&flight.ident, flight.atc_ident.as_deref()),
+            // Settled below, once the view model can tell whether the flight has arrived
+            status: FlightStatus::default(),
             scheduled_departure: datetime_to_string(flight.scheduled_off.as_ref()),
             scheduled_arrival: datetime_to_string(flight.scheduled_on.as_ref()),
             estimated_departure: datetime_to_string(flight.estimated_off.as_ref()),
             estimated_arrival: datetime_to_string(flight.estimated_on.as_ref()),
             actual_departure: datetime_to_string(flight.actual_off.as_ref()),
             actual_arrival: datetime_to_string(flight.actual_on.as_ref()),
+            scheduled_gate_departure: datetime_to_string(flight.scheduled_out.as_ref()),
+            scheduled_gate_arrival: datetime_to_string(flight.scheduled_in.as_ref()),
+            estimated_gate_departure: datetime_to_string(flight.estimated_out.as_ref()),
+            estimated_gate_arrival: datetime_to_string(flight.estimated_in.as_ref()),
+            actual_gate_departure: datetime_to_string(flight.actual_out.as_ref()),
+            actual_gate_arrival: datetime_to_string(flight.actual_in.as_ref()),
+            departure_delay: flight.departure_delay,
+            arrival_delay: flight.arrival_delay,
+            foresight_predictions_available: flight.foresight_predictions_available,
+            baggage_claim: flight.baggage_claim.clone(),
+            gate_origin: flight.gate_origin.clone(),
+            gate_destination: flight.gate_destination.clone(),
+            terminal_destination: flight.terminal_destination.clone(),
+            inbound_fa_flight_id: flight.inbound_fa_flight_id.clone(),
+            operator_icao: flight
+                .operator_icao
+                .clone()
+                .or_else(|| flight.operator.clone()),
+            operator_iata: flight.operator_iata.clone(),
             progress_percent: flight.progress_percent,
+            route_distance: flight.route_distance,
+            seats_cabin_first: flight.seats_cabin_first,
+            seats_cabin_business: flight.seats_cabin_business,
+            seats_cabin_coach: flight.seats_cabin_coach,
+            filed_altitude: flight.filed_altitude,
             origin_airport,
             destination_airport,
-        }
+            origin_city: flight.origin.as_ref().and_then(|o| o.city.clone()),
+            destination_city: flight.destination.as_ref().and_then(|d| d.city.clone()),
+            departure_reference: DepartureReference::default(),
+            arrival_definition: ArrivalDefinition::default(),
+        };
+        view_model.status = determine_flight_status_response_item(flight, &view_model);
+        view_model
     }
 }
 
@@ -68,71 +137,147 @@ fn datetime_to_string(dt: Option<&DateTime<Utc>>) -> Option<String> {
     dt.map(|d| d.to_rfc3339())
 }
 
-fn determine_flight_status_base(flight: &flightaware::types::BaseFlight) -> FlightStatus {
-    if flight.cancelled {
-        return FlightStatus::Cancelled;
-    }
+/// Arriving at least this many seconds ahead of schedule counts as early rather than on time
+const EARLY_THRESHOLD_SECONDS: i64 = 15 * 60;
 
-    if flight.actual_off.is_some() && flight.actual_on.is_none() {
-        return FlightStatus::EnRoute;
-    }
+/// Negative delays mean the flight is ahead of schedule
+fn is_early(arrival_delay: Option<i64>) -> bool {
+    arrival_delay.is_some_and(|delay| delay <= -EARLY_THRESHOLD_SECONDS)
+}
 
-    if let Some(delay) = flight.departure_delay
-        && delay > 0
+/// The status of a flight that isn't cancelled or en route, from its delays
+pub(crate) fn status_from_delays(
+    departure_delay: Option<i64>,
+    arrival_delay: Option<i64>,
+) -> FlightStatus {
+    if departure_delay.is_some_and(|delay| delay > 0)
+        || arrival_delay.is_some_and(|delay| delay > 0)
     {
         return FlightStatus::Delayed;
     }
 
-    if let Some(delay) = flight.arrival_delay
-        && delay > 0
-    {
-        return FlightStatus::Delayed;
+    if is_early(arrival_delay) {
+        return FlightStatus::Early;
     }
 
     FlightStatus::OnTime
 }
 
-fn determine_flight_status_response_item(
-    flight: &flightaware::types::GetFlightResponseFlightsItem,
+/// En route from departure until the flight has arrived by `view_model`'s arrival definition
+fn determine_flight_status_base(
+    flight: &flightaware::types::BaseFlight,
+    view_model: &FlightStatusViewModel,
 ) -> FlightStatus {
     if flight.cancelled {
         return FlightStatus::Cancelled;
     }
 
-    if flight.actual_off.is_some() && flight.actual_on.is_none() {
+    if view_model.is_en_route_at(Utc::now()) {
         return FlightStatus::EnRoute;
     }
 
-    if let Some(delay) = flight.departure_delay
-        && delay > 0
-    {
-        return FlightStatus::Delayed;
+    status_from_delays(flight.departure_delay, flight.arrival_delay)
+}
+
+fn determine_flight_status_response_item(
+    flight: &flightaware::types::GetFlightResponseFlightsItem,
+    view_model: &FlightStatusViewModel,
+) -> FlightStatus {
+    if flight.cancelled {
+        return FlightStatus::Cancelled;
     }
 
-    if let Some(delay) = flight.arrival_delay
-        && delay > 0
-    {
-        return FlightStatus::Delayed;
+    if view_model.is_en_route_at(Utc::now()) {
+        return FlightStatus::EnRoute;
     }
 
-    FlightStatus::OnTime
+    status_from_delays(flight.departure_delay, flight.arrival_delay)
+}
+
+/// The ATC callsign, dropped when it's just the ident again
+fn distinct_atc_ident(ident: &str, atc_ident: Option<&str>) -> Option<String> {
+    atc_ident
+        .filter(|atc_ident| !atc_ident.eq_ignore_ascii_case(ident))
+        .map(str::to_string)
 }
 
 /// Determine flight status based on FlightAware flight data
 /// This is pub for testing purposes
-pub fn determine_flight_status(flight: &flightaware::types::BaseFlight) -> FlightStatus {
-    determine_flight_status_base(flight)
+pub fn determine_flight_status(
+    flight: &flightaware::types::BaseFlight,
+    arrival_definition: ArrivalDefinition,
+) -> FlightStatus {
+    FlightStatusViewModel::from(flight)
+        .with_arrival_definition(arrival_definition)
+        .status
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
+    use flightaware::types::{BaseFlight, BaseFlightType};
+
+    /// Scheduled flight with every optional field empty, for tests to fill in as needed
+    fn sample_base_flight() -> BaseFlight {
+        BaseFlight {
+            ident: "AA100".to_string(),
+            ident_iata: None,
+            ident_icao: None,
+            fa_flight_id: "test".to_string(),
+            operator: None,
+            operator_iata: None,
+            operator_icao: None,
+            flight_number: None,
+            registration: None,
+            atc_ident: None,
+            inbound_fa_flight_id: None,
+            codeshares: None,
+            codeshares_iata: None,
+            blocked: false,
+            diverted: false,
+            cancelled: false,
+            position_only: false,
+            origin: None,
+            destination: None,
+            departure_delay: Some(0),
+            arrival_delay: Some(0),
+            filed_ete: None,
+            scheduled_out: None,
+            estimated_out: None,
+            actual_out: None,
+            scheduled_off: None,
+            estimated_off: None,
+            actual_off: None,
+            scheduled_on: None,
+            estimated_on: None,
+            actual_on: None,
+            scheduled_in: None,
+            estimated_in: None,
+            actual_in: None,
+            progress_percent: None,
+            status: "Scheduled".to_string(),
+            aircraft_type: None,
+            route_distance: None,
+            filed_airspeed: None,
+            filed_altitude: None,
+            route: None,
+            baggage_claim: None,
+            seats_cabin_business: None,
+            seats_cabin_coach: None,
+            seats_cabin_first: None,
+            gate_origin: None,
+            gate_destination: None,
+            terminal_origin: None,
+            terminal_destination: None,
+            type_: BaseFlightType::Airline,
+            actual_runway_off: None,
+            actual_runway_on: None,
+        }
+    }
 
     #[test]
     fn test_from_conversion() {
-        use chrono::TimeZone;
-        use flightaware::types::{BaseFlight, BaseFlightType};
-
         let flight = BaseFlight {
             ident: "AA100".to_string(),
             ident_iata: None,
@@ -200,8 +345,6 @@ mod tests {
 
     #[test]
     fn test_datetime_to_string_conversion() {
-        use chrono::TimeZone;
-
         let dt = Utc.with_ymd_and_hms(2025, 11, 16, 10, 0, 0).unwrap();
         let result = datetime_to_string(Some(&dt));
 
@@ -217,8 +360,6 @@ mod tests {
 
     #[test]
     fn test_status_determination_cancelled() {
-        use flightaware::types::{BaseFlight, BaseFlightType};
-
         let flight = BaseFlight {
             ident: "AA100".to_string(),
             ident_iata: None,
@@ -274,13 +415,14 @@ mod tests {
             actual_runway_on: None,
         };
 
-        assert_eq!(determine_flight_status(&flight), FlightStatus::Cancelled);
+        assert_eq!(
+            determine_flight_status(&flight, ArrivalDefinition::default()),
+            FlightStatus::Cancelled
+        );
     }
 
     #[test]
     fn test_status_determination_delayed() {
-        use flightaware::types::{BaseFlight, BaseFlightType};
-
         let flight = BaseFlight {
             ident: "AA100".to_string(),
             ident_iata: None,
@@ -336,6 +478,292 @@ mod tests {
             actual_runway_on: None,
         };
 
-        assert_eq!(determine_flight_status(&flight), FlightStatus::Delayed);
+        assert_eq!(
+            determine_flight_status(&flight, ArrivalDefinition::default()),
+            FlightStatus::Delayed
+        );
+    }
+
+    #[test]
+    fn test_status_determination_early() {
+        let early = BaseFlight {
+            arrival_delay: Some(-20 * 60),
+            ..sample_base_flight()
+        };
+        assert_eq!(
+            determine_flight_status(&early, ArrivalDefinition::default()),
+            FlightStatus::Early
+        );
+        assert_eq!(
+            FlightStatusViewModel::from(&early).status.to_string(),
+            "Early"
+        );
+
+        // A few minutes ahead of schedule is still on time
+        let slightly_early = BaseFlight {
+            arrival_delay: Some(-5 * 60),
+            ..sample_base_flight()
+        };
+        assert_eq!(
+            determine_flight_status(&slightly_early, ArrivalDefinition::default()),
+            FlightStatus::OnTime
+        );
+    }
+
+    #[test]
+    fn test_status_determination_arrival_definition() {
+        let now = Utc::now();
+        let landed = BaseFlight {
+            actual_off: Some(now - chrono::Duration::hours(3)),
+            actual_on: Some(now - chrono::Duration::minutes(5)),
+            ..sample_base_flight()
+        };
+        assert_eq!(
+            determine_flight_status(&landed, ArrivalDefinition::Touchdown),
+            FlightStatus::OnTime
+        );
+        assert_eq!(
+            determine_flight_status(&landed, ArrivalDefinition::Gate),
+            FlightStatus::EnRoute
+        );
+
+        let at_gate = BaseFlight {
+            actual_in: Some(now - chrono::Duration::minutes(1)),
+            ..landed.clone()
+        };
+        assert_eq!(
+            determine_flight_status(&at_gate, ArrivalDefinition::Gate),
+            FlightStatus::OnTime
+        );
+
+        // Airports that never report the gate still count the flight in after a while
+        let long_landed = BaseFlight {
+            actual_on: Some(now - chrono::Duration::minutes(40)),
+            ..landed
+        };
+        assert_eq!(
+            determine_flight_status(&long_landed, ArrivalDefinition::Gate),
+            FlightStatus::OnTime
+        );
+    }
+
+    #[test]
+    fn test_from_conversion_carries_gate_times() {
+        let flight = BaseFlight {
+            scheduled_out: Some(Utc.with_ymd_and_hms(2025, 11, 16, 9, 45, 0).unwrap()),
+            estimated_out: Some(Utc.with_ymd_and_hms(2025, 11, 16, 9, 50, 0).unwrap()),
+            actual_out: Some(Utc.with_ymd_and_hms(2025, 11, 16, 9, 52, 0).unwrap()),
+            estimated_on: Some(Utc.with_ymd_and_hms(2025, 11, 16, 14, 0, 0).unwrap()),
+            scheduled_in: Some(Utc.with_ymd_and_hms(2025, 11, 16, 14, 10, 0).unwrap()),
+            estimated_in: Some(Utc.with_ymd_and_hms(2025, 11, 16, 14, 12, 0).unwrap()),
+            actual_in: None,
+            ..sample_base_flight()
+        };
+
+        let view_model = FlightStatusViewModel::from(&flight);
+        let contains = |field: Option<String>, expected: &str| field.unwrap().contains(expected);
+
+        assert!(contains(view_model.scheduled_gate_departure, "09:45:00"));
+        assert!(contains(view_model.estimated_gate_departure, "09:50:00"));
+        assert!(contains(view_model.actual_gate_departure, "09:52:00"));
+        assert!(contains(view_model.scheduled_gate_arrival, "14:10:00"));
+        assert!(contains(view_model.estimated_gate_arrival, "14:12:00"));
+        assert!(view_model.actual_gate_arrival.is_none());
+    }
+
+    #[test]
+    fn test_from_conversion_carries_ident_forms() {
+        let flight = BaseFlight {
+            ident: "AAL100".to_string(),
+            ident_iata: Some("AA100".to_string()),
+            ident_icao: Some("AAL100".to_string()),
+            ..sample_base_flight()
+        };
+
+        let view_model = FlightStatusViewModel::from(&flight);
+
+        assert_eq!(view_model.flight_number, "AAL100");
+        assert_eq!(view_model.ident_iata.as_deref(), Some("AA100"));
+        assert_eq!(view_model.ident_icao.as_deref(), Some("AAL100"));
+    }
+
+    #[test]
+    fn test_from_conversion_carries_fa_flight_id() {
+        let view_model = FlightStatusViewModel::from(&sample_base_flight());
+
+        assert_eq!(view_model.fa_flight_id.as_deref(), Some("test"));
+    }
+
+    #[test]
+    fn test_from_conversion_carries_atc_ident() {
+        let flight = BaseFlight {
+            ident: "AAL100".to_string(),
+            atc_ident: Some("AAL100X".to_string()),
+            ..sample_base_flight()
+        };
+        assert_eq!(
+            FlightStatusViewModel::from(&flight).atc_ident.as_deref(),
+            Some("AAL100X")
+        );
+
+        // The same callsign as the ident adds nothing
+        let same = BaseFlight {
+            atc_ident: Some("aal100".to_string()),
+            ..flight
+        };
+        assert_eq!(FlightStatusViewModel::from(&same).atc_ident, None);
+    }
+
+    #[test]
+    fn test_from_conversion_carries_baggage_claim() {
+        let flight = BaseFlight {
+            baggage_claim: Some("4".to_string()),
+            ..sample_base_flight()
+        };
+
+        assert_eq!(
+            FlightStatusViewModel::from(&flight)
+                .baggage_claim
+                .as_deref(),
+            Some("4")
+        );
+        assert!(
+            FlightStatusViewModel::from(&sample_base_flight())
+                .baggage_claim
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_from_conversion_carries_route_distance() {
+        let flight = BaseFlight {
+            route_distance: Some(2475),
+            ..sample_base_flight()
+        };
+
+        assert_eq!(
+            FlightStatusViewModel::from(&flight).route_distance,
+            Some(2475)
+        );
+    }
+
+    #[test]
+    fn test_from_conversion_carries_cities() {
+        let response: flightaware::types::GetFlightResponse =
+            serde_json::from_str(include_str!("../../flightaware/sample_flight_aware.json"))
+                .unwrap();
+
+        let view_model = FlightStatusViewModel::from(&response.flights[0]);
+        assert_eq!(view_model.origin_city.as_deref(), Some("Tokyo (Narita)"));
+        assert_eq!(view_model.destination_city.as_deref(), Some("Seattle"));
+        assert_eq!(
+            FlightStatusViewModel::from(&sample_base_flight()).origin_city,
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_conversion_carries_gate_origin() {
+        let flight = BaseFlight {
+            gate_origin: Some("B12".to_string()),
+            ..sample_base_flight()
+        };
+
+        assert_eq!(
+            FlightStatusViewModel::from(&flight).gate_origin.as_deref(),
+            Some("B12")
+        );
+    }
+
+    #[test]
+    fn test_from_conversion_carries_arrival_gate() {
+        let flight = BaseFlight {
+            gate_destination: Some("B12".to_string()),
+            terminal_destination: Some("2".to_string()),
+            ..sample_base_flight()
+        };
+
+        let view_model = FlightStatusViewModel::from(&flight);
+        assert_eq!(view_model.gate_destination.as_deref(), Some("B12"));
+        assert_eq!(view_model.terminal_destination.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn test_from_conversion_carries_filed_altitude() {
+        let flight = BaseFlight {
+            filed_altitude: Some(350),
+            ..sample_base_flight()
+        };
+
+        assert_eq!(
+            FlightStatusViewModel::from(&flight).filed_altitude,
+            Some(350)
+        );
+    }
+
+    #[test]
+    fn test_from_conversion_carries_cabin_seats() {
+        let flight = BaseFlight {
+            seats_cabin_first: Some(12),
+            seats_cabin_business: Some(48),
+            seats_cabin_coach: Some(180),
+            ..sample_base_flight()
+        };
+
+        let view_model = FlightStatusViewModel::from(&flight);
+        assert_eq!(view_model.seats_cabin_first, Some(12));
+        assert_eq!(view_model.seats_cabin_business, Some(48));
+        assert_eq!(view_model.seats_cabin_coach, Some(180));
+    }
+
+    #[test]
+    fn test_from_conversion_carries_operator() {
+        let flight = BaseFlight {
+            ident: "BAW123".to_string(),
+            operator: Some("BAW".to_string()),
+            operator_iata: Some("BA".to_string()),
+            ..sample_base_flight()
+        };
+
+        let view_model = FlightStatusViewModel::from(&flight);
+        assert_eq!(view_model.operator_icao.as_deref(), Some("BAW"));
+        assert_eq!(view_model.operator_iata.as_deref(), Some("BA"));
+        assert_eq!(
+            view_model.operated_by("AA8500"),
+            Some("Operated by British Airways".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_conversion_carries_inbound_flight_id() {
+        let flight = BaseFlight {
+            inbound_fa_flight_id: Some("AAL99-1763195983-airline-231p".to_string()),
+            ..sample_base_flight()
+        };
+
+        assert_eq!(
+            FlightStatusViewModel::from(&flight)
+                .inbound_fa_flight_id
+                .as_deref(),
+            Some("AAL99-1763195983-airline-231p")
+        );
+    }
+
+    #[test]
+    fn test_from_conversion_carries_foresight_flag() {
+        let response: flightaware::types::GetFlightResponse =
+            serde_json::from_str(include_str!("../../flightaware/sample_flight_aware.json"))
+                .unwrap();
+
+        let flags: Vec<bool> = response
+            .flights
+            .iter()
+            .map(|flight| FlightStatusViewModel::from(flight).foresight_predictions_available)
+            .collect();
+
+        assert_eq!(&flags[..3], &[false, false, true]);
+        assert!(
+            !FlightStatusViewModel::from(&sample_base_flight()).foresight_predictions_available
+        );
     }
 }
@@ -0,0 +1,101 @@
+/// Rows in every big glyph
+pub const HEIGHT: usize = 3;
+
+/// Three-row glyphs drawn with half blocks, for text that has to be read from across a room
+fn glyph(c: char) -> Option<[&'static str; HEIGHT]> {
+    Some(match c.to_ascii_uppercase() {
+        '0' => ["█▀█", "█ █", "▀▀▀"],
+        '1' => ["▀█ ", " █ ", "▀▀▀"],
+        '2' => ["▀▀█", "█▀▀", "▀▀▀"],
+        '3' => ["▀▀█", " ▀█", "▀▀▀"],
+        '4' => ["█ █", "▀▀█", "  ▀"],
+        '5' => ["█▀▀", "▀▀█", "▀▀▀"],
+        '6' => ["█▀▀", "█▀█", "▀▀▀"],
+        '7' => ["▀▀█", "  █", "  ▀"],
+        '8' => ["█▀█", "█▀█", "▀▀▀"],
+        '9' => ["█▀█", "▀▀█", "▀▀▀"],
+        'A' => ["█▀█", "█▀█", "▀ ▀"],
+        'B' => ["█▀▄", "█▀▄", "▀▀ "],
+        'C' => ["█▀▀", "█  ", "▀▀▀"],
+        'D' => ["█▀▄", "█ █", "▀▀ "],
+        'E' => ["█▀▀", "█▀▀", "▀▀▀"],
+        'F' => ["█▀▀", "█▀ ", "▀  "],
+        'G' => ["█▀▀", "█ █", "▀▀▀"],
+        'H' => ["█ █", "█▀█", "▀ ▀"],
+        'I' => ["▀█▀", " █ ", "▀▀▀"],
+        'J' => ["  █", "█ █", "▀▀▀"],
+        'K' => ["█ █", "█▀▄", "▀ ▀"],
+        'L' => ["█  ", "█  ", "▀▀▀"],
+        'M' => ["█▄█", "█ █", "▀ ▀"],
+        'N' => ["█▀▄", "█ █", "▀ ▀"],
+        'O' => ["▄▀▄", "█ █", " ▀ "],
+        'P' => ["█▀█", "█▀▀", "▀  "],
+        'Q' => ["█▀█", "█ █", "▀▀█"],
+        'R' => ["█▀█", "█▀▄", "▀ ▀"],
+        'S' => ["█▀▀", "▀▀█", "▀▀▀"],
+        'T' => ["▀█▀", " █ ", " ▀ "],
+        'U' => ["█ █", "█ █", "▀▀▀"],
+        'V' => ["█ █", "█ █", " ▀ "],
+        'W' => ["█ █", "█▄█", "▀ ▀"],
+        'X' => ["█ █", "▄▀▄", "▀ ▀"],
+        'Y' => ["█ █", " █ ", " ▀ "],
+        'Z' => ["▀▀█", "▄▀ ", "▀▀▀"],
+        ' ' => ["   ", "   ", "   "],
+        ':' => ["▄", "▄", " "],
+        '.' | '·' => [" ", "▄", " "],
+        '-' => ["   ", "▀▀▀", "   "],
+        '+' => [" ▄ ", "▀█▀", "   "],
+        '%' => ["█ ▄", " ▄▀", "▀ █"],
+        '/' => ["  █", " █ ", "▀  "],
+        _ => return None,
+    })
+}
+
+/// `text` as three rows of big glyphs one column apart, or None when it has a character the
+/// font doesn't cover
+pub fn render(text: &str) -> Option<[String; HEIGHT]> {
+    let glyphs = text.chars().map(glyph).collect::<Option<Vec<_>>>()?;
+
+    Some(std::array::from_fn(|row| {
+        glyphs
+            .iter()
+            .map(|glyph| glyph[row])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_big_text() {
+        assert_eq!(
+            render("A1").unwrap(),
+            [
+                "█▀█ ▀█ ".to_string(),
+                "█▀█  █ ".to_string(),
+                "▀ ▀ ▀▀▀".to_string(),
+            ]
+        );
+        // Lowercase is drawn in capitals
+        assert_eq!(render("aa100"), render("AA100"));
+    }
+
+    #[test]
+    fn test_every_row_is_as_wide_as_the_first() {
+        let rows = render("14:30 +25M 45% B12·T2").unwrap();
+        assert!(
+            rows.iter()
+                .all(|row| row.chars().count() == rows[0].chars().count())
+        );
+        assert_eq!(render("14:30").unwrap()[0].chars().count(), 4 * 3 + 1 + 4);
+    }
+
+    #[test]
+    fn test_unsupported_characters() {
+        assert_eq!(render("Día"), None);
+        assert_eq!(render("—"), None);
+    }
+}
@@ -0,0 +1,51 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Settings read from the TOML file given with `--config`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ConfigFile {
+    /// Action name to the keys that trigger it, e.g. `quit = ["q", "ctrl+c"]`
+    pub keys: HashMap<String, Vec<String>>,
+}
+
+/// Read and parse a config file, describing what went wrong on failure
+pub fn read_config_file(path: &Path) -> Result<ConfigFile, String> {
+    let contents = std::fs::read_to_string(path).map_err(|error| error.to_string())?;
+    parse_config_file(&contents)
+}
+
+pub fn parse_config_file(contents: &str) -> Result<ConfigFile, String> {
+    toml::from_str(contents).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keys_table() {
+        let config = parse_config_file(
+            r#"
+[keys]
+quit = ["q", "ctrl+c"]
+refresh = ["r", "f5"]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.keys["quit"], vec!["q", "ctrl+c"]);
+        assert_eq!(config.keys["refresh"], vec!["r", "f5"]);
+    }
+
+    #[test]
+    fn test_parse_empty_file() {
+        assert!(parse_config_file("").unwrap().keys.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid_file() {
+        assert!(parse_config_file("[keys]\nquit = 5\n").is_err());
+    }
+}
@@ -0,0 +1,432 @@
+use crate::flight_status::FlightStatusViewModel;
+use chrono::{DateTime, Duration, Utc};
+use std::fmt;
+
+/// Minimum connection time the connection panel assumes, as FlightAware doesn't report the
+/// airport's own; with `CONNECTION_BUFFER_MINUTES` on top, layovers under 45 minutes are tight
+pub const MIN_CONNECT_MINUTES: i64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionRisk {
+    Comfortable,
+    Tight,
+    Missed,
+}
+
+/// Time on the ground between an inbound flight and the connecting outbound flight
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layover {
+    pub duration: Duration,
+    pub risk: ConnectionRisk,
+}
+
+impl Layover {
+    /// Compare the inbound flight's best-known arrival with the outbound flight's best-known
+    /// departure. Actual and estimated times already include any delay reported by the API,
+    /// so a late-running inbound flight shortens the layover accordingly.
+    /// The risk is judged by `is_connection_makeable` against `MIN_CONNECT_MINUTES`.
+    /// Returns None when either time is unavailable.
+    pub fn between(
+        inbound: &FlightStatusViewModel,
+        outbound: &FlightStatusViewModel,
+    ) -> Option<Self> {
+        let (arrival, departure) = connection_times(inbound, outbound)?;
+
+        Some(Layover {
+            duration: departure.signed_duration_since(arrival),
+            risk: is_connection_makeable(inbound, outbound, MIN_CONNECT_MINUTES),
+        })
+    }
+}
+
+impl fmt::Display for Layover {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let minutes = self.duration.num_minutes().abs();
+        let length = if minutes >= 60 {
+            format!("{}h {}m", minutes / 60, minutes % 60)
+        } else {
+            format!("{}m", minutes)
+        };
+
+        match self.risk {
+            ConnectionRisk::Comfortable => write!(f, "Layover {}", length),
+            ConnectionRisk::Tight => write!(f, "Layover {} — tight!", length),
+            ConnectionRisk::Missed if self.duration > Duration::zero() => {
+                write!(f, "Connection missed — only {} to connect", length)
+            }
+            ConnectionRisk::Missed => write!(f, "Connection missed — arrives {} too late", length),
+        }
+    }
+}
+
+/// Slack beyond the minimum connection time below which a connection is still flagged as tight
+pub const CONNECTION_BUFFER_MINUTES: i64 = 15;
+
+/// Whether a traveller can get from the inbound flight onto the outbound one, given the
+/// airport's minimum connection time. The gap runs from the inbound's best-known arrival to the
+/// outbound's best-known departure; when only the schedule is known, the reported delays are
+/// added to it. A gap shorter than the minimum is missed, one within
+/// `CONNECTION_BUFFER_MINUTES` of it is tight.
+/// When either time is unknown the connection can't be confirmed, so it's reported as tight.
+pub fn is_connection_makeable(
+    arrival: &FlightStatusViewModel,
+    departure: &FlightStatusViewModel,
+    min_connect_minutes: i64,
+) -> ConnectionRisk {
+    let Some((arrives, departs)) = connection_times(arrival, departure) else {
+        return ConnectionRisk::Tight;
+    };
+
+    let gap = departs.signed_duration_since(arrives);
+    if gap < Duration::minutes(min_connect_minutes) {
+        ConnectionRisk::Missed
+    } else if gap < Duration::minutes(min_connect_minutes + CONNECTION_BUFFER_MINUTES) {
+        ConnectionRisk::Tight
+    } else {
+        ConnectionRisk::Comfortable
+    }
+}
+
+/// Shortest time the aircraft spends at the gate between landing and departing again
+pub const MIN_TURNAROUND_MINUTES: i64 = 30;
+
+/// Predicted knock-on delay from the late arrival of the aircraft that will operate this flight
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InboundDelay {
+    /// How late the inbound flight is running against its schedule
+    pub inbound_late_by: Duration,
+    /// How much later than currently reported this flight is likely to leave
+    pub departure_pushed_by: Duration,
+}
+
+impl InboundDelay {
+    /// Predict whether a late inbound aircraft will hold up this flight. The delay carries over
+    /// once it eats up the scheduled gap between the two flights beyond the minimum turnaround.
+    /// Returns None when the inbound flight can still turn around in time, this flight has
+    /// already left, or the schedules needed for the comparison are missing.
+    pub fn predict(
+        inbound: &FlightStatusViewModel,
+        flight: &FlightStatusViewModel,
+    ) -> Option<Self> {
+        if flight.actual_departure.is_some() {
+            return None;
+        }
+
+        let inbound_scheduled: DateTime<Utc> =
+            inbound.scheduled_arrival.as_deref()?.parse().ok()?;
+        let inbound_arrival = best_time(inbound.arrival_time(), None).unwrap_or(inbound_scheduled);
+        let departure = best_time(
+            flight.departure_time(),
+            flight.scheduled_departure.as_deref(),
+        )?;
+
+        let ready = inbound_arrival + Duration::minutes(MIN_TURNAROUND_MINUTES);
+        let departure_pushed_by = ready.signed_duration_since(departure);
+        if departure_pushed_by <= Duration::zero() {
+            return None;
+        }
+
+        Some(InboundDelay {
+            inbound_late_by: inbound_arrival.signed_duration_since(inbound_scheduled),
+            departure_pushed_by,
+        })
+    }
+}
+
+impl fmt::Display for InboundDelay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Prediction: likely delayed — inbound aircraft running {}m late",
+            self.inbound_late_by.num_minutes().max(0)
+        )
+    }
+}
+
+/// Prefer the actual/estimated time, falling back to the schedule
+fn best_time(current: Option<&str>, scheduled: Option<&str>) -> Option<DateTime<Utc>> {
+    current.or(scheduled)?.parse().ok()
+}
+
+/// When the inbound flight arrives and the outbound one departs, with the delays added to a
+/// bare schedule
+fn connection_times(
+    arrival: &FlightStatusViewModel,
+    departure: &FlightStatusViewModel,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let arrives = delayed_time(
+        arrival.arrival_time(),
+        arrival.scheduled_arrival.as_deref(),
+        arrival.arrival_delay,
+    )?;
+    let departs = delayed_time(
+        departure.departure_time(),
+        departure.scheduled_departure.as_deref(),
+        departure.departure_delay,
+    )?;
+    Some((arrives, departs))
+}
+
+/// Like `best_time`, but a scheduled time is pushed back by the reported delay in seconds;
+/// actual and estimated times already include it
+fn delayed_time(
+    current: Option<&str>,
+    scheduled: Option<&str>,
+    delay_seconds: Option<i64>,
+) -> Option<DateTime<Utc>> {
+    if let Some(current) = current {
+        return current.parse().ok();
+    }
+    let scheduled: DateTime<Utc> = scheduled?.parse().ok()?;
+    Some(scheduled + Duration::seconds(delay_seconds.unwrap_or(0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inbound(estimated_arrival: &str) -> FlightStatusViewModel {
+        FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            estimated_arrival: Some(estimated_arrival.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn outbound(estimated_departure: &str) -> FlightStatusViewModel {
+        FlightStatusViewModel {
+            flight_number: "UA200".to_string(),
+            scheduled_departure: Some("2025-11-16T15:30:00Z".to_string()),
+            estimated_departure: Some(estimated_departure.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_layover_comfortable() {
+        let layover = Layover::between(
+            &inbound("2025-11-16T14:00:00Z"),
+            &outbound("2025-11-16T15:30:00Z"),
+        )
+        .unwrap();
+
+        assert_eq!(layover.risk, ConnectionRisk::Comfortable);
+        assert_eq!(layover.duration, Duration::minutes(90));
+        assert_eq!(layover.to_string(), "Layover 1h 30m");
+    }
+
+    #[test]
+    fn test_layover_tight_when_inbound_delayed() {
+        // Inbound running 55 minutes late eats into a 90 minute connection
+        let layover = Layover::between(
+            &inbound("2025-11-16T14:55:00Z"),
+            &outbound("2025-11-16T15:30:00Z"),
+        )
+        .unwrap();
+
+        assert_eq!(layover.risk, ConnectionRisk::Tight);
+        assert_eq!(layover.to_string(), "Layover 35m — tight!");
+    }
+
+    #[test]
+    fn test_layover_missed() {
+        let layover = Layover::between(
+            &inbound("2025-11-16T15:40:00Z"),
+            &outbound("2025-11-16T15:30:00Z"),
+        )
+        .unwrap();
+
+        assert_eq!(layover.risk, ConnectionRisk::Missed);
+        assert_eq!(
+            layover.to_string(),
+            "Connection missed — arrives 10m too late"
+        );
+    }
+
+    #[test]
+    fn test_layover_missed_below_minimum_connect_time() {
+        // 20 minutes on the ground is short of the minimum connection time
+        let layover = Layover::between(
+            &inbound("2025-11-16T15:10:00Z"),
+            &outbound("2025-11-16T15:30:00Z"),
+        )
+        .unwrap();
+
+        assert_eq!(layover.risk, ConnectionRisk::Missed);
+        assert_eq!(
+            layover.to_string(),
+            "Connection missed — only 20m to connect"
+        );
+    }
+
+    #[test]
+    fn test_layover_agrees_with_connection_check() {
+        for estimated_arrival in [
+            "2025-11-16T14:00:00Z",
+            "2025-11-16T14:35:00Z",
+            "2025-11-16T14:50:00Z",
+            "2025-11-16T15:10:00Z",
+            "2025-11-16T15:40:00Z",
+        ] {
+            let inbound = inbound(estimated_arrival);
+            let outbound = outbound("2025-11-16T15:30:00Z");
+            assert_eq!(
+                Layover::between(&inbound, &outbound).unwrap().risk,
+                is_connection_makeable(&inbound, &outbound, MIN_CONNECT_MINUTES),
+                "{}",
+                estimated_arrival
+            );
+        }
+    }
+
+    #[test]
+    fn test_layover_falls_back_to_scheduled_times() {
+        let inbound = FlightStatusViewModel {
+            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            ..Default::default()
+        };
+        let outbound = FlightStatusViewModel {
+            scheduled_departure: Some("2025-11-16T15:00:00Z".to_string()),
+            ..Default::default()
+        };
+
+        let layover = Layover::between(&inbound, &outbound).unwrap();
+        assert_eq!(layover.duration, Duration::minutes(60));
+    }
+
+    #[test]
+    fn test_connection_comfortable_with_minimum_connect_time() {
+        // 90 minutes on the ground against a 45 minute minimum
+        assert_eq!(
+            is_connection_makeable(
+                &inbound("2025-11-16T14:00:00Z"),
+                &outbound("2025-11-16T15:30:00Z"),
+                45
+            ),
+            ConnectionRisk::Comfortable
+        );
+    }
+
+    #[test]
+    fn test_connection_tight_when_inbound_delayed() {
+        // Running 35 minutes late leaves 55 minutes, inside the buffer above a 45 minute minimum
+        assert_eq!(
+            is_connection_makeable(
+                &inbound("2025-11-16T14:35:00Z"),
+                &outbound("2025-11-16T15:30:00Z"),
+                45
+            ),
+            ConnectionRisk::Tight
+        );
+    }
+
+    #[test]
+    fn test_connection_missed_below_minimum_connect_time() {
+        // 40 minutes between flights isn't enough when the airport needs 45
+        assert_eq!(
+            is_connection_makeable(
+                &inbound("2025-11-16T14:50:00Z"),
+                &outbound("2025-11-16T15:30:00Z"),
+                45
+            ),
+            ConnectionRisk::Missed
+        );
+    }
+
+    #[test]
+    fn test_connection_adds_delay_to_schedule() {
+        // No estimates, but 40 minutes late in and 10 out leaves a 30 minute gap
+        let inbound = FlightStatusViewModel {
+            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            arrival_delay: Some(40 * 60),
+            ..Default::default()
+        };
+        let outbound = FlightStatusViewModel {
+            scheduled_departure: Some("2025-11-16T15:00:00Z".to_string()),
+            departure_delay: Some(10 * 60),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            is_connection_makeable(&inbound, &outbound, 35),
+            ConnectionRisk::Missed
+        );
+        assert_eq!(
+            is_connection_makeable(&inbound, &outbound, 25),
+            ConnectionRisk::Tight
+        );
+        assert_eq!(
+            is_connection_makeable(&inbound, &outbound, 15),
+            ConnectionRisk::Comfortable
+        );
+    }
+
+    #[test]
+    fn test_connection_unconfirmed_without_times() {
+        assert_eq!(
+            is_connection_makeable(
+                &FlightStatusViewModel::default(),
+                &outbound("2025-11-16T15:30:00Z"),
+                45
+            ),
+            ConnectionRisk::Tight
+        );
+    }
+
+    #[test]
+    fn test_inbound_delay_carries_over() {
+        // Landing 40 minutes late leaves 20 minutes to turn around, 10 short of the minimum
+        let delay = InboundDelay::predict(
+            &inbound("2025-11-16T14:40:00Z"),
+            &outbound("2025-11-16T15:00:00Z"),
+        )
+        .unwrap();
+
+        assert_eq!(delay.inbound_late_by, Duration::minutes(40));
+        assert_eq!(delay.departure_pushed_by, Duration::minutes(10));
+        assert_eq!(
+            delay.to_string(),
+            "Prediction: likely delayed — inbound aircraft running 40m late"
+        );
+    }
+
+    #[test]
+    fn test_inbound_delay_absorbed_by_schedule_gap() {
+        // 20 minutes late still leaves 40 minutes at the gate
+        let delay = InboundDelay::predict(
+            &inbound("2025-11-16T14:20:00Z"),
+            &outbound("2025-11-16T15:00:00Z"),
+        );
+        assert!(delay.is_none());
+    }
+
+    #[test]
+    fn test_inbound_delay_already_reported() {
+        // The estimated departure already allows for the late inbound aircraft
+        let delay = InboundDelay::predict(
+            &inbound("2025-11-16T14:40:00Z"),
+            &outbound("2025-11-16T15:15:00Z"),
+        );
+        assert!(delay.is_none());
+    }
+
+    #[test]
+    fn test_inbound_delay_ignored_after_departure() {
+        let departed = FlightStatusViewModel {
+            actual_departure: Some("2025-11-16T15:20:00Z".to_string()),
+            ..outbound("2025-11-16T15:00:00Z")
+        };
+
+        assert!(InboundDelay::predict(&inbound("2025-11-16T14:40:00Z"), &departed).is_none());
+    }
+
+    #[test]
+    fn test_layover_unknown_without_times() {
+        let layover = Layover::between(
+            &FlightStatusViewModel::default(),
+            &outbound("2025-11-16T15:30:00Z"),
+        );
+        assert!(layover.is_none());
+    }
+}
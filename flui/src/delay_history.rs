@@ -0,0 +1,235 @@
+use chrono::{DateTime, Utc};
+use flui::FlightStatusViewModel;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Most delay samples kept; older ones are dropped as new refreshes arrive
+pub const MAX_DELAY_SAMPLES: usize = 60;
+
+/// How many of the latest samples the trend is worked out from
+pub const TREND_SAMPLES: usize = 6;
+
+/// Fewest samples a trend is reported for; with fewer, one odd refresh would swing it
+pub const MIN_TREND_SAMPLES: usize = 3;
+
+/// Change in delay, in minutes per hour, below which the delay counts as stable
+const STABLE_MINUTES_PER_HOUR: f64 = 5.0;
+
+/// Which way the delay is heading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelayTrend {
+    Worsening,
+    Recovering,
+    Stable,
+}
+
+impl fmt::Display for DelayTrend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DelayTrend::Worsening => write!(f, "Delay worsening"),
+            DelayTrend::Recovering => write!(f, "Recovering"),
+            DelayTrend::Stable => write!(f, "Stable"),
+        }
+    }
+}
+
+/// The trend of a series of (time, delay in minutes) samples, from the least-squares slope of
+/// the delay over time
+/// Fewer than two samples, or samples all taken at once, count as stable
+pub fn delay_trend(samples: &[(DateTime<Utc>, i64)]) -> DelayTrend {
+    let Some(&(start, _)) = samples.first() else {
+        return DelayTrend::Stable;
+    };
+    let points: Vec<(f64, f64)> = samples
+        .iter()
+        .map(|(at, delay)| {
+            let hours = at.signed_duration_since(start).num_seconds() as f64 / 3600.0;
+            (hours, *delay as f64)
+        })
+        .collect();
+
+    let count = points.len() as f64;
+    let mean_hours = points.iter().map(|(hours, _)| hours).sum::<f64>() / count;
+    let mean_delay = points.iter().map(|(_, delay)| delay).sum::<f64>() / count;
+    let (covariance, variance) =
+        points
+            .iter()
+            .fold((0.0, 0.0), |(covariance, variance), (hours, delay)| {
+                let hours = hours - mean_hours;
+                (
+                    covariance + hours * (delay - mean_delay),
+                    variance + hours * hours,
+                )
+            });
+    if variance == 0.0 {
+        return DelayTrend::Stable;
+    }
+
+    let minutes_per_hour = covariance / variance;
+    if minutes_per_hour >= STABLE_MINUTES_PER_HOUR {
+        DelayTrend::Worsening
+    } else if minutes_per_hour <= -STABLE_MINUTES_PER_HOUR {
+        DelayTrend::Recovering
+    } else {
+        DelayTrend::Stable
+    }
+}
+
+/// The flight's delay as seen on one refresh
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DelaySample {
+    pub at: DateTime<Utc>,
+    /// Negative when running early
+    pub delay_minutes: i64,
+}
+
+/// The delays observed over the session, oldest first, to show whether a delay is growing or
+/// shrinking
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DelayHistory {
+    samples: VecDeque<DelaySample>,
+}
+
+impl DelayHistory {
+    /// Record the flight's arrival delay, or its larger delay when the arrival delay isn't
+    /// reported; refreshes without any delay are skipped
+    pub fn record(&mut self, view_model: &FlightStatusViewModel, at: DateTime<Utc>) {
+        let delay_minutes = view_model
+            .arrival_delay
+            .map(|seconds| seconds / 60)
+            .or_else(|| view_model.delay_minutes());
+        if let Some(delay_minutes) = delay_minutes {
+            self.push(DelaySample { at, delay_minutes });
+        }
+    }
+
+    pub fn push(&mut self, sample: DelaySample) {
+        if self.samples.len() == MAX_DELAY_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    pub fn samples(&self) -> impl DoubleEndedIterator<Item = &DelaySample> + ExactSizeIterator {
+        self.samples.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// The trend over the latest `TREND_SAMPLES`, or None until there are enough to tell
+    pub fn trend(&self) -> Option<DelayTrend> {
+        if self.samples.len() < MIN_TREND_SAMPLES {
+            return None;
+        }
+        let recent: Vec<(DateTime<Utc>, i64)> = self
+            .samples
+            .iter()
+            .skip(self.samples.len().saturating_sub(TREND_SAMPLES))
+            .map(|sample| (sample.at, sample.delay_minutes))
+            .collect();
+        Some(delay_trend(&recent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 11, 16, 10, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_record_prefers_arrival_delay() {
+        let mut history = DelayHistory::default();
+        history.record(
+            &FlightStatusViewModel {
+                departure_delay: Some(1800),
+                arrival_delay: Some(600),
+                ..Default::default()
+            },
+            at(0),
+        );
+        history.record(
+            &FlightStatusViewModel {
+                departure_delay: Some(1200),
+                ..Default::default()
+            },
+            at(5),
+        );
+        history.record(&FlightStatusViewModel::default(), at(10));
+
+        let delays: Vec<i64> = history
+            .samples()
+            .map(|sample| sample.delay_minutes)
+            .collect();
+        assert_eq!(delays, [10, 20]);
+    }
+
+    fn series(delays: &[i64]) -> Vec<(DateTime<Utc>, i64)> {
+        delays
+            .iter()
+            .enumerate()
+            .map(|(index, &delay)| (at(5 * index as u32), delay))
+            .collect()
+    }
+
+    #[test]
+    fn test_delay_trend_worsening() {
+        assert_eq!(
+            delay_trend(&series(&[10, 15, 25, 30])),
+            DelayTrend::Worsening
+        );
+        assert_eq!(DelayTrend::Worsening.to_string(), "Delay worsening");
+    }
+
+    #[test]
+    fn test_delay_trend_recovering() {
+        assert_eq!(
+            delay_trend(&series(&[40, 35, 35, 20])),
+            DelayTrend::Recovering
+        );
+    }
+
+    #[test]
+    fn test_delay_trend_stable() {
+        assert_eq!(delay_trend(&series(&[20, 20, 20, 20])), DelayTrend::Stable);
+        // A minute either way over a quarter of an hour is noise
+        assert_eq!(delay_trend(&series(&[20, 21, 20, 21])), DelayTrend::Stable);
+        assert_eq!(delay_trend(&series(&[20])), DelayTrend::Stable);
+        assert_eq!(delay_trend(&[]), DelayTrend::Stable);
+    }
+
+    #[test]
+    fn test_history_trend_uses_recent_samples() {
+        // An early climb followed by a steady stretch as long as the trend window
+        let mut history = DelayHistory::default();
+        for (index, (at, delay_minutes)) in series(&[0, 10, 30, 30, 30, 30, 30, 30])
+            .into_iter()
+            .enumerate()
+        {
+            history.push(DelaySample { at, delay_minutes });
+            if index == 1 {
+                assert_eq!(history.trend(), None);
+            }
+        }
+        assert_eq!(history.trend(), Some(DelayTrend::Stable));
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut history = DelayHistory::default();
+        for minute in 0..MAX_DELAY_SAMPLES as i64 + 5 {
+            history.push(DelaySample {
+                at: at(0),
+                delay_minutes: minute,
+            });
+        }
+
+        assert_eq!(history.samples().len(), MAX_DELAY_SAMPLES);
+        assert_eq!(history.samples().next().unwrap().delay_minutes, 5);
+    }
+}
@@ -0,0 +1,115 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use flui::FlightStatusViewModel;
+use flui::flight_status::FlightStatus;
+
+/// Flight number shown by --demo
+pub const FLIGHT_NUMBER: &str = "DEMO1";
+
+/// The scripted flight runs from boarding to the gate at one flight minute per second
+const MINUTES_BEFORE_PUSHBACK: f64 = 15.0;
+const TAKEOFF_MINUTE: f64 = 10.0;
+const LANDING_MINUTE: f64 = 85.0;
+const GATE_ARRIVAL_MINUTE: f64 = 95.0;
+
+/// The synthetic flight `elapsed` into the demo, with its times laid out around `now` so the
+/// countdowns match the scripted position; after about two minutes it stays parked at the gate
+pub fn demo_flight(elapsed: std::time::Duration, now: DateTime<Utc>) -> FlightStatusViewModel {
+    let minute = (elapsed.as_secs_f64() - MINUTES_BEFORE_PUSHBACK).min(GATE_ARRIVAL_MINUTE);
+    let at = |offset: f64| {
+        let seconds = ((offset - minute) * 60.0).round() as i64;
+        (now + chrono::Duration::seconds(seconds)).to_rfc3339_opts(SecondsFormat::Secs, true)
+    };
+    let once_reached = |offset: f64| (minute >= offset).then(|| at(offset));
+
+    let airborne = (TAKEOFF_MINUTE..LANDING_MINUTE).contains(&minute);
+    let progress =
+        ((minute - TAKEOFF_MINUTE) / (LANDING_MINUTE - TAKEOFF_MINUTE) * 100.0).clamp(0.0, 100.0);
+
+    FlightStatusViewModel {
+        flight_number: FLIGHT_NUMBER.to_string(),
+        status: if airborne {
+            FlightStatus::EnRoute
+        } else {
+            FlightStatus::OnTime
+        },
+        scheduled_departure: Some(at(TAKEOFF_MINUTE)),
+        scheduled_arrival: Some(at(LANDING_MINUTE)),
+        estimated_departure: Some(at(TAKEOFF_MINUTE)),
+        estimated_arrival: Some(at(LANDING_MINUTE)),
+        actual_departure: once_reached(TAKEOFF_MINUTE),
+        actual_arrival: once_reached(LANDING_MINUTE),
+        scheduled_gate_departure: Some(at(0.0)),
+        scheduled_gate_arrival: Some(at(GATE_ARRIVAL_MINUTE)),
+        estimated_gate_departure: Some(at(0.0)),
+        estimated_gate_arrival: Some(at(GATE_ARRIVAL_MINUTE)),
+        actual_gate_departure: once_reached(0.0),
+        actual_gate_arrival: once_reached(GATE_ARRIVAL_MINUTE),
+        departure_delay: Some(0),
+        arrival_delay: Some(0),
+        baggage_claim: (minute >= LANDING_MINUTE).then(|| "5".to_string()),
+        gate_origin: Some("A12".to_string()),
+        gate_destination: Some("52A".to_string()),
+        terminal_destination: Some("5".to_string()),
+        progress_percent: Some(progress as i64),
+        route_distance: Some(337),
+        seats_cabin_first: Some(16),
+        seats_cabin_coach: Some(150),
+        filed_altitude: Some(330),
+        origin_airport: Some("SFO".to_string()),
+        destination_airport: Some("LAX".to_string()),
+        origin_city: Some("San Francisco".to_string()),
+        destination_city: Some("Los Angeles".to_string()),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flui::flight_status::FlightPhase;
+
+    fn phase_after(seconds: u64) -> FlightPhase {
+        demo_flight(std::time::Duration::from_secs(seconds), Utc::now()).phase()
+    }
+
+    #[test]
+    fn test_demo_flight_goes_through_every_phase() {
+        assert_eq!(phase_after(0), FlightPhase::PreDeparture);
+        assert_eq!(phase_after(20), FlightPhase::Taxiing);
+        assert_eq!(phase_after(30), FlightPhase::Climb);
+        assert_eq!(phase_after(60), FlightPhase::Cruise);
+        assert_eq!(phase_after(95), FlightPhase::Descent);
+        assert_eq!(phase_after(105), FlightPhase::Landed);
+        assert_eq!(phase_after(110), FlightPhase::AtGate);
+        assert_eq!(phase_after(600), FlightPhase::AtGate);
+    }
+
+    #[test]
+    fn test_demo_flight_times_follow_the_script() {
+        let now: DateTime<Utc> = "2025-11-16T12:00:00Z".parse().unwrap();
+        let cruising = demo_flight(std::time::Duration::from_secs(55), now);
+
+        // 40 minutes in: pushed back at 11:20, off at 11:30, landing due at 12:45
+        assert_eq!(
+            cruising.actual_gate_departure.as_deref(),
+            Some("2025-11-16T11:20:00Z")
+        );
+        assert_eq!(
+            cruising.actual_departure.as_deref(),
+            Some("2025-11-16T11:30:00Z")
+        );
+        assert_eq!(cruising.actual_arrival, None);
+        assert_eq!(
+            cruising.estimated_arrival.as_deref(),
+            Some("2025-11-16T12:45:00Z")
+        );
+        assert_eq!(cruising.progress_percent, Some(40));
+        assert_eq!(cruising.status, FlightStatus::EnRoute);
+    }
+
+    #[test]
+    fn test_demo_flight_completes() {
+        assert!(!demo_flight(std::time::Duration::from_secs(100), Utc::now()).is_complete());
+        assert!(demo_flight(std::time::Duration::from_secs(110), Utc::now()).is_complete());
+    }
+}
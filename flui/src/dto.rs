@@ -0,0 +1,216 @@
+use crate::flight_status::{FlightPhase, FlightStatus, FlightStatusViewModel};
+use serde::Serialize;
+
+/// Version of the JSON field set; bumped whenever a field is renamed or removed or changes
+/// meaning, but not when one is added
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The flight status as published to other programs, kept apart from the view model so the
+/// wire format doesn't change with internal refactors
+/// Times are ISO 8601 in UTC, delays are in seconds (negative when early)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FlightStatusDto {
+    /// Always `SCHEMA_VERSION`
+    pub version: u32,
+    /// The ident as FlightAware returns it
+    pub flight_number: String,
+    /// The flight number in the form the user chose to display
+    pub ident: String,
+    /// The flight number the user searched, which for a codeshare is the marketing airline's
+    pub marketing_ident: String,
+    /// The flight number of the operating carrier
+    pub operating_ident: String,
+    pub ident_iata: Option<String>,
+    pub ident_icao: Option<String>,
+    /// Callsign used with air traffic control, only when it differs from the ident
+    pub atc_ident: Option<String>,
+    /// FlightAware's id for this leg
+    pub fa_flight_id: Option<String>,
+    /// One of `OnTime`, `Delayed`, `Cancelled`, `EnRoute` or `Early`
+    pub status: &'static str,
+    /// One of `PreDeparture`, `Taxiing`, `Climb`, `Cruise`, `Descent`, `Landed` or `AtGate`
+    pub phase: &'static str,
+    pub origin_airport: Option<String>,
+    pub destination_airport: Option<String>,
+    pub origin_city: Option<String>,
+    pub destination_city: Option<String>,
+    /// Runway (off/on) times
+    pub scheduled_departure: Option<String>,
+    pub estimated_departure: Option<String>,
+    pub actual_departure: Option<String>,
+    pub scheduled_arrival: Option<String>,
+    pub estimated_arrival: Option<String>,
+    pub actual_arrival: Option<String>,
+    /// Gate (out/in) times
+    pub scheduled_gate_departure: Option<String>,
+    pub estimated_gate_departure: Option<String>,
+    pub actual_gate_departure: Option<String>,
+    pub scheduled_gate_arrival: Option<String>,
+    pub estimated_gate_arrival: Option<String>,
+    pub actual_gate_arrival: Option<String>,
+    pub departure_delay: Option<i64>,
+    pub arrival_delay: Option<i64>,
+    pub progress_percent: Option<i64>,
+    pub gate_origin: Option<String>,
+    pub gate_destination: Option<String>,
+    pub terminal_destination: Option<String>,
+    pub baggage_claim: Option<String>,
+    /// Airline flying the aircraft, which differs from the ident's airline on codeshares
+    pub operator_iata: Option<String>,
+    pub operator_icao: Option<String>,
+    /// Planned distance along the filed route, in statute miles
+    pub route_distance: Option<i64>,
+    /// Filed cruise altitude, in hundreds of feet
+    pub filed_altitude: Option<i64>,
+}
+
+fn status_name(status: &FlightStatus) -> &'static str {
+    match status {
+        FlightStatus::OnTime => "OnTime",
+        FlightStatus::Delayed => "Delayed",
+        FlightStatus::Cancelled => "Cancelled",
+        FlightStatus::EnRoute => "EnRoute",
+        FlightStatus::Early => "Early",
+    }
+}
+
+fn phase_name(phase: FlightPhase) -> &'static str {
+    match phase {
+        FlightPhase::PreDeparture => "PreDeparture",
+        FlightPhase::Taxiing => "Taxiing",
+        FlightPhase::Climb => "Climb",
+        FlightPhase::Cruise => "Cruise",
+        FlightPhase::Descent => "Descent",
+        FlightPhase::Landed => "Landed",
+        FlightPhase::AtGate => "AtGate",
+    }
+}
+
+/// Without knowing how the flight was searched for or displayed, every ident field holds the
+/// flight's own ident; callers that know better overwrite them
+impl From<&FlightStatusViewModel> for FlightStatusDto {
+    fn from(view_model: &FlightStatusViewModel) -> Self {
+        FlightStatusDto {
+            version: SCHEMA_VERSION,
+            flight_number: view_model.flight_number.clone(),
+            ident: view_model.flight_number.clone(),
+            marketing_ident: view_model.flight_number.clone(),
+            operating_ident: view_model.flight_number.clone(),
+            ident_iata: view_model.ident_iata.clone(),
+            ident_icao: view_model.ident_icao.clone(),
+            atc_ident: view_model.atc_ident.clone(),
+            fa_flight_id: view_model.fa_flight_id.clone(),
+            status: status_name(&view_model.status),
+            phase: phase_name(view_model.phase()),
+            origin_airport: view_model.origin_airport.clone(),
+            destination_airport: view_model.destination_airport.clone(),
+            origin_city: view_model.origin_city.clone(),
+            destination_city: view_model.destination_city.clone(),
+            scheduled_departure: view_model.scheduled_departure.clone(),
+            estimated_departure: view_model.estimated_departure.clone(),
+            actual_departure: view_model.actual_departure.clone(),
+            scheduled_arrival: view_model.scheduled_arrival.clone(),
+            estimated_arrival: view_model.estimated_arrival.clone(),
+            actual_arrival: view_model.actual_arrival.clone(),
+            scheduled_gate_departure: view_model.scheduled_gate_departure.clone(),
+            estimated_gate_departure: view_model.estimated_gate_departure.clone(),
+            actual_gate_departure: view_model.actual_gate_departure.clone(),
+            scheduled_gate_arrival: view_model.scheduled_gate_arrival.clone(),
+            estimated_gate_arrival: view_model.estimated_gate_arrival.clone(),
+            actual_gate_arrival: view_model.actual_gate_arrival.clone(),
+            departure_delay: view_model.departure_delay,
+            arrival_delay: view_model.arrival_delay,
+            progress_percent: view_model.progress_percent,
+            gate_origin: view_model.gate_origin.clone(),
+            gate_destination: view_model.gate_destination.clone(),
+            terminal_destination: view_model.terminal_destination.clone(),
+            baggage_claim: view_model.baggage_claim.clone(),
+            operator_iata: view_model.operator_iata.clone(),
+            operator_icao: view_model.operator_icao.clone(),
+            route_distance: view_model.route_distance,
+            filed_altitude: view_model.filed_altitude,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_field_names_are_stable() {
+        // Renaming or removing any of these breaks consumers; bump SCHEMA_VERSION if it's meant
+        let json =
+            serde_json::to_value(FlightStatusDto::from(&FlightStatusViewModel::default())).unwrap();
+        let mut fields: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        fields.sort_unstable();
+
+        let mut expected = vec![
+            "version",
+            "flight_number",
+            "ident",
+            "marketing_ident",
+            "operating_ident",
+            "ident_iata",
+            "ident_icao",
+            "atc_ident",
+            "fa_flight_id",
+            "status",
+            "phase",
+            "origin_airport",
+            "destination_airport",
+            "origin_city",
+            "destination_city",
+            "scheduled_departure",
+            "estimated_departure",
+            "actual_departure",
+            "scheduled_arrival",
+            "estimated_arrival",
+            "actual_arrival",
+            "scheduled_gate_departure",
+            "estimated_gate_departure",
+            "actual_gate_departure",
+            "scheduled_gate_arrival",
+            "estimated_gate_arrival",
+            "actual_gate_arrival",
+            "departure_delay",
+            "arrival_delay",
+            "progress_percent",
+            "gate_origin",
+            "gate_destination",
+            "terminal_destination",
+            "baggage_claim",
+            "operator_iata",
+            "operator_icao",
+            "route_distance",
+            "filed_altitude",
+        ];
+        expected.sort_unstable();
+
+        assert_eq!(fields, expected);
+        assert_eq!(json["version"], SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_dto_from_view_model() {
+        let view_model = FlightStatusViewModel {
+            flight_number: "AAL100".to_string(),
+            status: FlightStatus::EnRoute,
+            actual_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            progress_percent: Some(50),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(FlightStatusDto::from(&view_model)).unwrap();
+        assert_eq!(json["flight_number"], "AAL100");
+        assert_eq!(json["status"], "EnRoute");
+        assert_eq!(json["phase"], "Cruise");
+        assert_eq!(json["progress_percent"], 50);
+        assert_eq!(json["estimated_arrival"], serde_json::Value::Null);
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt::Debug;
+
+/// Banner shown once the API keeps refusing requests for quota reasons
+pub const QUOTA_BANNER: &str =
+    "API quota likely exceeded — upgrade your FlightAware plan or increase --refresh-interval";
+
+/// Message for a request that never got a usable answer from FlightAware
+pub const UNREACHABLE_MESSAGE: &str =
+    "Couldn't reach FlightAware — check your connection and API key";
+
+/// Consecutive quota errors before the banner is shown, so a single throttled request
+/// doesn't raise a false alarm
+const QUOTA_ERRORS_BEFORE_BANNER: u32 = 2;
+
+/// Broad category of a failed FlightAware request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchErrorKind {
+    /// Rate limited (HTTP 429) or the response body mentions the quota or rate limit
+    QuotaExceeded,
+    /// The request never reached FlightAware, or FlightAware rejected the API key
+    Unreachable,
+    Other,
+}
+
+impl FetchErrorKind {
+    /// What to tell the user about a failed request
+    pub fn message(self) -> &'static str {
+        match self {
+            FetchErrorKind::QuotaExceeded => QUOTA_BANNER,
+            FetchErrorKind::Unreachable => UNREACHABLE_MESSAGE,
+            FetchErrorKind::Other => "FlightAware returned an error",
+        }
+    }
+}
+
+/// Classify a failed request from the FlightAware client
+pub fn classify_fetch_error<E: Debug>(error: &flightaware::Error<E>) -> FetchErrorKind {
+    match error.status() {
+        Some(reqwest::StatusCode::TOO_MANY_REQUESTS) => return FetchErrorKind::QuotaExceeded,
+        Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN) => {
+            return FetchErrorKind::Unreachable;
+        }
+        _ => {}
+    }
+
+    let body = match error {
+        flightaware::Error::ErrorResponse(response) => format!("{:?}", &**response),
+        flightaware::Error::InvalidResponsePayload(bytes, _) => {
+            String::from_utf8_lossy(bytes).into_owned()
+        }
+        flightaware::Error::CommunicationError(_) => return FetchErrorKind::Unreachable,
+        _ => return FetchErrorKind::Other,
+    };
+
+    if mentions_quota(&body) {
+        FetchErrorKind::QuotaExceeded
+    } else {
+        FetchErrorKind::Other
+    }
+}
+
+fn mentions_quota(body: &str) -> bool {
+    let body = body.to_lowercase();
+    body.contains("quota") || body.contains("rate limit")
+}
+
+/// Counts consecutive quota errors across refreshes to decide when to show the banner
+#[derive(Debug, Default)]
+pub struct QuotaTracker {
+    consecutive_errors: u32,
+}
+
+impl QuotaTracker {
+    pub fn record_error(&mut self, kind: FetchErrorKind) {
+        match kind {
+            FetchErrorKind::QuotaExceeded => self.consecutive_errors += 1,
+            FetchErrorKind::Unreachable | FetchErrorKind::Other => self.consecutive_errors = 0,
+        }
+    }
+
+    pub fn record_success(&mut self) {
+        self.consecutive_errors = 0;
+    }
+
+    pub fn banner(&self) -> Option<&'static str> {
+        (self.consecutive_errors >= QUOTA_ERRORS_BEFORE_BANNER).then_some(QUOTA_BANNER)
+    }
+}
+
+/// A client for a local port nothing listens on, so every request fails to connect
+#[cfg(test)]
+pub(crate) fn unreachable_client() -> flightaware::Client {
+    // Nothing listens on the port once the listener is dropped
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    flightaware::Client::new(&format!("http://127.0.0.1:{}", port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+
+    /// Classify the error from a request the mock server answers with `status` and `body`
+    async fn classify_response(status: u16, body: &str) -> FetchErrorKind {
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/flights/AA100");
+                then.status(status).body(body);
+            })
+            .await;
+
+        let client = flightaware::Client::new(&server.base_url());
+        match client
+            .get_flight("AA100", None, None, None, None, None)
+            .await
+        {
+            Err(error) => classify_fetch_error(&error),
+            Ok(_) => panic!("Expected the request to fail"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_classify_rate_limited_response() {
+        assert_eq!(
+            classify_response(429, "Too Many Requests").await,
+            FetchErrorKind::QuotaExceeded
+        );
+    }
+
+    #[tokio::test]
+    async fn test_classify_server_error() {
+        assert_eq!(
+            classify_response(500, "Internal Server Error").await,
+            FetchErrorKind::Other
+        );
+    }
+
+    #[tokio::test]
+    async fn test_classify_rejected_api_key() {
+        assert_eq!(
+            classify_response(401, "Unauthorized").await,
+            FetchErrorKind::Unreachable
+        );
+    }
+
+    #[tokio::test]
+    async fn test_classify_connection_failure() {
+        match unreachable_client()
+            .get_flight("AA100", None, None, None, None, None)
+            .await
+        {
+            Err(error) => assert_eq!(classify_fetch_error(&error), FetchErrorKind::Unreachable),
+            Ok(_) => panic!("Expected the request to fail"),
+        }
+    }
+
+    #[test]
+    fn test_mentions_quota() {
+        assert!(mentions_quota(r#"{"title": "Quota Exceeded"}"#));
+        assert!(mentions_quota("Rate limit reached for this key"));
+        assert!(!mentions_quota(r#"{"title": "Not Found"}"#));
+    }
+
+    #[test]
+    fn test_quota_tracker_needs_repeated_errors() {
+        let mut tracker = QuotaTracker::default();
+        assert_eq!(tracker.banner(), None);
+
+        tracker.record_error(FetchErrorKind::QuotaExceeded);
+        assert_eq!(tracker.banner(), None);
+
+        tracker.record_error(FetchErrorKind::QuotaExceeded);
+        assert_eq!(tracker.banner(), Some(QUOTA_BANNER));
+
+        tracker.record_success();
+        assert_eq!(tracker.banner(), None);
+    }
+
+    #[test]
+    fn test_quota_tracker_resets_on_other_errors() {
+        let mut tracker = QuotaTracker::default();
+        tracker.record_error(FetchErrorKind::QuotaExceeded);
+        tracker.record_error(FetchErrorKind::Other);
+        tracker.record_error(FetchErrorKind::QuotaExceeded);
+
+        assert_eq!(tracker.banner(), None);
+    }
+}
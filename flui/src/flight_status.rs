@@ -1,13 +1,16 @@
 use derive_builder::Builder;
+use serde::Serialize;
 use std::fmt;
 
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub enum FlightStatus {
     #[default]
     OnTime,
     Delayed,
     Cancelled,
     EnRoute,
+    /// Expected to arrive well ahead of schedule
+    Early,
 }
 
 impl fmt::Display for FlightStatus {
@@ -17,6 +20,55 @@ impl fmt::Display for FlightStatus {
             FlightStatus::Delayed => write!(f, "Delayed"),
             FlightStatus::Cancelled => write!(f, "Cancelled"),
             FlightStatus::EnRoute => write!(f, "En Route"),
+            FlightStatus::Early => write!(f, "Early"),
+        }
+    }
+}
+
+/// What counts as the moment a flight departs: pushback from the gate (`actual_out`) or
+/// wheels off the runway (`actual_off`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum DepartureReference {
+    Gate,
+    #[default]
+    Runway,
+}
+
+impl std::str::FromStr for DepartureReference {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "gate" => Ok(DepartureReference::Gate),
+            "runway" => Ok(DepartureReference::Runway),
+            _ => Err(format!(
+                "unknown departure reference '{}', expected gate or runway",
+                value
+            )),
+        }
+    }
+}
+
+/// What counts as the moment a flight arrives: wheels down on the runway (`actual_on`) or
+/// reaching the gate (`actual_in`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum ArrivalDefinition {
+    Touchdown,
+    #[default]
+    Gate,
+}
+
+impl std::str::FromStr for ArrivalDefinition {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "touchdown" => Ok(ArrivalDefinition::Touchdown),
+            "gate" => Ok(ArrivalDefinition::Gate),
+            _ => Err(format!(
+                "unknown arrival definition '{}', expected touchdown or gate",
+                value
+            )),
         }
     }
 }
@@ -25,6 +77,10 @@ impl From<FlightStatusViewModel> for FlightStatusViewModelBuilder {
     fn from(view_model: FlightStatusViewModel) -> Self {
         let mut builder = FlightStatusViewModelBuilder::default();
         builder.flight_number(view_model.flight_number);
+        builder.fa_flight_id(view_model.fa_flight_id);
+        builder.ident_iata(view_model.ident_iata);
+        builder.ident_icao(view_model.ident_icao);
+        builder.atc_ident(view_model.atc_ident);
         builder.status(view_model.status);
         builder.scheduled_departure(view_model.scheduled_departure);
         builder.scheduled_arrival(view_model.scheduled_arrival);
@@ -32,17 +88,48 @@ impl From<FlightStatusViewModel> for FlightStatusViewModelBuilder {
         builder.estimated_arrival(view_model.estimated_arrival);
         builder.actual_departure(view_model.actual_departure);
         builder.actual_arrival(view_model.actual_arrival);
+        builder.scheduled_gate_departure(view_model.scheduled_gate_departure);
+        builder.scheduled_gate_arrival(view_model.scheduled_gate_arrival);
+        builder.estimated_gate_departure(view_model.estimated_gate_departure);
+        builder.estimated_gate_arrival(view_model.estimated_gate_arrival);
+        builder.actual_gate_departure(view_model.actual_gate_departure);
+        builder.actual_gate_arrival(view_model.actual_gate_arrival);
+        builder.departure_delay(view_model.departure_delay);
+        builder.arrival_delay(view_model.arrival_delay);
+        builder.foresight_predictions_available(view_model.foresight_predictions_available);
+        builder.baggage_claim(view_model.baggage_claim);
+        builder.gate_origin(view_model.gate_origin);
+        builder.gate_destination(view_model.gate_destination);
+        builder.terminal_destination(view_model.terminal_destination);
+        builder.inbound_fa_flight_id(view_model.inbound_fa_flight_id);
+        builder.operator_icao(view_model.operator_icao);
+        builder.operator_iata(view_model.operator_iata);
         builder.progress_percent(view_model.progress_percent);
+        builder.route_distance(view_model.route_distance);
+        builder.seats_cabin_first(view_model.seats_cabin_first);
+        builder.seats_cabin_business(view_model.seats_cabin_business);
+        builder.seats_cabin_coach(view_model.seats_cabin_coach);
+        builder.filed_altitude(view_model.filed_altitude);
         builder.origin_airport(view_model.origin_airport);
         builder.destination_airport(view_model.destination_airport);
+        builder.origin_city(view_model.origin_city);
+        builder.destination_city(view_model.destination_city);
+        builder.departure_reference(view_model.departure_reference);
+        builder.arrival_definition(view_model.arrival_definition);
         builder
     }
 }
 
-#[derive(Debug, Clone, Builder, Default)]
+#[derive(Debug, Clone, Builder, Default, Serialize)]
 #[builder(setter(into), default)]
 pub struct FlightStatusViewModel {
     pub flight_number: String,
+    /// FlightAware's id for this leg
+    pub fa_flight_id: Option<String>,
+    pub ident_iata: Option<String>,
+    pub ident_icao: Option<String>,
+    /// Callsign used with air traffic control, only when it differs from the ident
+    pub atc_ident: Option<String>,
     pub status: FlightStatus,
     pub scheduled_departure: Option<String>,
     pub scheduled_arrival: Option<String>,
@@ -50,100 +137,955 @@ pub struct FlightStatusViewModel {
     pub estimated_arrival: Option<String>,
     pub actual_departure: Option<String>,
     pub actual_arrival: Option<String>,
+    // Gate (out/in) times, as opposed to the runway (off/on) times above
+    pub scheduled_gate_departure: Option<String>,
+    pub scheduled_gate_arrival: Option<String>,
+    pub estimated_gate_departure: Option<String>,
+    pub estimated_gate_arrival: Option<String>,
+    pub actual_gate_departure: Option<String>,
+    pub actual_gate_arrival: Option<String>,
+    /// Delays in seconds as reported by the API (negative when early)
+    pub departure_delay: Option<i64>,
+    pub arrival_delay: Option<i64>,
+    /// FlightAware has Foresight (predictive) data for this flight
+    pub foresight_predictions_available: bool,
+    /// Baggage claim carousel at the destination
+    pub baggage_claim: Option<String>,
+    /// Departure gate at the origin
+    pub gate_origin: Option<String>,
+    /// Arrival gate and terminal at the destination
+    pub gate_destination: Option<String>,
+    pub terminal_destination: Option<String>,
+    /// FlightAware id of the flight bringing in this flight's aircraft
+    pub inbound_fa_flight_id: Option<String>,
+    /// Airline actually flying the aircraft, which differs from the ident's airline on codeshares
+    pub operator_icao: Option<String>,
+    pub operator_iata: Option<String>,
     pub progress_percent: Option<i64>,
+    /// Planned distance along the filed route, in statute miles
+    pub route_distance: Option<i64>,
+    /// Seats per cabin on the aircraft
+    pub seats_cabin_first: Option<i64>,
+    pub seats_cabin_business: Option<i64>,
+    pub seats_cabin_coach: Option<i64>,
+    /// Filed cruise altitude, in hundreds of feet
+    pub filed_altitude: Option<i64>,
     pub origin_airport: Option<String>,
     pub destination_airport: Option<String>,
+    pub origin_city: Option<String>,
+    pub destination_city: Option<String>,
+    /// Whether the flight counts as departed from pushback or from takeoff, for its status,
+    /// departure time and time-based progress
+    pub departure_reference: DepartureReference,
+    /// Whether the flight counts as arrived at touchdown or at the gate, for its status, the
+    /// countdown and the landing alert
+    pub arrival_definition: ArrivalDefinition,
+}
+
+/// `time` qualified with its day relative to `now`'s on the same clock, named by `day_name`
+fn format_with_relative_day<Tz: chrono::TimeZone>(
+    time: chrono::DateTime<Tz>,
+    now: chrono::DateTime<Tz>,
+    day_name: impl Fn(RelativeDay) -> String,
+) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    let day = match (time.date_naive() - now.date_naive()).num_days() {
+        -1 => RelativeDay::Yesterday,
+        0 => RelativeDay::Today,
+        1 => RelativeDay::Tomorrow,
+        _ => return time.format("%b %-d, %Y at %-I:%M %p %Z").to_string(),
+    };
+    format!("{} {}", day_name(day), time.format("%-I:%M %p %Z"))
 }
 
 impl FlightStatusViewModel {
+    /// The actual departure if the flight has left, otherwise the estimate, both going by the
+    /// departure reference
     pub fn departure_time(&self) -> Option<&str> {
-        self.actual_departure
-            .as_deref()
-            .or(self.estimated_departure.as_deref())
+        let estimated = match self.departure_reference {
+            DepartureReference::Gate => self
+                .estimated_gate_departure
+                .as_deref()
+                .or(self.estimated_departure.as_deref()),
+            DepartureReference::Runway => self.estimated_departure.as_deref(),
+        };
+        self.actual_departure_time().or(estimated)
+    }
+
+    /// When the flight departed going by the departure reference; a flight that has taken off
+    /// has also left the gate, so takeoff stands in for a missing pushback time
+    pub fn actual_departure_time(&self) -> Option<&str> {
+        match self.departure_reference {
+            DepartureReference::Gate => self
+                .actual_gate_departure
+                .as_deref()
+                .or(self.actual_departure.as_deref()),
+            DepartureReference::Runway => self.actual_departure.as_deref(),
+        }
+    }
+
+    /// Count the flight as departed from pushback or from takeoff
+    /// The API's status goes by takeoff, so with the gate reference a flight that has pushed
+    /// back but not landed is en route too
+    pub fn with_departure_reference(mut self, reference: DepartureReference) -> Self {
+        self.departure_reference = reference;
+        self.update_en_route_status(chrono::Utc::now());
+        self
+    }
+
+    /// Count the flight as arrived at touchdown or at the gate
+    /// The status is en route until then, so a flight between touchdown and the gate is still en
+    /// route with the gate definition
+    pub fn with_arrival_definition(mut self, definition: ArrivalDefinition) -> Self {
+        self.arrival_definition = definition;
+        self.update_en_route_status(chrono::Utc::now());
+        self
+    }
+
+    /// Departed going by the departure reference and not yet arrived going by the arrival
+    /// definition
+    pub(crate) fn is_en_route_at(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.actual_departure_time().is_some() && self.arrived_at(now).is_none()
+    }
+
+    /// Bring the status in line with the departure reference and arrival definition: en route
+    /// from departure until arrival, then back to on time, delayed or early by the delays
+    fn update_en_route_status(&mut self, now: chrono::DateTime<chrono::Utc>) {
+        let arrived = self.arrived_at(now).is_some();
+        match self.status {
+            FlightStatus::EnRoute if arrived => {
+                self.status = crate::api_converter::status_from_delays(
+                    self.departure_delay,
+                    self.arrival_delay,
+                );
+            }
+            FlightStatus::OnTime | FlightStatus::Delayed | FlightStatus::Early
+                if self.is_en_route_at(now) =>
+            {
+                self.status = FlightStatus::EnRoute;
+            }
+            _ => {}
+        }
     }
 
     pub fn arrival_time(&self) -> Option<&str> {
-        self.actual_arrival
-            .as_deref()
+        self.landed_at(chrono::Utc::now())
             .or(self.estimated_arrival.as_deref())
     }
 
-    /// Format arrival time for display in local timezone
-    /// Returns a human-readable formatted time string
-    pub fn formatted_arrival_time(&self) -> Option<String> {
-        use chrono::{DateTime, Local, Utc};
+    /// The recorded landing time, unless it's more than a minute in the future: the API
+    /// occasionally blends a prediction into the actual time, and that flight hasn't landed yet
+    /// A time that doesn't parse is taken at its word
+    fn landed_at(&self, now: chrono::DateTime<chrono::Utc>) -> Option<&str> {
+        let arrival = self.actual_arrival.as_deref()?;
+        match arrival.parse::<chrono::DateTime<chrono::Utc>>() {
+            Ok(time)
+                if time.signed_duration_since(now).num_seconds()
+                    > FUTURE_ACTUAL_ARRIVAL_TOLERANCE_SECONDS =>
+            {
+                None
+            }
+            _ => Some(arrival),
+        }
+    }
+
+    /// When the flight arrived going by the arrival definition, or None if it hasn't yet
+    /// With the gate definition a flight that never reports reaching the gate is taken to be in
+    /// once it's complete, timed from its landing
+    fn arrived_at(&self, now: chrono::DateTime<chrono::Utc>) -> Option<&str> {
+        match self.arrival_definition {
+            ArrivalDefinition::Touchdown => {
+                self.landed_at(now).or(self.actual_gate_arrival.as_deref())
+            }
+            ArrivalDefinition::Gate if self.is_complete_at(now) => self
+                .actual_gate_arrival
+                .as_deref()
+                .or(self.actual_arrival.as_deref()),
+            ArrivalDefinition::Gate => None,
+        }
+    }
+
+    /// Down on the runway but, going by the arrival definition, not yet in, with no estimate of
+    /// when it reaches the gate, so the countdown reads "Landed"
+    pub fn is_taxiing_in(&self) -> bool {
+        self.is_taxiing_in_at(chrono::Utc::now())
+    }
+
+    fn is_taxiing_in_at(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.arrived_at(now).is_none()
+            && self.landed_at(now).is_some()
+            && self.estimated_gate_arrival.is_none()
+    }
+
+    /// The arrival time to display along with the field it came from: actual if the flight has
+    /// landed, otherwise estimated, falling back to the schedule when there's no estimate
+    pub fn arrival_time_with_source(&self) -> Option<(&str, TimeSource)> {
+        let sources = [
+            (self.landed_at(chrono::Utc::now()), TimeSource::Actual),
+            (self.estimated_arrival.as_deref(), TimeSource::Estimated),
+            (self.scheduled_arrival.as_deref(), TimeSource::Scheduled),
+        ];
+        sources
+            .into_iter()
+            .find_map(|(time, source)| Some((time?, source)))
+    }
+
+    /// Pushback from the departure gate, actual if known otherwise estimated
+    pub fn gate_departure_time(&self) -> Option<&str> {
+        self.actual_gate_departure
+            .as_deref()
+            .or(self.estimated_gate_departure.as_deref())
+    }
+
+    /// Arrival at the destination gate, actual if known otherwise estimated
+    pub fn gate_arrival_time(&self) -> Option<&str> {
+        self.actual_gate_arrival
+            .as_deref()
+            .or(self.estimated_gate_arrival.as_deref())
+    }
+
+    /// Format arrival time for display in `timezone`, or the local timezone when it's None
+    /// Arrivals on the day before, of or after today on that clock are qualified with
+    /// "Yesterday", "Today" or "Tomorrow", e.g. "Tomorrow 6:15 AM EST"; any other day gets
+    /// the full date, e.g. "Nov 18, 2025 at 2:30 PM EST"
+    pub fn formatted_arrival_time(&self, timezone: Option<chrono_tz::Tz>) -> Option<String> {
+        self.localized_arrival_time(timezone, |day| day.to_string())
+    }
+
+    /// [`Self::formatted_arrival_time`] with the relative day named by `day_name`, for
+    /// languages other than English
+    pub fn localized_arrival_time(
+        &self,
+        timezone: Option<chrono_tz::Tz>,
+        day_name: impl Fn(RelativeDay) -> String,
+    ) -> Option<String> {
+        self.formatted_arrival_time_at(timezone, chrono::Utc::now(), day_name)
+    }
+
+    fn formatted_arrival_time_at(
+        &self,
+        timezone: Option<chrono_tz::Tz>,
+        now: chrono::DateTime<chrono::Utc>,
+        day_name: impl Fn(RelativeDay) -> String,
+    ) -> Option<String> {
+        let (time_str, _) = self.arrival_time_with_source()?;
+        let arrival: chrono::DateTime<chrono::Utc> = time_str.parse().ok()?;
+
+        // Today is judged on the display clock, not UTC's, so a 1 AM arrival is tomorrow's
+        // even while it's still the same day in UTC
+        Some(match timezone {
+            Some(timezone) => format_with_relative_day(
+                arrival.with_timezone(&timezone),
+                now.with_timezone(&timezone),
+                day_name,
+            ),
+            None => format_with_relative_day(
+                arrival.with_timezone(&chrono::Local),
+                now.with_timezone(&chrono::Local),
+                day_name,
+            ),
+        })
+    }
+
+    /// Arrival time on the destination's clock, e.g. "11:30 AM PST at SEA"
+    /// Returns None when the destination's timezone isn't known or keeps the same time as here
+    pub fn formatted_destination_arrival_time(&self) -> Option<String> {
+        use chrono::Offset;
+
+        let (arrival, _) = self.arrival_time_with_source()?;
+        let arrival: chrono::DateTime<chrono::Utc> = arrival.parse().ok()?;
+        let local_offset = arrival.with_timezone(&chrono::Local).offset().fix();
+        self.formatted_destination_arrival_time_from(local_offset)
+    }
+
+    fn formatted_destination_arrival_time_from(
+        &self,
+        local_offset: chrono::FixedOffset,
+    ) -> Option<String> {
+        use chrono::Offset;
+
+        let destination = self.destination_airport.as_deref()?;
+        let timezone = crate::timezones::timezone_for_airport(destination)?;
+        let (arrival, _) = self.arrival_time_with_source()?;
+        let arrival: chrono::DateTime<chrono::Utc> = arrival.parse().ok()?;
+        let destination_time = arrival.with_timezone(&timezone);
+        if destination_time.offset().fix() == local_offset {
+            return None;
+        }
+
+        Some(format!(
+            "{} at {}",
+            destination_time.format("%-I:%M %p %Z"),
+            destination
+        ))
+    }
+
+    /// The arrival time on the origin's, the destination's and the local clock, each labeled
+    /// with the airport code, or None for the local clock; an airport whose timezone isn't
+    /// known is left out
+    pub fn arrival_times_multi(&self) -> Vec<(Option<String>, String)> {
+        let Some(arrival) = self
+            .arrival_time_with_source()
+            .and_then(|(arrival, _)| arrival.parse::<chrono::DateTime<chrono::Utc>>().ok())
+        else {
+            return Vec::new();
+        };
+
+        let mut times: Vec<(Option<String>, String)> =
+            [&self.origin_airport, &self.destination_airport]
+                .into_iter()
+                .flatten()
+                .filter_map(|airport| {
+                    let timezone = crate::timezones::timezone_for_airport(airport)?;
+                    let time = arrival.with_timezone(&timezone).format("%H:%M");
+                    Some((Some(airport.clone()), time.to_string()))
+                })
+                .collect();
+        times.push((
+            None,
+            arrival
+                .with_timezone(&chrono::Local)
+                .format("%H:%M")
+                .to_string(),
+        ));
+        times
+    }
 
-        let time_str = self.arrival_time()?;
+    /// Gate-to-gate duration in the schedule
+    pub fn scheduled_block_time(&self) -> Option<chrono::Duration> {
+        block_time(
+            self.scheduled_gate_departure.as_deref(),
+            self.scheduled_gate_arrival.as_deref(),
+        )
+    }
 
-        // Parse the ISO 8601 timestamp
-        let utc_time: DateTime<Utc> = time_str.parse().ok()?;
+    /// Gate-to-gate duration from actual or estimated times, falling back to the schedule for
+    /// whichever end has neither
+    pub fn current_block_time(&self) -> Option<chrono::Duration> {
+        block_time(
+            self.gate_departure_time()
+                .or(self.scheduled_gate_departure.as_deref()),
+            self.gate_arrival_time()
+                .or(self.scheduled_gate_arrival.as_deref()),
+        )
+    }
 
-        // Convert to local timezone
-        let local_time: DateTime<Local> = utc_time.into();
+    /// Whether the flight departs and arrives at the same airport (training, ferry, or pattern work)
+    pub fn is_local_flight(&self) -> bool {
+        matches!(
+            (&self.origin_airport, &self.destination_airport),
+            (Some(origin), Some(destination)) if origin == destination
+        )
+    }
 
-        // Format as: "Nov 18, 2025 at 2:30 PM EST"
-        Some(local_time.format("%b %-d, %Y at %-I:%M %p %Z").to_string())
+    /// The larger of the departure and arrival delays, in whole minutes
+    pub fn delay_minutes(&self) -> Option<i64> {
+        self.departure_delay
+            .into_iter()
+            .chain(self.arrival_delay)
+            .max()
+            .map(|seconds| seconds / 60)
     }
 
     pub fn progress_percentage(&self) -> f64 {
         self.progress_percent.map(|p| p as f64).unwrap_or(0.0)
     }
 
+    /// Share of the flight flown going by the clock: time since departure over the time from
+    /// departure to the arrival time, as a percentage
+    /// Departure is takeoff, or pushback with the gate reference
+    /// Returns None before departure or without an arrival time to measure against
+    pub fn time_based_progress(&self) -> Option<f64> {
+        self.time_based_progress_at(chrono::Utc::now())
+    }
+
+    fn time_based_progress_at(&self, now: chrono::DateTime<chrono::Utc>) -> Option<f64> {
+        let departure: chrono::DateTime<chrono::Utc> =
+            self.actual_departure_time()?.parse().ok()?;
+        let (arrival, _) = self.arrival_time_with_source()?;
+        let arrival: chrono::DateTime<chrono::Utc> = arrival.parse().ok()?;
+
+        let total = arrival.signed_duration_since(departure).num_seconds();
+        if total <= 0 {
+            return None;
+        }
+        let elapsed = now.signed_duration_since(departure).num_seconds();
+        Some((elapsed as f64 / total as f64 * 100.0).clamp(0.0, 100.0))
+    }
+
+    /// How far the progress reported by the API is from time-based progress, in percentage
+    /// points; a large gap hints at lagging data or a diversion
+    pub fn progress_discrepancy(&self) -> Option<f64> {
+        self.progress_discrepancy_at(chrono::Utc::now())
+    }
+
+    fn progress_discrepancy_at(&self, now: chrono::DateTime<chrono::Utc>) -> Option<f64> {
+        let reported = self.progress_percent? as f64;
+        Some((reported - self.time_based_progress_at(now)?).abs())
+    }
+
+    /// The flight's live page on FlightAware's website, to open or share
+    /// Links to this leg by its FlightAware id when known, otherwise to the flight number's
+    /// latest leg
+    pub fn flightaware_url(&self) -> Option<String> {
+        match &self.fa_flight_id {
+            Some(fa_flight_id) => Some(format!(
+                "{}/live/flight/id/{}",
+                FLIGHTAWARE_WEB_URL, fa_flight_id
+            )),
+            None if !self.flight_number.is_empty() => Some(format!(
+                "{}/live/flight/{}",
+                FLIGHTAWARE_WEB_URL, self.flight_number
+            )),
+            None => None,
+        }
+    }
+
+    /// The flight's current phase, in words through its Display impl
+    /// Climb and descent are judged from progress alone, as the API doesn't report vertical rate
+    /// here
+    pub fn phase(&self) -> FlightPhase {
+        self.phase_at(chrono::Utc::now())
+    }
+
+    fn phase_at(&self, now: chrono::DateTime<chrono::Utc>) -> FlightPhase {
+        if self.actual_gate_arrival.is_some() {
+            FlightPhase::AtGate
+        } else if self.landed_at(now).is_some() {
+            FlightPhase::Landed
+        } else if self.actual_departure.is_some() {
+            match self.progress_percent.unwrap_or_default() {
+                progress if progress < CLIMB_PROGRESS_PERCENT => FlightPhase::Climb,
+                progress if progress > DESCENT_PROGRESS_PERCENT => FlightPhase::Descent,
+                _ => FlightPhase::Cruise,
+            }
+        } else if self.actual_gate_departure.is_some() {
+            FlightPhase::Taxiing
+        } else {
+            FlightPhase::PreDeparture
+        }
+    }
+
+    /// Whether the flight has finished and will see no further updates: it has reached the gate,
+    /// or landed at least 30 minutes ago
+    pub fn is_complete(&self) -> bool {
+        self.is_complete_at(chrono::Utc::now())
+    }
+
+    fn is_complete_at(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        if self.actual_gate_arrival.is_some() {
+            return true;
+        }
+        self.actual_arrival
+            .as_deref()
+            .and_then(|landed| landed.parse::<chrono::DateTime<chrono::Utc>>().ok())
+            .is_some_and(|landed| {
+                now.signed_duration_since(landed).num_minutes() >= COMPLETE_AFTER_LANDING_MINUTES
+            })
+    }
+
     /// Calculate time remaining until arrival
-    /// Returns a formatted string like "2h 30m" or None if unavailable
+    /// Counts down to the gate when an estimated gate arrival is known, otherwise to touchdown,
+    /// and stops at whichever the arrival definition counts as arrived
+    /// Returns a formatted string like "2h 30m to gate" or None if unavailable
     pub fn time_remaining(&self) -> Option<String> {
-        use chrono::{DateTime, Utc};
+        self.time_remaining_at(chrono::Utc::now())
+    }
 
+    fn time_remaining_at(&self, now: chrono::DateTime<chrono::Utc>) -> Option<String> {
         // Only calculate if flight hasn't arrived yet
-        if self.actual_arrival.is_some() {
+        if self.arrived_at(now).is_some() {
             return Some("Arrived".to_string());
         }
+        if self.is_taxiing_in_at(now) {
+            return Some("Landed".to_string());
+        }
 
-        let arrival_str = self.estimated_arrival.as_deref()?;
-        let arrival_time: DateTime<Utc> = arrival_str.parse().ok()?;
-        let now = Utc::now();
+        let (arrival_time, target) = self.expected_arrival_at(now)?;
 
         let duration = arrival_time.signed_duration_since(now);
 
+        // The ETA has passed without a recorded landing (e.g. holding), so it hasn't arrived
         if duration.num_seconds() < 0 {
-            return Some("Arrived".to_string());
+            return Some("Overdue".to_string());
+        }
+
+        Some(format_time_remaining(duration, arrival_time, target))
+    }
+
+    /// The expected arrival as a 24-hour clock time, e.g. "arrives 14:30", for when a fixed
+    /// time reads better than a countdown
+    /// Shown in `timezone`, or the local timezone when it's None; counts to the same gate or
+    /// touchdown time as `time_remaining`
+    pub fn arrival_clock(&self, timezone: Option<chrono_tz::Tz>) -> Option<String> {
+        self.arrival_clock_at(timezone, chrono::Utc::now())
+    }
+
+    fn arrival_clock_at(
+        &self,
+        timezone: Option<chrono_tz::Tz>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<String> {
+        let clock = |time: chrono::DateTime<chrono::Utc>| match timezone {
+            Some(timezone) => time.with_timezone(&timezone).format("%H:%M").to_string(),
+            None => time
+                .with_timezone(&chrono::Local)
+                .format("%H:%M")
+                .to_string(),
+        };
+
+        if let Some(actual_arrival) = self.arrived_at(now) {
+            return Some(match actual_arrival.parse() {
+                Ok(arrived_at) => format!("arrived {}", clock(arrived_at)),
+                Err(_) => "Arrived".to_string(),
+            });
         }
+        if self.is_taxiing_in_at(now) {
+            return Some("Landed".to_string());
+        }
+
+        let (arrival_time, _) = self.expected_arrival_at(now)?;
+        if arrival_time < now {
+            return Some("Overdue".to_string());
+        }
+        Some(format!("arrives {}", clock(arrival_time)))
+    }
 
-        let hours = duration.num_hours();
-        let minutes = (duration.num_minutes() % 60).abs();
+    /// When the flight is expected in and whether that's at the gate or on the runway
+    /// Without any estimate from the API, falls back to extrapolating from progress so far
+    fn expected_arrival_at(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<(chrono::DateTime<chrono::Utc>, &'static str)> {
+        use chrono::{DateTime, Utc};
+
+        let parse = |time: Option<&str>| time.and_then(|time| time.parse::<DateTime<Utc>>().ok());
+
+        match parse(self.estimated_gate_arrival.as_deref()) {
+            Some(gate_arrival) => Some((gate_arrival, "to gate")),
+            None => Some((
+                parse(self.estimated_arrival.as_deref())
+                    .or_else(|| self.implied_arrival_at(now))?,
+                "to touchdown",
+            )),
+        }
+    }
 
-        if hours > 0 {
-            Some(format!("{}h {}m", hours, minutes))
+    /// Countdown to the start of boarding, taken as `offset_minutes` before the estimated
+    /// departure since FlightAware rarely has a boarding time, e.g. "Boarding in 12m"
+    /// Returns None once the flight has departed or boarding should already have started
+    pub fn boarding_in(&self, offset_minutes: i64) -> Option<String> {
+        let countdown = self.boarding_countdown_at(offset_minutes, chrono::Utc::now())?;
+        let hours = countdown.num_hours();
+        let minutes = countdown.num_minutes() % 60;
+        Some(if hours > 0 {
+            format!("Boarding in {}h {}m", hours, minutes)
         } else {
-            Some(format!("{}m", minutes))
+            format!("Boarding in {}m", minutes)
+        })
+    }
+
+    fn boarding_countdown_at(
+        &self,
+        offset_minutes: i64,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<chrono::Duration> {
+        if self.actual_departure.is_some() {
+            return None;
+        }
+        let departure: chrono::DateTime<chrono::Utc> =
+            self.estimated_departure.as_deref()?.parse().ok()?;
+        let boarding = departure - chrono::Duration::minutes(offset_minutes);
+        let countdown = boarding.signed_duration_since(now);
+        (countdown > chrono::Duration::zero()).then_some(countdown)
+    }
+
+    /// Arrival implied by progress so far: a flight 45% done after 90 minutes in the air
+    /// should land about 200 minutes after takeoff
+    /// Measured from pushback instead with the gate reference
+    /// Returns None before departure or while progress is still zero
+    pub fn implied_arrival(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.implied_arrival_at(chrono::Utc::now())
+    }
+
+    fn implied_arrival_at(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        let departure: chrono::DateTime<chrono::Utc> =
+            self.actual_departure_time()?.parse().ok()?;
+        let progress = self.progress_percent.filter(|progress| *progress > 0)?;
+
+        let elapsed = now.signed_duration_since(departure);
+        let total = elapsed * 100 / i32::try_from(progress.min(100)).ok()?;
+        Some(departure + total)
+    }
+
+    /// The operating airline when it isn't the airline of the flight number the user asked for,
+    /// e.g. a flight booked as AA8500 that British Airways flies
+    /// The queried airline is the 3 letter ICAO prefix when there is one, otherwise the 2
+    /// character IATA prefix
+    pub fn operated_by(&self, queried_ident: &str) -> Option<String> {
+        let queried_ident = queried_ident.trim().to_uppercase();
+        let icao_prefix = queried_ident
+            .get(..3)
+            .filter(|prefix| prefix.chars().all(|c| c.is_ascii_alphabetic()));
+
+        let matches_query = match icao_prefix {
+            Some(prefix) => self.operator_icao.as_deref()?.eq_ignore_ascii_case(prefix),
+            None => self
+                .operator_iata
+                .as_deref()?
+                .eq_ignore_ascii_case(queried_ident.get(..2)?),
+        };
+        if matches_query {
+            return None;
+        }
+
+        // The airline's name when it's known, otherwise its code
+        let codes = [self.operator_iata.as_deref(), self.operator_icao.as_deref()];
+        let operator = codes
+            .iter()
+            .flatten()
+            .find_map(|code| crate::airlines::airline_name(code))
+            .or(codes.into_iter().flatten().next())?;
+        Some(format!("Operated by {}", operator))
+    }
+
+    /// Average ground speed in knots since takeoff, from the share of the route flown so far
+    /// Returns None before takeoff, without a route distance, or when no time has passed
+    pub fn average_speed_kt(&self) -> Option<f64> {
+        self.average_speed_kt_at(chrono::Utc::now())
+    }
+
+    fn average_speed_kt_at(&self, now: chrono::DateTime<chrono::Utc>) -> Option<f64> {
+        const KNOTS_PER_MPH: f64 = 0.868_976;
+
+        let departure: chrono::DateTime<chrono::Utc> =
+            self.actual_departure.as_deref()?.parse().ok()?;
+        // Once landed, the average covers the whole flight
+        let end = match self.actual_arrival.as_deref() {
+            Some(arrival) => arrival.parse().ok()?,
+            None => now,
+        };
+        let hours = end.signed_duration_since(departure).num_seconds() as f64 / 3600.0;
+        if hours <= 0.0 {
+            return None;
+        }
+
+        let distance_flown =
+            self.route_distance? as f64 * self.progress_percent?.clamp(0, 100) as f64 / 100.0;
+        Some(distance_flown * KNOTS_PER_MPH / hours)
+    }
+
+    /// Origin and destination with their cities, e.g. "San Francisco (SFO) → Los Angeles (LAX)"
+    /// Returns None unless FlightAware names at least one of the cities
+    pub fn route_text(&self) -> Option<String> {
+        if self.origin_city.is_none() && self.destination_city.is_none() {
+            return None;
+        }
+
+        let endpoint = |city: &Option<String>, code: &Option<String>| {
+            let code = code.as_deref().unwrap_or("???");
+            match city {
+                Some(city) => format!("{} ({})", city, code),
+                None => code.to_string(),
+            }
+        };
+        Some(format!(
+            "{} → {}",
+            endpoint(&self.origin_city, &self.origin_airport),
+            endpoint(&self.destination_city, &self.destination_airport)
+        ))
+    }
+
+    /// Seats per cabin with their fare class letters, e.g. "Cabin: 12F / 48J / 180Y",
+    /// listing only the cabins FlightAware reports
+    pub fn cabin_text(&self) -> Option<String> {
+        let cabins: Vec<String> = [
+            (self.seats_cabin_first, 'F'),
+            (self.seats_cabin_business, 'J'),
+            (self.seats_cabin_coach, 'Y'),
+        ]
+        .into_iter()
+        .filter_map(|(seats, class)| seats.map(|seats| format!("{}{}", seats, class)))
+        .collect();
+
+        (!cabins.is_empty()).then(|| format!("Cabin: {}", cabins.join(" / ")))
+    }
+
+    /// Filed cruise altitude, e.g. "Cruise: FL350"
+    pub fn cruise_altitude_text(&self) -> Option<String> {
+        self.filed_altitude
+            .map(|altitude| format!("Cruise: {}", format_altitude(altitude * 100)))
+    }
+
+    /// Where to collect bags, shown only once the flight has landed
+    pub fn baggage_claim_text(&self) -> Option<String> {
+        self.actual_arrival.as_ref()?;
+        self.baggage_claim
+            .as_deref()
+            .map(|carousel| format!("Baggage: Carousel {}", carousel))
+    }
+
+    /// Terminal and gate the flight arrives at, e.g. "T2·B12", shown only once it has landed
+    /// A terminal that doesn't already start with T (like LAX's "TBIT") gets one; returns None
+    /// when neither is known
+    pub fn arrival_gate_text(&self) -> Option<String> {
+        self.actual_arrival.as_ref()?;
+        let terminal = self.terminal_destination.as_deref().map(|terminal| {
+            if terminal.starts_with(['T', 't']) {
+                terminal.to_string()
+            } else {
+                format!("T{}", terminal)
+            }
+        });
+        match (terminal, self.gate_destination.as_deref()) {
+            (Some(terminal), Some(gate)) => Some(format!("{}·{}", terminal, gate)),
+            (Some(terminal), None) => Some(terminal),
+            (None, Some(gate)) => Some(gate.to_string()),
+            (None, None) => None,
         }
     }
 
-    /// Check if the flight is approaching landing (within threshold minutes)
+    /// Whether the estimated arrival has passed but no landing has been recorded
+    pub fn is_overdue(&self) -> bool {
+        use chrono::{DateTime, Utc};
+
+        let now = Utc::now();
+        self.landed_at(now).is_none()
+            && self
+                .estimated_arrival
+                .as_deref()
+                .and_then(|arrival| arrival.parse::<DateTime<Utc>>().ok())
+                .is_some_and(|arrival| arrival < now)
+    }
+
+    /// Check if the flight is approaching arrival (within threshold minutes)
+    /// Counts down to the gate with the gate arrival definition, otherwise to touchdown
     pub fn is_approaching_landing(&self, threshold_minutes: i64) -> bool {
+        self.is_approaching_landing_at(threshold_minutes, chrono::Utc::now())
+    }
+
+    fn is_approaching_landing_at(
+        &self,
+        threshold_minutes: i64,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
         use chrono::{DateTime, Utc};
 
-        // Already landed
-        if self.actual_arrival.is_some() {
+        // Already arrived
+        if self.arrived_at(now).is_some() {
             return false;
         }
 
-        let arrival_str = match self.estimated_arrival.as_deref() {
-            Some(s) => s,
-            None => return false,
+        let target = match self.arrival_definition {
+            ArrivalDefinition::Gate => self
+                .estimated_gate_arrival
+                .as_deref()
+                .or(self.estimated_arrival.as_deref()),
+            ArrivalDefinition::Touchdown => self.estimated_arrival.as_deref(),
         };
-
-        let arrival_time: DateTime<Utc> = match arrival_str.parse() {
-            Ok(t) => t,
-            Err(_) => return false,
+        let arrival_time = match target.and_then(|time| time.parse::<DateTime<Utc>>().ok()) {
+            Some(time) => time,
+            None => return false,
         };
 
-        let now = Utc::now();
         let duration = arrival_time.signed_duration_since(now);
 
         // Within threshold and not yet arrived
         duration.num_minutes() > 0 && duration.num_minutes() <= threshold_minutes
     }
+
+    /// Whether the landing alert should be showing for this flight
+    /// Stops showing once the flight has arrived by the arrival definition
+    pub fn is_alert_triggered(&self, trigger: AlertTrigger) -> bool {
+        self.is_alert_triggered_at(trigger, chrono::Utc::now())
+    }
+
+    fn is_alert_triggered_at(
+        &self,
+        trigger: AlertTrigger,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
+        if self.arrived_at(now).is_some() {
+            return false;
+        }
+        match trigger {
+            AlertTrigger::Minutes(threshold_minutes) => {
+                self.is_approaching_landing_at(threshold_minutes, now)
+            }
+            AlertTrigger::Progress(threshold_percent) => self
+                .progress_percent
+                .is_some_and(|progress| progress as f64 >= threshold_percent),
+        }
+    }
+}
+
+/// FlightAware's website, for links to a flight's live page
+const FLIGHTAWARE_WEB_URL: &str = "https://www.flightaware.com";
+
+/// Where the flight is in its journey, from the recorded gate and runway times and, while
+/// airborne, its progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlightPhase {
+    PreDeparture,
+    Taxiing,
+    Climb,
+    Cruise,
+    Descent,
+    Landed,
+    AtGate,
+}
+
+impl fmt::Display for FlightPhase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            FlightPhase::PreDeparture => "Pre-departure",
+            FlightPhase::Taxiing => "Taxiing",
+            FlightPhase::Climb => "Climbing",
+            FlightPhase::Cruise => "Cruising",
+            FlightPhase::Descent => "Descending",
+            FlightPhase::Landed => "Landed",
+            FlightPhase::AtGate => "At gate",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// A day next to today, which arrival times are qualified with instead of their date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeDay {
+    Yesterday,
+    Today,
+    Tomorrow,
+}
+
+impl fmt::Display for RelativeDay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            RelativeDay::Yesterday => "Yesterday",
+            RelativeDay::Today => "Today",
+            RelativeDay::Tomorrow => "Tomorrow",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// Progress below which an airborne flight is taken to be climbing, and above which descending
+const CLIMB_PROGRESS_PERCENT: i64 = 15;
+const DESCENT_PROGRESS_PERCENT: i64 = 85;
+
+/// How long after touchdown a flight with no recorded gate arrival is taken to have reached
+/// the gate, since some airports never report one
+const COMPLETE_AFTER_LANDING_MINUTES: i64 = 30;
+
+/// How far in the future an actual arrival may be before it's treated as a prediction
+const FUTURE_ACTUAL_ARRIVAL_TOLERANCE_SECONDS: i64 = 60;
+
+/// Which of a flight's times a displayed time was taken from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeSource {
+    Actual,
+    Estimated,
+    Scheduled,
+}
+
+/// When the landing alert fires: a fixed time before arrival, or a share of the flight that
+/// scales with its length
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlertTrigger {
+    /// Within this many minutes of the estimated arrival
+    Minutes(i64),
+    /// Once this percentage of the route has been flown
+    Progress(f64),
+}
+
+/// A departure gate reassigned between two updates of the same flight
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GateChange {
+    pub from: String,
+    pub to: String,
+}
+
+impl GateChange {
+    /// Compare the departure gate before and after an update
+    /// A gate being assigned for the first time, or dropped from the data, isn't a change
+    pub fn detect(
+        previous: &FlightStatusViewModel,
+        current: &FlightStatusViewModel,
+    ) -> Option<Self> {
+        let from = previous.gate_origin.as_deref()?;
+        let to = current.gate_origin.as_deref()?;
+        (from != to).then(|| GateChange {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for GateChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Gate changed: {} → {}", self.from, self.to)
+    }
+}
+
+/// Format a countdown: hours and minutes under a day, days and hours under a week,
+/// and the local arrival date beyond that
+fn format_time_remaining(
+    duration: chrono::Duration,
+    arrival_time: chrono::DateTime<chrono::Utc>,
+    target: &str,
+) -> String {
+    use chrono::{DateTime, Local};
+
+    let days = duration.num_days();
+    let hours = duration.num_hours();
+    let minutes = duration.num_minutes() % 60;
+
+    if duration > chrono::Duration::weeks(1) {
+        let local_time: DateTime<Local> = arrival_time.into();
+        format!("on {}", local_time.format("%b %-d"))
+    } else if days > 0 {
+        format!("{}d {}h {}", days, hours % 24, target)
+    } else if hours > 0 {
+        format!("{}h {}m {}", hours, minutes, target)
+    } else {
+        format!("{}m {}", minutes, target)
+    }
+}
+
+fn block_time(
+    gate_departure: Option<&str>,
+    gate_arrival: Option<&str>,
+) -> Option<chrono::Duration> {
+    let gate_departure: chrono::DateTime<chrono::Utc> = gate_departure?.parse().ok()?;
+    let gate_arrival: chrono::DateTime<chrono::Utc> = gate_arrival?.parse().ok()?;
+    Some(gate_arrival.signed_duration_since(gate_departure))
+}
+
+/// Altitudes from here up are flown on standard pressure and given as flight levels
+const TRANSITION_ALTITUDE_FEET: i64 = 18_000;
+
+/// An altitude as pilots would say it: a flight level at or above the transition altitude
+/// ("FL350"), otherwise feet with a thousands separator ("8,000 ft")
+pub fn format_altitude(feet: i64) -> String {
+    if feet >= TRANSITION_ALTITUDE_FEET {
+        return format!("FL{:03}", feet / 100);
+    }
+
+    let digits = feet.unsigned_abs().to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    let sign = if feet < 0 { "-" } else { "" };
+    format!("{}{} ft", sign, grouped)
 }
 
 #[cfg(test)]
@@ -172,6 +1114,7 @@ mod tests {
             progress_percent: Some(50),
             origin_airport: None,
             destination_airport: None,
+            ..Default::default()
         };
 
         assert_eq!(view_model.departure_time(), Some("10:20"));
@@ -191,6 +1134,7 @@ mod tests {
             progress_percent: Some(50),
             origin_airport: None,
             destination_airport: None,
+            ..Default::default()
         };
 
         assert_eq!(view_model.departure_time(), Some("10:15"));
@@ -210,11 +1154,45 @@ mod tests {
             progress_percent: Some(100),
             origin_airport: None,
             destination_airport: None,
+            ..Default::default()
         };
 
         assert_eq!(view_model.arrival_time(), Some("14:25"));
     }
 
+    #[test]
+    fn test_arrival_time_with_source() {
+        let scheduled_only = FlightStatusViewModel {
+            scheduled_arrival: Some("14:00".to_string()),
+            ..Default::default()
+        };
+        let estimated = FlightStatusViewModel {
+            estimated_arrival: Some("14:20".to_string()),
+            ..scheduled_only.clone()
+        };
+        let landed = FlightStatusViewModel {
+            actual_arrival: Some("14:25".to_string()),
+            ..estimated.clone()
+        };
+
+        assert_eq!(
+            landed.arrival_time_with_source(),
+            Some(("14:25", TimeSource::Actual))
+        );
+        assert_eq!(
+            estimated.arrival_time_with_source(),
+            Some(("14:20", TimeSource::Estimated))
+        );
+        assert_eq!(
+            scheduled_only.arrival_time_with_source(),
+            Some(("14:00", TimeSource::Scheduled))
+        );
+        assert_eq!(
+            FlightStatusViewModel::default().arrival_time_with_source(),
+            None
+        );
+    }
+
     #[test]
     fn test_flight_status_view_model_arrival_time_estimated() {
         let view_model = FlightStatusViewModel {
@@ -229,6 +1207,7 @@ mod tests {
             progress_percent: Some(50),
             origin_airport: None,
             destination_airport: None,
+            ..Default::default()
         };
 
         assert_eq!(view_model.arrival_time(), Some("14:20"));
@@ -248,6 +1227,7 @@ mod tests {
             progress_percent: Some(50),
             origin_airport: None,
             destination_airport: None,
+            ..Default::default()
         };
 
         assert_eq!(view_model.departure_time(), None);
@@ -255,60 +1235,624 @@ mod tests {
     }
 
     #[test]
-    fn test_progress_percentage_some() {
+    fn test_gate_departure_time_actual() {
         let view_model = FlightStatusViewModel {
             flight_number: "AA100".to_string(),
-            status: FlightStatus::EnRoute,
-            scheduled_departure: Some("10:00".to_string()),
-            scheduled_arrival: Some("14:00".to_string()),
             estimated_departure: Some("10:15".to_string()),
-            estimated_arrival: Some("14:20".to_string()),
-            actual_departure: Some("10:20".to_string()),
-            actual_arrival: None,
-            progress_percent: Some(45),
-            origin_airport: None,
-            destination_airport: None,
+            scheduled_gate_departure: Some("09:50".to_string()),
+            estimated_gate_departure: Some("10:00".to_string()),
+            actual_gate_departure: Some("10:05".to_string()),
+            ..Default::default()
         };
 
-        assert_eq!(view_model.progress_percentage(), 45.0);
+        assert_eq!(view_model.gate_departure_time(), Some("10:05"));
     }
 
     #[test]
-    fn test_progress_percentage_none() {
+    fn test_gate_departure_time_estimated() {
         let view_model = FlightStatusViewModel {
             flight_number: "AA100".to_string(),
-            status: FlightStatus::Cancelled,
-            scheduled_departure: Some("10:00".to_string()),
-            scheduled_arrival: Some("14:00".to_string()),
-            estimated_departure: None,
-            estimated_arrival: None,
-            actual_departure: None,
-            actual_arrival: None,
-            progress_percent: None,
-            origin_airport: None,
-            destination_airport: None,
+            scheduled_gate_departure: Some("09:50".to_string()),
+            estimated_gate_departure: Some("10:00".to_string()),
+            ..Default::default()
         };
 
-        assert_eq!(view_model.progress_percentage(), 0.0);
+        assert_eq!(view_model.gate_departure_time(), Some("10:00"));
     }
 
     #[test]
-    fn test_formatted_arrival_time() {
+    fn test_gate_arrival_time_actual() {
         let view_model = FlightStatusViewModel {
             flight_number: "AA100".to_string(),
-            status: FlightStatus::OnTime,
-            scheduled_departure: Some("2025-11-16T10:00:00Z".to_string()),
-            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
-            estimated_departure: Some("2025-11-16T10:00:00Z".to_string()),
-            estimated_arrival: Some("2025-11-16T14:00:00Z".to_string()),
-            actual_departure: None,
-            actual_arrival: None,
-            progress_percent: Some(0),
-            origin_airport: None,
-            destination_airport: None,
+            actual_arrival: Some("14:25".to_string()),
+            scheduled_gate_arrival: Some("14:10".to_string()),
+            estimated_gate_arrival: Some("14:30".to_string()),
+            actual_gate_arrival: Some("14:34".to_string()),
+            ..Default::default()
         };
 
-        let formatted = view_model.formatted_arrival_time();
+        assert_eq!(view_model.gate_arrival_time(), Some("14:34"));
+    }
+
+    #[test]
+    fn test_gate_arrival_time_estimated() {
+        let view_model = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            scheduled_gate_arrival: Some("14:10".to_string()),
+            estimated_gate_arrival: Some("14:30".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(view_model.gate_arrival_time(), Some("14:30"));
+    }
+
+    #[test]
+    fn test_gate_times_none() {
+        let view_model = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            estimated_departure: Some("10:15".to_string()),
+            estimated_arrival: Some("14:20".to_string()),
+            scheduled_gate_departure: Some("09:50".to_string()),
+            scheduled_gate_arrival: Some("14:10".to_string()),
+            ..Default::default()
+        };
+
+        // Runway times and the schedule are not used as gate time fallbacks
+        assert_eq!(view_model.gate_departure_time(), None);
+        assert_eq!(view_model.gate_arrival_time(), None);
+    }
+
+    #[test]
+    fn test_delay_minutes() {
+        let view_model = FlightStatusViewModel {
+            departure_delay: Some(900),
+            arrival_delay: Some(600),
+            ..Default::default()
+        };
+        assert_eq!(view_model.delay_minutes(), Some(15));
+
+        let arrival_only = FlightStatusViewModel {
+            arrival_delay: Some(1260),
+            ..Default::default()
+        };
+        assert_eq!(arrival_only.delay_minutes(), Some(21));
+
+        assert_eq!(FlightStatusViewModel::default().delay_minutes(), None);
+    }
+
+    #[test]
+    fn test_progress_percentage_some() {
+        let view_model = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            scheduled_departure: Some("10:00".to_string()),
+            scheduled_arrival: Some("14:00".to_string()),
+            estimated_departure: Some("10:15".to_string()),
+            estimated_arrival: Some("14:20".to_string()),
+            actual_departure: Some("10:20".to_string()),
+            actual_arrival: None,
+            progress_percent: Some(45),
+            origin_airport: None,
+            destination_airport: None,
+            ..Default::default()
+        };
+
+        assert_eq!(view_model.progress_percentage(), 45.0);
+    }
+
+    #[test]
+    fn test_progress_percentage_none() {
+        let view_model = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::Cancelled,
+            scheduled_departure: Some("10:00".to_string()),
+            scheduled_arrival: Some("14:00".to_string()),
+            estimated_departure: None,
+            estimated_arrival: None,
+            actual_departure: None,
+            actual_arrival: None,
+            progress_percent: None,
+            origin_airport: None,
+            destination_airport: None,
+            ..Default::default()
+        };
+
+        assert_eq!(view_model.progress_percentage(), 0.0);
+    }
+
+    fn flight_at_progress(progress_percent: i64) -> FlightStatusViewModel {
+        FlightStatusViewModel {
+            actual_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            estimated_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            progress_percent: Some(progress_percent),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_time_based_progress() {
+        use chrono::{TimeZone, Utc};
+
+        let view_model = flight_at_progress(50);
+        let at = |hour, minute| Utc.with_ymd_and_hms(2025, 11, 16, hour, minute, 0).unwrap();
+
+        assert_eq!(view_model.time_based_progress_at(at(11, 0)), Some(25.0));
+        assert_eq!(view_model.time_based_progress_at(at(9, 0)), Some(0.0));
+        assert_eq!(view_model.time_based_progress_at(at(15, 0)), Some(100.0));
+        assert_eq!(
+            FlightStatusViewModel::default().time_based_progress_at(at(11, 0)),
+            None
+        );
+    }
+
+    /// Pushed back at 09:40 and still taxiing out, due at the gate at 14:00
+    fn taxiing_flight() -> FlightStatusViewModel {
+        FlightStatusViewModel {
+            status: FlightStatus::Delayed,
+            estimated_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            estimated_gate_departure: Some("2025-11-16T09:40:00Z".to_string()),
+            actual_gate_departure: Some("2025-11-16T09:40:00Z".to_string()),
+            estimated_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_runway_reference_waits_for_takeoff() {
+        let view_model = taxiing_flight().with_departure_reference(DepartureReference::Runway);
+
+        assert_eq!(view_model.status, FlightStatus::Delayed);
+        assert_eq!(view_model.actual_departure_time(), None);
+        assert_eq!(view_model.departure_time(), Some("2025-11-16T10:00:00Z"));
+        assert_eq!(
+            view_model.time_based_progress_at("2025-11-16T11:00:00Z".parse().unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_gate_reference_departs_at_pushback() {
+        let view_model = taxiing_flight().with_departure_reference(DepartureReference::Gate);
+
+        assert_eq!(view_model.status, FlightStatus::EnRoute);
+        assert_eq!(
+            view_model.actual_departure_time(),
+            Some("2025-11-16T09:40:00Z")
+        );
+        assert_eq!(view_model.departure_time(), Some("2025-11-16T09:40:00Z"));
+        // 80 of the 260 minutes from pushback to arrival
+        let progress = view_model
+            .time_based_progress_at("2025-11-16T11:00:00Z".parse().unwrap())
+            .unwrap();
+        assert!((progress - 80.0 / 260.0 * 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_gate_reference_keeps_other_statuses() {
+        let cancelled = FlightStatusViewModel {
+            status: FlightStatus::Cancelled,
+            ..taxiing_flight()
+        }
+        .with_departure_reference(DepartureReference::Gate);
+        assert_eq!(cancelled.status, FlightStatus::Cancelled);
+
+        let landed = FlightStatusViewModel {
+            status: FlightStatus::OnTime,
+            actual_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            actual_arrival: Some("2025-11-16T13:50:00Z".to_string()),
+            ..taxiing_flight()
+        }
+        .with_departure_reference(DepartureReference::Gate);
+        assert_eq!(landed.status, FlightStatus::OnTime);
+
+        // Before pushback nothing has departed either way
+        let at_gate = FlightStatusViewModel {
+            actual_gate_departure: None,
+            ..taxiing_flight()
+        }
+        .with_departure_reference(DepartureReference::Gate);
+        assert_eq!(at_gate.status, FlightStatus::Delayed);
+        assert_eq!(at_gate.departure_time(), Some("2025-11-16T09:40:00Z"));
+    }
+
+    #[test]
+    fn test_gate_reference_falls_back_to_takeoff() {
+        let view_model = FlightStatusViewModel {
+            actual_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            departure_reference: DepartureReference::Gate,
+            ..Default::default()
+        };
+        assert_eq!(
+            view_model.actual_departure_time(),
+            Some("2025-11-16T10:00:00Z")
+        );
+    }
+
+    #[test]
+    fn test_parse_departure_reference() {
+        assert_eq!("gate".parse(), Ok(DepartureReference::Gate));
+        assert_eq!("Runway".parse(), Ok(DepartureReference::Runway));
+        assert!("wheels".parse::<DepartureReference>().is_err());
+    }
+
+    #[test]
+    fn test_parse_arrival_definition() {
+        assert_eq!("touchdown".parse(), Ok(ArrivalDefinition::Touchdown));
+        assert_eq!("Gate".parse(), Ok(ArrivalDefinition::Gate));
+        assert!("baggage".parse::<ArrivalDefinition>().is_err());
+        assert_eq!(ArrivalDefinition::default(), ArrivalDefinition::Gate);
+    }
+
+    #[test]
+    fn test_progress_discrepancy_when_progress_agrees() {
+        use chrono::{TimeZone, Utc};
+
+        let now = Utc.with_ymd_and_hms(2025, 11, 16, 12, 0, 0).unwrap();
+        assert_eq!(
+            flight_at_progress(50).progress_discrepancy_at(now),
+            Some(0.0)
+        );
+        assert_eq!(
+            flight_at_progress(55).progress_discrepancy_at(now),
+            Some(5.0)
+        );
+    }
+
+    #[test]
+    fn test_progress_discrepancy_when_progress_disagrees() {
+        use chrono::{TimeZone, Utc};
+
+        // Three hours into a four hour flight
+        let now = Utc.with_ymd_and_hms(2025, 11, 16, 13, 0, 0).unwrap();
+        assert_eq!(
+            flight_at_progress(20).progress_discrepancy_at(now),
+            Some(55.0)
+        );
+
+        let early = Utc.with_ymd_and_hms(2025, 11, 16, 10, 48, 0).unwrap();
+        assert_eq!(
+            flight_at_progress(80).progress_discrepancy_at(early),
+            Some(60.0)
+        );
+
+        let unknown = FlightStatusViewModel {
+            progress_percent: None,
+            ..flight_at_progress(0)
+        };
+        assert_eq!(unknown.progress_discrepancy_at(now), None);
+    }
+
+    #[test]
+    fn test_arrival_gate_text() {
+        let landed = FlightStatusViewModel {
+            actual_arrival: Some("2025-11-16T14:10:00Z".to_string()),
+            terminal_destination: Some("2".to_string()),
+            gate_destination: Some("B12".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(landed.arrival_gate_text().as_deref(), Some("T2·B12"));
+
+        let named_terminal = FlightStatusViewModel {
+            terminal_destination: Some("TBIT".to_string()),
+            gate_destination: None,
+            ..landed.clone()
+        };
+        assert_eq!(named_terminal.arrival_gate_text().as_deref(), Some("TBIT"));
+
+        let gate_only = FlightStatusViewModel {
+            terminal_destination: None,
+            ..landed.clone()
+        };
+        assert_eq!(gate_only.arrival_gate_text().as_deref(), Some("B12"));
+
+        let unknown = FlightStatusViewModel {
+            gate_destination: None,
+            ..gate_only
+        };
+        assert_eq!(unknown.arrival_gate_text(), None);
+
+        let airborne = FlightStatusViewModel {
+            actual_arrival: None,
+            ..landed
+        };
+        assert_eq!(airborne.arrival_gate_text(), None);
+    }
+
+    #[test]
+    fn test_flightaware_url() {
+        let view_model = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            fa_flight_id: Some("AAL100-1763277826-airline-1033p".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            view_model.flightaware_url().as_deref(),
+            Some("https://www.flightaware.com/live/flight/id/AAL100-1763277826-airline-1033p")
+        );
+
+        let without_id = FlightStatusViewModel {
+            fa_flight_id: None,
+            ..view_model
+        };
+        assert_eq!(
+            without_id.flightaware_url().as_deref(),
+            Some("https://www.flightaware.com/live/flight/AA100")
+        );
+        assert_eq!(FlightStatusViewModel::default().flightaware_url(), None);
+    }
+
+    #[test]
+    fn test_phase_on_the_ground() {
+        let scheduled = FlightStatusViewModel {
+            scheduled_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(scheduled.phase(), FlightPhase::PreDeparture);
+
+        let pushed_back = FlightStatusViewModel {
+            actual_gate_departure: Some("2025-11-16T09:50:00Z".to_string()),
+            ..scheduled.clone()
+        };
+        assert_eq!(pushed_back.phase(), FlightPhase::Taxiing);
+
+        let landed = FlightStatusViewModel {
+            actual_departure: Some("2025-11-16T10:05:00Z".to_string()),
+            actual_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            progress_percent: Some(100),
+            ..pushed_back.clone()
+        };
+        assert_eq!(landed.phase(), FlightPhase::Landed);
+
+        let at_gate = FlightStatusViewModel {
+            actual_gate_arrival: Some("2025-11-16T14:10:00Z".to_string()),
+            ..landed
+        };
+        assert_eq!(at_gate.phase(), FlightPhase::AtGate);
+        assert_eq!(at_gate.phase().to_string(), "At gate");
+    }
+
+    #[test]
+    fn test_phase_in_the_air() {
+        let airborne = |progress_percent| FlightStatusViewModel {
+            actual_gate_departure: Some("2025-11-16T09:50:00Z".to_string()),
+            actual_departure: Some("2025-11-16T10:05:00Z".to_string()),
+            progress_percent,
+            ..Default::default()
+        };
+
+        assert_eq!(airborne(Some(5)).phase(), FlightPhase::Climb);
+        assert_eq!(airborne(None).phase(), FlightPhase::Climb);
+        assert_eq!(airborne(Some(15)).phase(), FlightPhase::Cruise);
+        assert_eq!(airborne(Some(50)).phase(), FlightPhase::Cruise);
+        assert_eq!(airborne(Some(85)).phase(), FlightPhase::Cruise);
+        assert_eq!(airborne(Some(92)).phase(), FlightPhase::Descent);
+        assert_eq!(airborne(Some(50)).phase().to_string(), "Cruising");
+
+        // A touchdown time still in the future is a prediction, not a landing
+        let now = "2025-11-16T13:30:00Z"
+            .parse::<chrono::DateTime<chrono::Utc>>()
+            .unwrap();
+        let predicted = FlightStatusViewModel {
+            actual_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            ..airborne(Some(92))
+        };
+        assert_eq!(predicted.phase_at(now), FlightPhase::Descent);
+        assert_eq!(
+            predicted.phase_at(now + chrono::Duration::minutes(30)),
+            FlightPhase::Landed
+        );
+    }
+
+    #[test]
+    fn test_arrival_times_multi() {
+        let arrival = "2025-11-16T14:30:00Z";
+        let local = arrival
+            .parse::<chrono::DateTime<chrono::Utc>>()
+            .unwrap()
+            .with_timezone(&chrono::Local)
+            .format("%H:%M")
+            .to_string();
+        let vm = FlightStatusViewModel {
+            origin_airport: Some("SEA".to_string()),
+            destination_airport: Some("NRT".to_string()),
+            estimated_arrival: Some(arrival.to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            vm.arrival_times_multi(),
+            vec![
+                (Some("SEA".to_string()), "06:30".to_string()),
+                (Some("NRT".to_string()), "23:30".to_string()),
+                (None, local.clone()),
+            ]
+        );
+
+        let unknown_origin = FlightStatusViewModel {
+            origin_airport: Some("XXX".to_string()),
+            ..vm.clone()
+        };
+        assert_eq!(
+            unknown_origin.arrival_times_multi(),
+            vec![
+                (Some("NRT".to_string()), "23:30".to_string()),
+                (None, local),
+            ]
+        );
+
+        let no_arrival = FlightStatusViewModel {
+            estimated_arrival: None,
+            ..vm
+        };
+        assert!(no_arrival.arrival_times_multi().is_empty());
+    }
+
+    #[test]
+    fn test_is_complete() {
+        let now: chrono::DateTime<chrono::Utc> = "2025-11-16T14:00:00Z".parse().unwrap();
+        let airborne = FlightStatusViewModel {
+            actual_departure: Some("2025-11-16T10:05:00Z".to_string()),
+            progress_percent: Some(95),
+            ..Default::default()
+        };
+        assert!(!airborne.is_complete_at(now));
+
+        let just_landed = FlightStatusViewModel {
+            actual_arrival: Some("2025-11-16T13:50:00Z".to_string()),
+            ..airborne.clone()
+        };
+        assert!(!just_landed.is_complete_at(now));
+
+        let landed_a_while_ago = FlightStatusViewModel {
+            actual_arrival: Some("2025-11-16T13:30:00Z".to_string()),
+            ..airborne.clone()
+        };
+        assert!(landed_a_while_ago.is_complete_at(now));
+
+        let at_gate = FlightStatusViewModel {
+            actual_gate_arrival: Some("2025-11-16T13:58:00Z".to_string()),
+            ..just_landed
+        };
+        assert!(at_gate.is_complete_at(now));
+    }
+
+    /// Landed at 13:50 and due at the gate at 14:05, looked at from 14:00
+    fn taxiing_in(definition: ArrivalDefinition) -> FlightStatusViewModel {
+        FlightStatusViewModel {
+            status: FlightStatus::EnRoute,
+            actual_departure: Some("2025-11-16T10:05:00Z".to_string()),
+            actual_arrival: Some("2025-11-16T13:50:00Z".to_string()),
+            estimated_gate_arrival: Some("2025-11-16T14:05:00Z".to_string()),
+            progress_percent: Some(100),
+            arrival_definition: definition,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_touchdown_definition_arrives_at_landing() {
+        let now: chrono::DateTime<chrono::Utc> = "2025-11-16T14:00:00Z".parse().unwrap();
+        let landed = taxiing_in(ArrivalDefinition::Touchdown);
+
+        assert_eq!(landed.time_remaining_at(now), Some("Arrived".to_string()));
+        assert!(!landed.is_alert_triggered_at(AlertTrigger::Progress(90.0), now));
+
+        let mut airborne = landed.clone();
+        airborne.actual_arrival = None;
+        assert!(airborne.is_alert_triggered_at(AlertTrigger::Progress(90.0), now));
+    }
+
+    #[test]
+    fn test_gate_definition_arrives_at_gate() {
+        let now: chrono::DateTime<chrono::Utc> = "2025-11-16T14:00:00Z".parse().unwrap();
+        let landed = taxiing_in(ArrivalDefinition::Gate);
+
+        // Down on the runway, the countdown and alert carry on to the gate
+        assert_eq!(
+            landed.time_remaining_at(now),
+            Some("5m to gate".to_string())
+        );
+        assert!(landed.is_alert_triggered_at(AlertTrigger::Progress(90.0), now));
+
+        let at_gate = FlightStatusViewModel {
+            actual_gate_arrival: Some("2025-11-16T13:58:00Z".to_string()),
+            ..landed.clone()
+        };
+        assert_eq!(at_gate.time_remaining_at(now), Some("Arrived".to_string()));
+        assert!(!at_gate.is_alert_triggered_at(AlertTrigger::Progress(90.0), now));
+
+        // No gate estimate to count down to
+        let no_gate_estimate = FlightStatusViewModel {
+            estimated_gate_arrival: None,
+            ..landed
+        };
+        assert_eq!(
+            no_gate_estimate.time_remaining_at(now),
+            Some("Landed".to_string())
+        );
+    }
+
+    #[test]
+    fn test_minutes_alert_follows_arrival_definition() {
+        let now: chrono::DateTime<chrono::Utc> = "2025-11-16T14:00:00Z".parse().unwrap();
+
+        // Down on the runway with the gate 5 minutes away, the alert carries on to the gate
+        let gate = taxiing_in(ArrivalDefinition::Gate);
+        assert!(gate.is_alert_triggered_at(AlertTrigger::Minutes(30), now));
+        assert!(!gate.is_alert_triggered_at(AlertTrigger::Minutes(3), now));
+
+        let touchdown = taxiing_in(ArrivalDefinition::Touchdown);
+        assert!(!touchdown.is_alert_triggered_at(AlertTrigger::Minutes(30), now));
+
+        let at_gate = FlightStatusViewModel {
+            actual_gate_arrival: Some("2025-11-16T13:58:00Z".to_string()),
+            ..gate
+        };
+        assert!(!at_gate.is_alert_triggered_at(AlertTrigger::Minutes(30), now));
+    }
+
+    #[test]
+    fn test_arrival_definition_sets_landed_status() {
+        use chrono::{Duration, Utc};
+
+        let landed = FlightStatusViewModel {
+            status: FlightStatus::EnRoute,
+            actual_departure: Some((Utc::now() - Duration::hours(3)).to_rfc3339()),
+            actual_arrival: Some((Utc::now() - Duration::minutes(5)).to_rfc3339()),
+            arrival_delay: Some(20 * 60),
+            ..Default::default()
+        };
+
+        let touchdown = landed
+            .clone()
+            .with_arrival_definition(ArrivalDefinition::Touchdown);
+        assert_eq!(touchdown.status, FlightStatus::Delayed);
+
+        let gate = landed.with_arrival_definition(ArrivalDefinition::Gate);
+        assert_eq!(gate.status, FlightStatus::EnRoute);
+
+        // Back from touchdown to gate puts a flight still taxiing in en route again
+        let switched_back = touchdown.with_arrival_definition(ArrivalDefinition::Gate);
+        assert_eq!(switched_back.status, FlightStatus::EnRoute);
+    }
+
+    #[test]
+    fn test_is_local_flight() {
+        let local = FlightStatusViewModelBuilder::default()
+            .origin_airport(Some("SFO".to_string()))
+            .destination_airport(Some("SFO".to_string()))
+            .build()
+            .unwrap();
+        let point_to_point = FlightStatusViewModelBuilder::default()
+            .origin_airport(Some("SFO".to_string()))
+            .destination_airport(Some("LAX".to_string()))
+            .build()
+            .unwrap();
+        let unknown = FlightStatusViewModel::default();
+
+        assert!(local.is_local_flight());
+        assert!(!point_to_point.is_local_flight());
+        assert!(!unknown.is_local_flight());
+    }
+
+    #[test]
+    fn test_formatted_arrival_time() {
+        let view_model = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::OnTime,
+            scheduled_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            estimated_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            estimated_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            actual_departure: None,
+            actual_arrival: None,
+            progress_percent: Some(0),
+            origin_airport: None,
+            destination_airport: None,
+            ..Default::default()
+        };
+
+        let formatted = view_model.formatted_arrival_time(None);
         assert!(formatted.is_some());
 
         // The formatted string should contain the year
@@ -319,6 +1863,87 @@ mod tests {
         assert!(formatted_str.contains("at"));
     }
 
+    #[test]
+    fn test_formatted_arrival_time_across_local_midnight() {
+        // 1:00 AM in New York on the 17th, while UTC is already on the 17th from 7 PM the day
+        // before
+        let view_model = FlightStatusViewModel {
+            estimated_arrival: Some("2025-11-17T06:00:00Z".to_string()),
+            ..Default::default()
+        };
+        let new_york = Some(chrono_tz::America::New_York);
+        let at = |now: &str| {
+            view_model
+                .formatted_arrival_time_at(new_york, now.parse().unwrap(), |day| day.to_string())
+        };
+
+        // 10 PM and 11:59 PM on the 16th in New York, the same UTC day as the arrival
+        assert_eq!(at("2025-11-17T03:00:00Z").unwrap(), "Tomorrow 1:00 AM EST");
+        assert_eq!(at("2025-11-17T04:59:00Z").unwrap(), "Tomorrow 1:00 AM EST");
+        // Just past midnight in New York
+        assert_eq!(at("2025-11-17T05:00:00Z").unwrap(), "Today 1:00 AM EST");
+        assert_eq!(at("2025-11-18T12:00:00Z").unwrap(), "Yesterday 1:00 AM EST");
+        assert_eq!(
+            at("2025-11-15T12:00:00Z").unwrap(),
+            "Nov 17, 2025 at 1:00 AM EST"
+        );
+
+        // The same moments are both on the 17th in Tokyo
+        assert_eq!(
+            view_model
+                .formatted_arrival_time_at(
+                    Some(chrono_tz::Asia::Tokyo),
+                    "2025-11-17T03:00:00Z".parse().unwrap(),
+                    |day| day.to_string()
+                )
+                .unwrap(),
+            "Today 3:00 PM JST"
+        );
+
+        // Other languages name the day themselves
+        assert_eq!(
+            view_model
+                .formatted_arrival_time_at(
+                    new_york,
+                    "2025-11-17T03:00:00Z".parse().unwrap(),
+                    |_| "Mañana".to_string()
+                )
+                .unwrap(),
+            "Mañana 1:00 AM EST"
+        );
+    }
+
+    #[test]
+    fn test_formatted_destination_arrival_time() {
+        let view_model = FlightStatusViewModel {
+            flight_number: "HAL824".to_string(),
+            estimated_arrival: Some("2025-11-18T05:30:00Z".to_string()),
+            destination_airport: Some("NRT".to_string()),
+            ..Default::default()
+        };
+        let utc = chrono::FixedOffset::east_opt(0).unwrap();
+        let tokyo = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+
+        assert_eq!(
+            view_model.formatted_destination_arrival_time_from(utc),
+            Some("2:30 PM JST at NRT".to_string())
+        );
+        // Nothing to add when the destination keeps the same time as here
+        assert_eq!(
+            view_model.formatted_destination_arrival_time_from(tokyo),
+            None
+        );
+
+        let unknown_airport = FlightStatusViewModel {
+            destination_airport: Some("XXX".to_string()),
+            ..view_model
+        };
+        assert_eq!(
+            unknown_airport.formatted_destination_arrival_time_from(utc),
+            None
+        );
+    }
+
     #[test]
     fn test_formatted_arrival_time_none() {
         let view_model = FlightStatusViewModel {
@@ -333,9 +1958,10 @@ mod tests {
             progress_percent: None,
             origin_airport: None,
             destination_airport: None,
+            ..Default::default()
         };
 
-        assert!(view_model.formatted_arrival_time().is_none());
+        assert!(view_model.formatted_arrival_time(None).is_none());
     }
 
     #[test]
@@ -375,7 +2001,7 @@ mod tests {
 
         assert_eq!(view_model.flight_number, "DL456");
         assert_eq!(view_model.status, FlightStatus::Cancelled);
-        assert!(view_model.formatted_arrival_time().is_none());
+        assert!(view_model.formatted_arrival_time(None).is_none());
     }
 
     #[test]
@@ -394,6 +2020,59 @@ mod tests {
         assert!(view_model.scheduled_arrival.is_none());
     }
 
+    #[test]
+    fn test_arrival_clock() {
+        use chrono::{TimeZone, Utc};
+
+        let now = Utc.with_ymd_and_hms(2025, 11, 16, 12, 0, 0).unwrap();
+        let en_route = FlightStatusViewModel {
+            estimated_arrival: Some("2025-11-16T14:30:00Z".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            en_route.arrival_clock_at(Some(chrono_tz::UTC), now),
+            Some("arrives 14:30".to_string())
+        );
+        assert_eq!(
+            en_route.arrival_clock_at(Some(chrono_tz::Asia::Tokyo), now),
+            Some("arrives 23:30".to_string())
+        );
+
+        let at_gate = FlightStatusViewModel {
+            estimated_gate_arrival: Some("2025-11-16T14:42:00Z".to_string()),
+            ..en_route.clone()
+        };
+        assert_eq!(
+            at_gate.arrival_clock_at(Some(chrono_tz::UTC), now),
+            Some("arrives 14:42".to_string())
+        );
+
+        let later = Utc.with_ymd_and_hms(2025, 11, 16, 15, 0, 0).unwrap();
+        let landed = FlightStatusViewModel {
+            actual_arrival: Some("2025-11-16T14:25:00Z".to_string()),
+            ..en_route.clone()
+        };
+        assert_eq!(
+            landed.arrival_clock_at(Some(chrono_tz::UTC), later),
+            Some("arrived 14:25".to_string())
+        );
+        // A landing recorded ahead of the clock is a prediction, not an arrival
+        assert_eq!(
+            landed.arrival_clock_at(Some(chrono_tz::UTC), now),
+            Some("arrives 14:30".to_string())
+        );
+
+        assert_eq!(
+            en_route.arrival_clock_at(Some(chrono_tz::UTC), later),
+            Some("Overdue".to_string())
+        );
+        assert_eq!(
+            FlightStatusViewModel::default().arrival_clock_at(None, now),
+            None
+        );
+    }
+
     #[test]
     fn test_time_remaining_arrived() {
         let view_model = FlightStatusViewModel {
@@ -408,6 +2087,7 @@ mod tests {
             progress_percent: Some(100),
             origin_airport: None,
             destination_airport: None,
+            ..Default::default()
         };
 
         assert_eq!(view_model.time_remaining(), Some("Arrived".to_string()));
@@ -427,11 +2107,507 @@ mod tests {
             progress_percent: Some(0),
             origin_airport: None,
             destination_airport: None,
+            ..Default::default()
         };
 
         assert_eq!(view_model.time_remaining(), None);
     }
 
+    #[test]
+    fn test_time_remaining_overdue_without_actual_arrival() {
+        use chrono::{Duration, Utc};
+
+        let view_model = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            estimated_arrival: Some((Utc::now() - Duration::minutes(10)).to_rfc3339()),
+            arrival_definition: ArrivalDefinition::Touchdown,
+            ..Default::default()
+        };
+
+        assert_eq!(view_model.time_remaining(), Some("Overdue".to_string()));
+        assert!(view_model.is_overdue());
+
+        let landed = FlightStatusViewModel {
+            actual_arrival: Some((Utc::now() - Duration::minutes(5)).to_rfc3339()),
+            ..view_model
+        };
+        assert_eq!(landed.time_remaining(), Some("Arrived".to_string()));
+        assert!(!landed.is_overdue());
+    }
+
+    #[test]
+    fn test_future_actual_arrival_is_treated_as_estimated() {
+        use chrono::{Duration, Utc};
+
+        let estimated = (Utc::now() + Duration::minutes(20)).to_rfc3339();
+        let view_model = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            estimated_arrival: Some(estimated.clone()),
+            actual_arrival: Some((Utc::now() + Duration::minutes(10)).to_rfc3339()),
+            arrival_definition: ArrivalDefinition::Touchdown,
+            ..Default::default()
+        };
+
+        assert_ne!(view_model.time_remaining(), Some("Arrived".to_string()));
+        assert_eq!(view_model.arrival_time(), Some(estimated.as_str()));
+        assert_eq!(
+            view_model.arrival_time_with_source(),
+            Some((estimated.as_str(), TimeSource::Estimated))
+        );
+
+        // A few seconds ahead is just clock skew
+        let skewed = FlightStatusViewModel {
+            actual_arrival: Some((Utc::now() + Duration::seconds(20)).to_rfc3339()),
+            ..view_model
+        };
+        assert_eq!(skewed.time_remaining(), Some("Arrived".to_string()));
+    }
+
+    #[test]
+    fn test_block_times() {
+        let view_model = FlightStatusViewModel {
+            scheduled_gate_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            scheduled_gate_arrival: Some("2025-11-16T13:00:00Z".to_string()),
+            actual_gate_departure: Some("2025-11-16T10:20:00Z".to_string()),
+            estimated_gate_arrival: Some("2025-11-16T13:35:00Z".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            view_model.scheduled_block_time(),
+            Some(chrono::Duration::minutes(180))
+        );
+        assert_eq!(
+            view_model.current_block_time(),
+            Some(chrono::Duration::minutes(195))
+        );
+
+        // Without a gate arrival estimate, the scheduled arrival stands in
+        let no_estimate = FlightStatusViewModel {
+            estimated_gate_arrival: None,
+            ..view_model
+        };
+        assert_eq!(
+            no_estimate.current_block_time(),
+            Some(chrono::Duration::minutes(160))
+        );
+        assert_eq!(
+            FlightStatusViewModel::default().scheduled_block_time(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_average_speed_halfway() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let departure = Utc.with_ymd_and_hms(2025, 11, 16, 10, 0, 0).unwrap();
+        // Half of 2,000 statute miles (869 nautical miles) in two hours
+        let view_model = FlightStatusViewModel {
+            actual_departure: Some(departure.to_rfc3339()),
+            route_distance: Some(2000),
+            progress_percent: Some(50),
+            ..Default::default()
+        };
+
+        let speed = view_model
+            .average_speed_kt_at(departure + Duration::hours(2))
+            .unwrap();
+        assert!((speed - 434.488).abs() < 0.01);
+
+        // After landing the clock stops at the actual arrival
+        let landed = FlightStatusViewModel {
+            actual_arrival: Some((departure + Duration::hours(4)).to_rfc3339()),
+            progress_percent: Some(100),
+            ..view_model
+        };
+        let speed = landed
+            .average_speed_kt_at(departure + Duration::hours(10))
+            .unwrap();
+        assert!((speed - 434.488).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_route_text_with_cities() {
+        let view_model = FlightStatusViewModel {
+            origin_airport: Some("SFO".to_string()),
+            destination_airport: Some("LAX".to_string()),
+            origin_city: Some("San Francisco".to_string()),
+            destination_city: Some("Los Angeles".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            view_model.route_text().as_deref(),
+            Some("San Francisco (SFO) → Los Angeles (LAX)")
+        );
+
+        let one_city = FlightStatusViewModel {
+            destination_city: None,
+            ..view_model.clone()
+        };
+        assert_eq!(
+            one_city.route_text().as_deref(),
+            Some("San Francisco (SFO) → LAX")
+        );
+
+        let no_cities = FlightStatusViewModel {
+            origin_city: None,
+            ..one_city
+        };
+        assert_eq!(no_cities.route_text(), None);
+    }
+
+    #[test]
+    fn test_boarding_countdown_at_offset_boundary() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let departure = Utc.with_ymd_and_hms(2025, 11, 16, 10, 0, 0).unwrap();
+        let view_model = FlightStatusViewModel {
+            estimated_departure: Some(departure.to_rfc3339()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            view_model.boarding_countdown_at(40, departure - Duration::minutes(52)),
+            Some(Duration::minutes(12))
+        );
+        assert_eq!(
+            view_model.boarding_countdown_at(40, departure - Duration::minutes(41)),
+            Some(Duration::minutes(1))
+        );
+        // Hidden from the moment boarding should start
+        assert_eq!(
+            view_model.boarding_countdown_at(40, departure - Duration::minutes(40)),
+            None
+        );
+        assert_eq!(
+            view_model.boarding_countdown_at(40, departure - Duration::minutes(10)),
+            None
+        );
+        // A shorter offset moves the start of boarding later
+        assert_eq!(
+            view_model.boarding_countdown_at(20, departure - Duration::minutes(40)),
+            Some(Duration::minutes(20))
+        );
+    }
+
+    #[test]
+    fn test_boarding_countdown_hidden_after_departure() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let departure = Utc.with_ymd_and_hms(2025, 11, 16, 10, 0, 0).unwrap();
+        // Departed early, while the estimate still lies ahead
+        let view_model = FlightStatusViewModel {
+            estimated_departure: Some(departure.to_rfc3339()),
+            actual_departure: Some((departure - Duration::hours(2)).to_rfc3339()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            view_model.boarding_countdown_at(40, departure - Duration::hours(1)),
+            None
+        );
+        assert_eq!(
+            FlightStatusViewModel::default()
+                .boarding_countdown_at(40, departure - Duration::hours(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_gate_change_detection() {
+        let gate = |gate: Option<&str>| FlightStatusViewModel {
+            gate_origin: gate.map(str::to_string),
+            ..Default::default()
+        };
+
+        let change = GateChange::detect(&gate(Some("B12")), &gate(Some("C7"))).unwrap();
+        assert_eq!(
+            change,
+            GateChange {
+                from: "B12".to_string(),
+                to: "C7".to_string()
+            }
+        );
+        assert_eq!(change.to_string(), "Gate changed: B12 → C7");
+
+        assert_eq!(
+            GateChange::detect(&gate(Some("B12")), &gate(Some("B12"))),
+            None
+        );
+        assert_eq!(GateChange::detect(&gate(None), &gate(Some("B12"))), None);
+        assert_eq!(GateChange::detect(&gate(Some("B12")), &gate(None)), None);
+    }
+
+    #[test]
+    fn test_format_altitude() {
+        assert_eq!(format_altitude(35000), "FL350");
+        assert_eq!(format_altitude(18000), "FL180");
+        assert_eq!(format_altitude(8000), "8,000 ft");
+        assert_eq!(format_altitude(17500), "17,500 ft");
+        assert_eq!(format_altitude(500), "500 ft");
+    }
+
+    #[test]
+    fn test_cruise_altitude_text() {
+        let view_model = FlightStatusViewModel {
+            filed_altitude: Some(370),
+            ..Default::default()
+        };
+        assert_eq!(
+            view_model.cruise_altitude_text().as_deref(),
+            Some("Cruise: FL370")
+        );
+        assert_eq!(
+            FlightStatusViewModel::default().cruise_altitude_text(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cabin_text_lists_present_cabins() {
+        let view_model = FlightStatusViewModel {
+            seats_cabin_first: Some(12),
+            seats_cabin_business: Some(48),
+            seats_cabin_coach: Some(180),
+            ..Default::default()
+        };
+        assert_eq!(
+            view_model.cabin_text().as_deref(),
+            Some("Cabin: 12F / 48J / 180Y")
+        );
+
+        let no_first = FlightStatusViewModel {
+            seats_cabin_first: None,
+            ..view_model
+        };
+        assert_eq!(no_first.cabin_text().as_deref(), Some("Cabin: 48J / 180Y"));
+
+        assert_eq!(FlightStatusViewModel::default().cabin_text(), None);
+    }
+
+    #[test]
+    fn test_average_speed_guards() {
+        use chrono::{TimeZone, Utc};
+
+        let departure = Utc.with_ymd_and_hms(2025, 11, 16, 10, 0, 0).unwrap();
+        let view_model = FlightStatusViewModel {
+            actual_departure: Some(departure.to_rfc3339()),
+            route_distance: Some(2000),
+            progress_percent: Some(50),
+            ..Default::default()
+        };
+
+        assert_eq!(view_model.average_speed_kt_at(departure), None);
+        assert_eq!(
+            FlightStatusViewModel {
+                route_distance: None,
+                ..view_model.clone()
+            }
+            .average_speed_kt_at(departure + chrono::Duration::hours(1)),
+            None
+        );
+        assert_eq!(
+            FlightStatusViewModel {
+                actual_departure: None,
+                ..view_model
+            }
+            .average_speed_kt(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_operated_by_on_codeshare() {
+        let view_model = FlightStatusViewModel {
+            flight_number: "BAW123".to_string(),
+            operator_icao: Some("BAW".to_string()),
+            operator_iata: Some("BA".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            view_model.operated_by("AA8500"),
+            Some("Operated by British Airways".to_string())
+        );
+        assert_eq!(
+            view_model.operated_by("aal8500"),
+            Some("Operated by British Airways".to_string())
+        );
+        assert_eq!(view_model.operated_by("BA123"), None);
+        assert_eq!(view_model.operated_by("BAW123"), None);
+    }
+
+    #[test]
+    fn test_operated_by_needs_operator() {
+        let view_model = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(view_model.operated_by("AA100"), None);
+
+        // Two character airline codes may contain digits, e.g. jetBlue's B6
+        let jetblue = FlightStatusViewModel {
+            operator_iata: Some("B6".to_string()),
+            operator_icao: Some("JBU".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(jetblue.operated_by("B6123"), None);
+        assert_eq!(
+            jetblue.operated_by("DL6123"),
+            Some("Operated by JetBlue".to_string())
+        );
+
+        // An airline missing from the names falls back to its code
+        let unknown = FlightStatusViewModel {
+            operator_iata: Some("ZZ".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            unknown.operated_by("DL6123"),
+            Some("Operated by ZZ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_baggage_claim_only_after_landing() {
+        let in_flight = FlightStatusViewModel {
+            baggage_claim: Some("4".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(in_flight.baggage_claim_text(), None);
+
+        let landed = FlightStatusViewModel {
+            actual_arrival: Some("2025-11-16T14:10:00Z".to_string()),
+            ..in_flight
+        };
+        assert_eq!(
+            landed.baggage_claim_text(),
+            Some("Baggage: Carousel 4".to_string())
+        );
+
+        let no_claim = FlightStatusViewModel {
+            baggage_claim: None,
+            ..landed
+        };
+        assert_eq!(no_claim.baggage_claim_text(), None);
+    }
+
+    fn implied_arrival_after(progress_percent: i64, minutes_in_air: i64) -> Option<String> {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let departure = Utc.with_ymd_and_hms(2025, 11, 16, 10, 0, 0).unwrap();
+        let view_model = FlightStatusViewModel {
+            actual_departure: Some(departure.to_rfc3339()),
+            progress_percent: Some(progress_percent),
+            ..Default::default()
+        };
+
+        view_model
+            .implied_arrival_at(departure + Duration::minutes(minutes_in_air))
+            .map(|arrival| arrival.format("%H:%M").to_string())
+    }
+
+    #[test]
+    fn test_implied_arrival() {
+        // 25% after 30 minutes: 120 minute flight
+        assert_eq!(implied_arrival_after(25, 30), Some("12:00".to_string()));
+        // 75% after 90 minutes: 120 minute flight
+        assert_eq!(implied_arrival_after(75, 90), Some("12:00".to_string()));
+        // 45% after 90 minutes: 200 minute flight
+        assert_eq!(implied_arrival_after(45, 90), Some("13:20".to_string()));
+    }
+
+    #[test]
+    fn test_implied_arrival_guards() {
+        assert_eq!(implied_arrival_after(0, 30), None);
+        assert_eq!(
+            FlightStatusViewModel {
+                progress_percent: Some(50),
+                ..Default::default()
+            }
+            .implied_arrival(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_time_remaining_falls_back_to_implied_arrival() {
+        use chrono::{Duration, Utc};
+
+        // Halfway after an hour in the air: about an hour to go
+        let view_model = FlightStatusViewModel {
+            actual_departure: Some((Utc::now() - Duration::minutes(60)).to_rfc3339()),
+            progress_percent: Some(50),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            view_model.time_remaining(),
+            Some("1h 0m to touchdown".to_string())
+        );
+    }
+
+    #[test]
+    fn test_time_remaining_to_gate() {
+        use chrono::{Duration, Utc};
+
+        let now = Utc::now();
+        let view_model = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            estimated_arrival: Some((now + Duration::minutes(80)).to_rfc3339()),
+            estimated_gate_arrival: Some(
+                (now + Duration::minutes(90) + Duration::seconds(30)).to_rfc3339(),
+            ),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            view_model.time_remaining(),
+            Some("1h 30m to gate".to_string())
+        );
+    }
+
+    #[test]
+    fn test_time_remaining_to_touchdown_without_gate_estimate() {
+        use chrono::{Duration, Utc};
+
+        let view_model = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            estimated_arrival: Some(
+                (Utc::now() + Duration::minutes(45) + Duration::seconds(30)).to_rfc3339(),
+            ),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            view_model.time_remaining(),
+            Some("45m to touchdown".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_time_remaining_tiers() {
+        use chrono::{Duration, Local, TimeZone, Utc};
+
+        let arrival = Utc.with_ymd_and_hms(2025, 11, 24, 12, 0, 0).unwrap();
+        let format = |duration| format_time_remaining(duration, arrival, "to gate");
+
+        assert_eq!(format(Duration::minutes(45)), "45m to gate");
+        assert_eq!(format(Duration::hours(23)), "23h 0m to gate");
+        assert_eq!(format(Duration::hours(25)), "1d 1h to gate");
+        assert_eq!(format(Duration::days(3)), "3d 0h to gate");
+        assert_eq!(format(Duration::days(6)), "6d 0h to gate");
+        assert_eq!(format(Duration::days(7)), "7d 0h to gate");
+
+        let expected_date = arrival.with_timezone(&Local).format("%b %-d").to_string();
+        assert_eq!(format(Duration::days(8)), format!("on {}", expected_date));
+    }
+
     #[test]
     fn test_is_approaching_landing_true() {
         use chrono::{Duration, Utc};
@@ -451,6 +2627,7 @@ mod tests {
             progress_percent: Some(85),
             origin_airport: None,
             destination_airport: None,
+            ..Default::default()
         };
 
         assert!(view_model.is_approaching_landing(30));
@@ -476,11 +2653,45 @@ mod tests {
             progress_percent: Some(50),
             origin_airport: None,
             destination_airport: None,
+            ..Default::default()
         };
 
         assert!(!view_model.is_approaching_landing(30));
     }
 
+    #[test]
+    fn test_alert_trigger_minutes() {
+        use chrono::{Duration, Utc};
+
+        let arriving_in = |minutes| FlightStatusViewModel {
+            estimated_arrival: Some((Utc::now() + Duration::minutes(minutes)).to_rfc3339()),
+            progress_percent: Some(50),
+            ..Default::default()
+        };
+
+        assert!(arriving_in(20).is_alert_triggered(AlertTrigger::Minutes(30)));
+        assert!(!arriving_in(45).is_alert_triggered(AlertTrigger::Minutes(30)));
+    }
+
+    #[test]
+    fn test_alert_trigger_progress() {
+        let at_progress = |progress| FlightStatusViewModel {
+            progress_percent: Some(progress),
+            ..Default::default()
+        };
+
+        assert!(!at_progress(89).is_alert_triggered(AlertTrigger::Progress(90.0)));
+        assert!(at_progress(90).is_alert_triggered(AlertTrigger::Progress(90.0)));
+        assert!(at_progress(97).is_alert_triggered(AlertTrigger::Progress(90.0)));
+        // No alert without progress data or once landed
+        assert!(!FlightStatusViewModel::default().is_alert_triggered(AlertTrigger::Progress(90.0)));
+        let landed = FlightStatusViewModel {
+            actual_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            ..at_progress(100)
+        };
+        assert!(!landed.is_alert_triggered(AlertTrigger::Progress(90.0)));
+    }
+
     #[test]
     fn test_is_approaching_landing_false_already_arrived() {
         let view_model = FlightStatusViewModel {
@@ -495,6 +2706,7 @@ mod tests {
             progress_percent: Some(100),
             origin_airport: None,
             destination_airport: None,
+            ..Default::default()
         };
 
         assert!(!view_model.is_approaching_landing(30));
@@ -0,0 +1,54 @@
+use std::path::Path;
+
+/// Read a list of flight numbers from a file, one per line
+pub fn read_flights_file(path: &Path) -> std::io::Result<Vec<String>> {
+    Ok(parse_flights_file(&std::fs::read_to_string(path)?))
+}
+
+/// Parse the flights file format: one flight number per line, blank lines and
+/// anything after a `#` are ignored
+pub fn parse_flights_file(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_flights_file_skips_blanks_and_comments() {
+        let contents = "\
+# Morning departures
+AA100
+
+  UA200
+DL300 # inbound from ATL
+   # indented comment
+";
+
+        assert_eq!(
+            parse_flights_file(contents),
+            vec![
+                "AA100".to_string(),
+                "UA200".to_string(),
+                "DL300".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_flights_file_empty() {
+        assert!(parse_flights_file("\n# nothing here\n").is_empty());
+    }
+
+    #[test]
+    fn test_read_flights_file_missing() {
+        let result = read_flights_file(Path::new("/nonexistent/flights.txt"));
+        assert!(result.is_err());
+    }
+}
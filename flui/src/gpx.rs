@@ -0,0 +1,107 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fmt::Write;
+
+const METERS_PER_FOOT: f64 = 0.3048;
+
+/// One recorded position of the aircraft
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackPoint {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude_feet: i64,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Serialize the recorded positions as a GPX 1.1 document with a single track named after the
+/// flight; with no points the track segment is left empty, which is still valid GPX
+pub fn to_gpx(points: &[TrackPoint], name: &str) -> String {
+    let mut gpx = String::new();
+    gpx.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    gpx.push_str(r#"<gpx version="1.1" creator="flui" xmlns="http://www.topografix.com/GPX/1/1">"#);
+    gpx.push('\n');
+    gpx.push_str("  <trk>\n");
+    // Writing to a String cannot fail
+    let _ = writeln!(gpx, "    <name>{}</name>", escape(name));
+    gpx.push_str("    <trkseg>\n");
+    for point in points {
+        let _ = writeln!(
+            gpx,
+            r#"      <trkpt lat="{:.6}" lon="{:.6}">"#,
+            point.latitude, point.longitude
+        );
+        // GPX elevations are in meters
+        let _ = writeln!(
+            gpx,
+            "        <ele>{:.1}</ele>",
+            point.altitude_feet as f64 * METERS_PER_FOOT
+        );
+        let _ = writeln!(
+            gpx,
+            "        <time>{}</time>",
+            point.timestamp.to_rfc3339_opts(SecondsFormat::Secs, true)
+        );
+        gpx.push_str("      </trkpt>\n");
+    }
+    gpx.push_str("    </trkseg>\n");
+    gpx.push_str("  </trk>\n");
+    gpx.push_str("</gpx>\n");
+
+    gpx
+}
+
+/// Escape text for use inside GPX elements
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_to_gpx_with_points() {
+        let points = [
+            TrackPoint {
+                latitude: 47.449,
+                longitude: -122.3093,
+                altitude_feet: 1000,
+                timestamp: Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap(),
+            },
+            TrackPoint {
+                latitude: 47.9,
+                longitude: -123.25,
+                altitude_feet: 35000,
+                timestamp: Utc.with_ymd_and_hms(2025, 1, 1, 12, 15, 30).unwrap(),
+            },
+        ];
+
+        let gpx = to_gpx(&points, "AA100");
+
+        assert!(gpx.starts_with("<?xml"));
+        assert!(gpx.contains("<name>AA100</name>"));
+        assert_eq!(gpx.matches("<trkpt ").count(), 2);
+        assert!(gpx.contains(
+            "      <trkpt lat=\"47.449000\" lon=\"-122.309300\">\n        <ele>304.8</ele>\n        <time>2025-01-01T12:00:00Z</time>\n      </trkpt>\n"
+        ));
+        assert!(gpx.contains("<ele>10668.0</ele>"));
+        assert!(gpx.contains("<time>2025-01-01T12:15:30Z</time>"));
+        assert!(gpx.trim_end().ends_with("</gpx>"));
+    }
+
+    #[test]
+    fn test_to_gpx_without_points() {
+        let gpx = to_gpx(&[], "AA100");
+
+        assert!(!gpx.contains("<trkpt"));
+        assert!(gpx.contains("    <trkseg>\n    </trkseg>\n"));
+        assert!(gpx.trim_end().ends_with("</gpx>"));
+    }
+
+    #[test]
+    fn test_to_gpx_escapes_name() {
+        assert!(to_gpx(&[], "<AA&100>").contains("<name>&lt;AA&amp;100&gt;</name>"));
+    }
+}
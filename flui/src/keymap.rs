@@ -0,0 +1,471 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Something the user can trigger from the keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    Refresh,
+    ToggleHelp,
+    CycleIdent,
+    ToggleDebug,
+    ToggleMinimal,
+    ToggleTimetable,
+    ToggleBlockTimes,
+    ToggleBoardDensity,
+    OpenInBrowser,
+    ScrollUp,
+    ScrollDown,
+    Select,
+}
+
+impl Action {
+    const ALL: [Action; 13] = [
+        Action::Quit,
+        Action::Refresh,
+        Action::ToggleHelp,
+        Action::CycleIdent,
+        Action::ToggleDebug,
+        Action::ToggleMinimal,
+        Action::ToggleTimetable,
+        Action::ToggleBlockTimes,
+        Action::ToggleBoardDensity,
+        Action::OpenInBrowser,
+        Action::ScrollUp,
+        Action::ScrollDown,
+        Action::Select,
+    ];
+
+    /// Name used for the action in the `[keys]` table
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Refresh => "refresh",
+            Action::ToggleHelp => "toggle_help",
+            Action::CycleIdent => "cycle_ident",
+            Action::ToggleDebug => "toggle_debug",
+            Action::ToggleMinimal => "toggle_minimal",
+            Action::ToggleTimetable => "toggle_timetable",
+            Action::ToggleBlockTimes => "toggle_block_times",
+            Action::ToggleBoardDensity => "toggle_board_density",
+            Action::OpenInBrowser => "open_in_browser",
+            Action::ScrollUp => "scroll_up",
+            Action::ScrollDown => "scroll_down",
+            Action::Select => "select",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::Quit => "Quit",
+            Action::Refresh => "Refresh now",
+            Action::ToggleHelp => "Show or hide this help",
+            Action::CycleIdent => "Cycle flight number format",
+            Action::ToggleDebug => "Show or hide the raw API JSON",
+            Action::ToggleMinimal => "Show or hide the flight path",
+            Action::ToggleTimetable => "Show all departure and arrival times",
+            Action::ToggleBlockTimes => "Compare scheduled and current block time",
+            Action::ToggleBoardDensity => "Switch the board between compact and spacious rows",
+            #[cfg(feature = "open")]
+            Action::OpenInBrowser => "Open the flight on FlightAware's website",
+            #[cfg(not(feature = "open"))]
+            Action::OpenInBrowser => "Open FlightAware's website (built without open support)",
+            Action::ScrollUp => "Scroll up",
+            Action::ScrollDown => "Scroll down",
+            Action::Select => "Track the highlighted flight",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Action::ALL.into_iter().find(|action| action.name() == name)
+    }
+
+    fn default_keys(self) -> &'static [&'static str] {
+        match self {
+            Action::Quit => &["q", "esc", "ctrl+c"],
+            Action::Refresh => &["r"],
+            Action::ToggleHelp => &["?"],
+            Action::CycleIdent => &["i"],
+            Action::ToggleDebug => &["D"],
+            Action::ToggleMinimal => &["v"],
+            Action::ToggleTimetable => &["t"],
+            Action::ToggleBlockTimes => &["b"],
+            Action::ToggleBoardDensity => &["s"],
+            Action::OpenInBrowser => &["o"],
+            Action::ScrollUp => &["up", "k"],
+            Action::ScrollDown => &["down", "j"],
+            Action::Select => &["enter"],
+        }
+    }
+}
+
+/// A single key, optionally with Ctrl/Alt held
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Parse a key string such as `q`, `esc`, `up`, `f1` or `ctrl+c`
+    pub fn parse(text: &str) -> Result<Self, KeyMapError> {
+        let invalid = || KeyMapError::InvalidKey(text.to_string());
+
+        let mut modifiers = KeyModifiers::NONE;
+        let mut parts: Vec<&str> = text.split('+').collect();
+        // A trailing "+" means the plus key itself, e.g. "ctrl++"
+        let key = if text.ends_with("++") || text == "+" {
+            parts.truncate(parts.len().saturating_sub(2));
+            "+"
+        } else {
+            parts.pop().ok_or_else(invalid)?
+        };
+
+        for modifier in parts {
+            modifiers |= match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                _ => return Err(invalid()),
+            };
+        }
+
+        let code = match key.to_ascii_lowercase().as_str() {
+            "esc" | "escape" => KeyCode::Esc,
+            "enter" | "return" => KeyCode::Enter,
+            "space" => KeyCode::Char(' '),
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            lower => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => lower
+                        .strip_prefix('f')
+                        .and_then(|n| n.parse::<u8>().ok())
+                        .filter(|n| (1..=12).contains(n))
+                        .map(KeyCode::F)
+                        .ok_or_else(invalid)?,
+                }
+            }
+        };
+
+        Ok(KeyBinding { code, modifiers })
+    }
+
+    fn from_event(key: &KeyEvent) -> Self {
+        // Shift is already reflected in the character (e.g. '?'), so only Ctrl/Alt matter
+        KeyBinding {
+            code: key.code,
+            modifiers: key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT),
+        }
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum KeyMapError {
+    InvalidKey(String),
+    Unbound(&'static str),
+    /// A key bound to two actions, with the actions' names
+    DuplicateKey(String, &'static str, &'static str),
+}
+
+impl fmt::Display for KeyMapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyMapError::InvalidKey(key) => write!(f, "Unrecognized key \"{}\"", key),
+            KeyMapError::Unbound(action) => {
+                write!(f, "Action \"{}\" has no keys bound to it", action)
+            }
+            KeyMapError::DuplicateKey(key, first, second) => write!(
+                f,
+                "Key \"{}\" is bound to both \"{}\" and \"{}\"",
+                key, first, second
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KeyMapError {}
+
+/// Keys bound to each action, used by the event loop to dispatch key presses
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    bindings: Vec<(Action, Vec<KeyBinding>)>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        let bindings = Action::ALL
+            .into_iter()
+            .map(|action| {
+                let keys = action
+                    .default_keys()
+                    .iter()
+                    .map(|key| KeyBinding::parse(key).expect("Default key bindings are valid"))
+                    .collect();
+                (action, keys)
+            })
+            .collect();
+        KeyMap { bindings }
+    }
+}
+
+impl KeyMap {
+    /// Build a key map from a `[keys]` table of action name to key strings
+    /// Actions listed in the table replace their default keys; the rest keep the defaults.
+    /// Unknown action names are returned as warnings rather than failing the whole table, but a
+    /// key left bound to two actions, whether by the table or a default, is an error.
+    pub fn from_table(
+        table: &HashMap<String, Vec<String>>,
+    ) -> Result<(Self, Vec<String>), KeyMapError> {
+        let mut key_map = KeyMap::default();
+        let mut warnings = Vec::new();
+
+        for (name, keys) in table {
+            let Some(action) = Action::from_name(name) else {
+                warnings.push(format!("Ignoring unknown key binding action \"{}\"", name));
+                continue;
+            };
+
+            let keys = keys
+                .iter()
+                .map(|key| KeyBinding::parse(key))
+                .collect::<Result<Vec<_>, _>>()?;
+            if keys.is_empty() {
+                return Err(KeyMapError::Unbound(action.name()));
+            }
+
+            if let Some((_, bound)) = key_map.bindings.iter_mut().find(|(a, _)| *a == action) {
+                *bound = keys;
+            }
+        }
+
+        key_map.check_duplicates()?;
+        warnings.sort();
+        Ok((key_map, warnings))
+    }
+
+    fn check_duplicates(&self) -> Result<(), KeyMapError> {
+        let mut bound_to: HashMap<KeyBinding, Action> = HashMap::new();
+        for (action, keys) in &self.bindings {
+            for key in keys {
+                if let Some(first) = bound_to.insert(*key, *action)
+                    && first != *action
+                {
+                    return Err(KeyMapError::DuplicateKey(
+                        key.to_string(),
+                        first.name(),
+                        action.name(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The action bound to a key press, if any
+    pub fn action_for(&self, key: &KeyEvent) -> Option<Action> {
+        let pressed = KeyBinding::from_event(key);
+        self.bindings
+            .iter()
+            .find(|(_, keys)| keys.contains(&pressed))
+            .map(|(action, _)| *action)
+    }
+
+    /// One line per action with its keys, for the help overlay
+    pub fn help_lines(&self) -> Vec<String> {
+        self.bindings
+            .iter()
+            .map(|(action, keys)| {
+                let keys = keys
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{:<12} {}", keys, action.description())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn table(entries: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+        entries
+            .iter()
+            .map(|(action, keys)| {
+                (
+                    action.to_string(),
+                    keys.iter().map(|key| key.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_key_strings() {
+        assert_eq!(
+            KeyBinding::parse("q").unwrap(),
+            KeyBinding {
+                code: KeyCode::Char('q'),
+                modifiers: KeyModifiers::NONE
+            }
+        );
+        assert_eq!(
+            KeyBinding::parse("ctrl+c").unwrap(),
+            KeyBinding {
+                code: KeyCode::Char('c'),
+                modifiers: KeyModifiers::CONTROL
+            }
+        );
+        assert_eq!(KeyBinding::parse("Esc").unwrap().code, KeyCode::Esc);
+        assert_eq!(KeyBinding::parse("up").unwrap().code, KeyCode::Up);
+        assert_eq!(KeyBinding::parse("F5").unwrap().code, KeyCode::F(5));
+        assert_eq!(KeyBinding::parse("space").unwrap().code, KeyCode::Char(' '));
+        assert_eq!(
+            KeyBinding::parse("ctrl++").unwrap().code,
+            KeyCode::Char('+')
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_key_strings() {
+        for key in ["", "shift+q", "f13", "nope", "ctrl+"] {
+            assert!(
+                matches!(KeyBinding::parse(key), Err(KeyMapError::InvalidKey(_))),
+                "{key:?} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_key_map() {
+        let key_map = KeyMap::default();
+
+        assert_eq!(
+            key_map.action_for(&press(KeyCode::Char('q'), KeyModifiers::NONE)),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            key_map.action_for(&press(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            key_map.action_for(&press(KeyCode::Char('?'), KeyModifiers::SHIFT)),
+            Some(Action::ToggleHelp)
+        );
+        assert_eq!(
+            key_map.action_for(&press(KeyCode::Char('c'), KeyModifiers::NONE)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_table_overrides_only_listed_actions() {
+        let (key_map, warnings) =
+            KeyMap::from_table(&table(&[("quit", &["x", "ctrl+d"]), ("refresh", &["f5"])]))
+                .unwrap();
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            key_map.action_for(&press(KeyCode::Char('x'), KeyModifiers::NONE)),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            key_map.action_for(&press(KeyCode::Char('q'), KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!(
+            key_map.action_for(&press(KeyCode::F(5), KeyModifiers::NONE)),
+            Some(Action::Refresh)
+        );
+        // Untouched actions keep their defaults
+        assert_eq!(
+            key_map.action_for(&press(KeyCode::Char('i'), KeyModifiers::NONE)),
+            Some(Action::CycleIdent)
+        );
+    }
+
+    #[test]
+    fn test_table_warns_on_unknown_actions() {
+        let (_, warnings) = KeyMap::from_table(&table(&[("jump", &["j"])])).unwrap();
+
+        assert_eq!(
+            warnings,
+            vec!["Ignoring unknown key binding action \"jump\"".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_table_rejects_unbound_action() {
+        let error = KeyMap::from_table(&table(&[("quit", &[])])).unwrap_err();
+
+        assert!(matches!(error, KeyMapError::Unbound("quit")));
+        assert_eq!(error.to_string(), "Action \"quit\" has no keys bound to it");
+    }
+
+    #[test]
+    fn test_table_rejects_key_bound_twice() {
+        let error =
+            KeyMap::from_table(&table(&[("quit", &["x"]), ("refresh", &["x"])])).unwrap_err();
+        assert!(matches!(
+            error,
+            KeyMapError::DuplicateKey(_, "quit", "refresh")
+        ));
+
+        // Taking another action's default key counts too
+        let error = KeyMap::from_table(&table(&[("refresh", &["q"])])).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Key \"q\" is bound to both \"quit\" and \"refresh\""
+        );
+    }
+
+    #[test]
+    fn test_table_rejects_invalid_key() {
+        let error = KeyMap::from_table(&table(&[("quit", &["hyper+q"])])).unwrap_err();
+
+        assert!(matches!(error, KeyMapError::InvalidKey(_)));
+    }
+
+    #[test]
+    fn test_help_lines() {
+        let lines = KeyMap::default().help_lines();
+
+        assert_eq!(lines.len(), 13);
+        assert!(lines[0].starts_with("q, Esc, Ctrl+c"));
+        assert!(lines[0].ends_with("Quit"));
+    }
+}
@@ -1,5 +1,16 @@
+pub mod airlines;
 pub mod api_converter;
+pub mod connection;
+pub mod dto;
 pub mod flight_status;
+pub mod gpx;
+#[cfg(feature = "svg")]
+pub mod svg;
+pub mod timezones;
 
 pub use api_converter::determine_flight_status;
+pub use connection::{ConnectionRisk, Layover};
+pub use dto::FlightStatusDto;
 pub use flight_status::{FlightStatus, FlightStatusViewModel};
+#[cfg(feature = "svg")]
+pub use svg::render_svg;
@@ -1,20 +1,56 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use crossterm::event::KeyEvent;
 use flightaware::Client;
+use std::collections::HashSet;
 use std::fmt;
-
-mod flight_status;
-use flight_status::FlightStatusViewModel;
-
-mod api_converter;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+// The view model and its conversions live in the library crate; import the modules at the
+// crate root so binary-only modules can keep referring to them through `crate::`
+use flui::flight_status::AlertTrigger;
+use flui::{FlightStatusViewModel, airlines, connection, flight_status, gpx, timezones};
+
+mod big_text;
+mod config_file;
+mod delay_history;
+mod demo;
+mod fetch_error;
+mod flights_file;
+mod keymap;
+mod nearby;
+mod paths;
+mod selection;
+mod translations;
 mod ui;
+mod ui_state;
+
+use fetch_error::{FetchErrorKind, QuotaTracker, classify_fetch_error};
+use keymap::{Action, KeyMap, KeyMapError};
+use selection::{
+    SelectionStrategy, connected_legs, dedupe_flights, flights_with_callsign,
+    select_relevant_flight, select_sticky_flight,
+};
 
 #[cfg(feature = "httpmock")]
 mod mock_server;
 
+#[cfg(feature = "serve")]
+mod server;
+
 #[derive(Debug)]
 pub enum ConfigurationError {
     MissingFlightNumber,
     MissingApiKey,
+    BlankApiKey,
+    UnreadableFlightsFile(PathBuf, std::io::Error),
+    InvalidConfigFile(PathBuf, String),
+    InvalidKeyBindings(KeyMapError),
+    InvalidTimezonesFile(PathBuf, String),
+    InvalidAirlinesFile(PathBuf, String),
+    UnwritableOutputDir(paths::OutputError),
+    EmptyStdin,
+    UnreadableStdin(std::io::Error),
 }
 
 impl fmt::Display for ConfigurationError {
@@ -32,6 +68,57 @@ impl fmt::Display for ConfigurationError {
                     "FlightAware API key is required. Provide via --api-key flag or FLIGHTAWARE_API_KEY environment variable"
                 )
             }
+            ConfigurationError::BlankApiKey => {
+                write!(
+                    f,
+                    "FlightAware API key is empty. Check the value passed via --api-key or FLIGHTAWARE_API_KEY"
+                )
+            }
+            ConfigurationError::UnreadableFlightsFile(path, error) => {
+                write!(
+                    f,
+                    "Could not read flights file {}: {}",
+                    path.display(),
+                    error
+                )
+            }
+            ConfigurationError::InvalidConfigFile(path, error) => {
+                write!(
+                    f,
+                    "Could not load config file {}: {}",
+                    path.display(),
+                    error
+                )
+            }
+            ConfigurationError::InvalidKeyBindings(error) => {
+                write!(f, "Invalid [keys] table in config file: {}", error)
+            }
+            ConfigurationError::InvalidTimezonesFile(path, error) => {
+                write!(
+                    f,
+                    "Could not load timezones file {}: {}",
+                    path.display(),
+                    error
+                )
+            }
+            ConfigurationError::InvalidAirlinesFile(path, error) => {
+                write!(
+                    f,
+                    "Could not load airlines file {}: {}",
+                    path.display(),
+                    error
+                )
+            }
+            ConfigurationError::UnwritableOutputDir(error) => write!(f, "{}", error),
+            ConfigurationError::EmptyStdin => {
+                write!(
+                    f,
+                    "--stdin was given but standard input had no flight number, e.g. `echo AA100 | flui --stdin`"
+                )
+            }
+            ConfigurationError::UnreadableStdin(error) => {
+                write!(f, "Could not read the flight number from stdin: {}", error)
+            }
         }
     }
 }
@@ -42,27 +129,337 @@ impl std::error::Error for ConfigurationError {}
 #[command(name = "flui")]
 #[command(about = "Flight tracker application", long_about = None)]
 struct CliArgs {
+    #[command(subcommand)]
+    command: Option<CliCommand>,
+
+    /// TOML config file with settings that don't fit on the command line, such as key bindings
+    #[clap(long, env = "FLUI_CONFIG")]
+    config: Option<PathBuf>,
+
     #[clap(long, env = "FLIGHT_NUMBER")]
     flight_number: Option<String>,
 
+    /// Read the flight number from the first line of standard input, taking precedence over
+    /// --flight-number; the TUI still reads keys from the terminal
+    #[clap(long)]
+    stdin: bool,
+
     #[clap(long, env = "FLIGHTAWARE_API_KEY")]
     api_key: Option<String>,
 
-    #[clap(long, env = "REFRESH_INTERVAL", default_value = "180")]
+    /// Time between API updates, in seconds or with units such as 90s, 2m or 1m30s
+    #[clap(long, env = "REFRESH_INTERVAL", default_value = "180", value_parser = parse_duration_secs)]
     refresh_interval: u64,
 
+    /// Time between API updates once the flight is within --alert-threshold-minutes of landing,
+    /// in the same format as --refresh-interval
+    #[clap(long, env = "NEAR_LANDING_REFRESH_INTERVAL", default_value_t = DEFAULT_NEAR_LANDING_REFRESH_INTERVAL, value_parser = parse_duration_secs)]
+    near_landing_refresh_interval: u64,
+
+    /// Seconds without a successful update before the data is flagged as stale
+    #[clap(long, env = "STALE_AFTER_SECS", default_value_t = DEFAULT_STALE_AFTER_SECS)]
+    stale_after_secs: u64,
+
+    /// Poll on the exact interval instead of spreading requests by up to ±20%
+    #[clap(long, env = "NO_JITTER")]
+    no_jitter: bool,
+
+    /// Seconds to wait for a FlightAware response before giving up on the request
+    #[clap(long, env = "API_TIMEOUT_SECS", default_value_t = DEFAULT_API_TIMEOUT_SECS)]
+    api_timeout_secs: u64,
+
+    /// Seconds between TCP keepalive probes on an idle FlightAware connection, so it survives
+    /// the wait between refreshes; 0 sends none
+    #[clap(long, env = "TCP_KEEPALIVE_SECS", default_value_t = DEFAULT_TCP_KEEPALIVE_SECS)]
+    tcp_keepalive_secs: u64,
+
+    /// Keep the airplane still while it taxis and don't flash the status when it changes
+    #[clap(long, env = "NO_ANIMATION")]
+    no_animation: bool,
+
+    /// Most frames per second the TUI draws; with nothing changing it redraws once a second
+    #[clap(long, env = "MAX_FPS", default_value_t = DEFAULT_MAX_FPS, value_parser = clap::value_parser!(u32).range(1..))]
+    max_fps: u32,
+
     #[clap(long, env = "ALERT_THRESHOLD_MINUTES", default_value = "30")]
     alert_threshold_minutes: i64,
+
+    /// Alert once this percentage of the route has been flown, instead of
+    /// --alert-threshold-minutes before landing
+    #[clap(long, env = "ALERT_AT_PROGRESS")]
+    alert_at_progress: Option<f64>,
+
+    /// Show the arrival as a 24-hour clock time ("arrives 14:30") instead of a countdown
+    #[clap(long, env = "ETA_CLOCK")]
+    eta_clock: bool,
+
+    /// IANA timezone for arrival times and --eta-clock, e.g. Asia/Tokyo; defaults to the local
+    /// timezone
+    #[clap(long, env = "ETA_TIMEZONE")]
+    eta_timezone: Option<chrono_tz::Tz>,
+
+    /// Minutes before the estimated departure that boarding is assumed to start
+    #[clap(long, env = "BOARDING_OFFSET_MINUTES", default_value_t = ui::DEFAULT_BOARDING_OFFSET_MINUTES)]
+    boarding_offset_minutes: i64,
+
+    /// Progress percentages to ring the terminal bell at, e.g. 50,90; each rings once per session
+    #[clap(long, env = "BELL_AT", value_delimiter = ',', value_parser = clap::value_parser!(u8).range(1..=100))]
+    bell_at: Vec<u8>,
+
+    /// Shell command to run once, without waiting for it, when the landing alert first fires,
+    /// e.g. "paplay ~/chime.wav"; it runs with your privileges, so only pass commands you trust
+    #[clap(long)]
+    alert_command: Option<String>,
+
+    /// Which form of the flight identifier to display; press `i` to cycle while running
+    /// [default: raw, or whatever was shown when flui last exited]
+    #[clap(long, env = "IDENT_FORMAT", value_enum)]
+    ident_format: Option<ui::IdentFormat>,
+
+    /// Hide the flight path and show only the status and time text; press `v` to toggle while running
+    #[clap(long, env = "MINIMAL", overrides_with = "no_minimal")]
+    minimal: bool,
+
+    /// Show the flight path even when minimal mode was on when flui last exited
+    #[clap(long, overrides_with = "minimal")]
+    no_minimal: bool,
+
+    /// Widest the flight path block gets, in columns; wider terminals center it
+    #[clap(long, env = "MAX_PATH_WIDTH", default_value_t = ui::DEFAULT_MAX_PATH_WIDTH, value_parser = clap::value_parser!(u16).range(20..))]
+    max_path_width: u16,
+
+    /// Draw the flight path as a column from origin at the bottom to destination at the top
+    /// when the terminal is taller than it is wide, e.g. in a narrow side pane
+    #[clap(long, env = "VERTICAL_PATH")]
+    vertical_path: bool,
+
+    /// Draw with ASCII characters only; on by default when the locale isn't UTF-8
+    #[clap(long, env = "ASCII")]
+    ascii: bool,
+
+    /// Replace the flight number with a placeholder everywhere it's drawn, for screenshots
+    #[clap(long, env = "REDACT")]
+    redact: bool,
+
+    /// Convey status with bold, reverse and dim instead of color, for e-ink or high contrast
+    #[clap(long, env = "MONO")]
+    mono: bool,
+
+    /// Show scheduled and current gate-to-gate times as bars; press `b` to toggle while running
+    #[clap(long, env = "BLOCK_TIMES", overrides_with = "no_block_times")]
+    block_times: bool,
+
+    /// Hide the block time bars even when they were showing when flui last exited
+    #[clap(long, overrides_with = "block_times")]
+    no_block_times: bool,
+
+    /// Chart the delay seen on each refresh in the space under the flight status, to show
+    /// whether it's growing or shrinking
+    #[clap(long, env = "DELAY_HISTORY")]
+    delay_history: bool,
+
+    /// Show the flight as a grid of large tiles for a wall-mounted display; falls back to the
+    /// usual layout on small terminals
+    #[clap(long, env = "DASHBOARD")]
+    dashboard: bool,
+
+    /// Language for status names and labels
+    #[clap(long, env = "FLUI_LANG", value_enum, default_value_t)]
+    lang: translations::Language,
+
+    /// Minimum delay in minutes before the status is colored as delayed
+    #[clap(long, env = "UI_DELAY_COLOR_THRESHOLD", default_value_t = ui::DEFAULT_DELAY_COLOR_THRESHOLD_MINUTES)]
+    ui_delay_color_threshold: i64,
+
+    /// How to pick a flight when the flight number matches several legs
+    #[clap(long, env = "SELECT_STRATEGY", value_enum, default_value_t)]
+    select_strategy: SelectionStrategy,
+
+    /// Airport you usually fly from, e.g. SEA; preferred when a flight number matches equally
+    /// relevant legs, and marked on the flight path
+    #[clap(long, env = "HOME_AIRPORT")]
+    home_airport: Option<String>,
+
+    /// Track the legs flown under this ATC callsign, e.g. a general aviation flight's; looked up
+    /// as the flight number unless --flight-number is also given
+    #[clap(long, env = "CALLSIGN")]
+    callsign: Option<String>,
+
+    /// Whether the flight has departed once it pushes back from the gate or once it takes off;
+    /// affects the status, the departure time and time-based progress
+    #[clap(long, env = "DEPARTURE_REFERENCE", default_value = "runway")]
+    departure_reference: flight_status::DepartureReference,
+
+    /// Whether the flight has arrived once it touches down or once it reaches the gate;
+    /// affects the status, the countdown and the landing alert
+    #[clap(long, env = "ARRIVAL_DEFINITION", default_value = "gate")]
+    arrival_definition: flight_status::ArrivalDefinition,
+
+    /// Flight number of an onward connection to analyze the layover against
+    #[clap(long, env = "CONNECTING_FLIGHT")]
+    connecting_flight: Option<String>,
+
+    /// Rows per flight on the board: compact fits one flight per line, spacious adds a
+    /// progress bar under each; press `s` to toggle while running
+    /// [default: compact, or whatever was shown when flui last exited]
+    #[clap(long, env = "BOARD_DENSITY", value_enum)]
+    board_density: Option<ui::BoardDensity>,
+
+    /// Track every flight listed in this file (one per line, # for comments) on a board
+    #[clap(long, env = "FLIGHTS_FILE")]
+    flights_file: Option<PathBuf>,
+
+    /// List the flights overhead within --radius of this point, given as LAT,LON in decimal
+    /// degrees, and pick one to track
+    #[clap(long, env = "NEAR", value_parser = nearby::parse_coordinates)]
+    near: Option<nearby::Coordinates>,
+
+    /// How far from --near to look for flights, in nautical miles
+    #[clap(long, env = "RADIUS", default_value = "25", value_parser = nearby::parse_radius)]
+    radius: f64,
+
+    /// Airport timezones (IATA code and IANA name per line) to use on top of the built-in set
+    #[clap(long, env = "TIMEZONES_FILE")]
+    timezones_file: Option<PathBuf>,
+
+    /// Show the airline's full name next to the flight number, e.g. American Airlines for AAL
+    #[clap(long, env = "AIRLINE_NAMES")]
+    airline_names: bool,
+
+    /// Airline names (IATA or ICAO code, then the name, per line) to use on top of the
+    /// built-in set
+    #[clap(long, env = "AIRLINES_FILE")]
+    airlines_file: Option<PathBuf>,
+
+    /// Poll the aircraft's position while running and write the recorded track to this file
+    /// as GPX on exit
+    #[clap(long, env = "GPX")]
+    gpx: Option<PathBuf>,
+
+    /// Start with the raw API JSON debug panel open (toggle with `D`) and show how long each
+    /// FlightAware request takes in the footer
+    #[clap(long)]
+    debug: bool,
+
+    /// Print development diagnostics, such as the mock server's address, to stderr
+    #[clap(long, env = "VERBOSE")]
+    verbose: bool,
+
+    /// Exit when the first fetch fails instead of opening the TUI and retrying in the background
+    #[clap(long)]
+    exit_on_error: bool,
+
+    /// Play a scripted flight from boarding to the gate without calling FlightAware, so no
+    /// API key or flight number is needed
+    #[clap(long)]
+    demo: bool,
+
+    /// Fetch once, draw the full layout inline in the terminal's scrollback, and exit
+    /// Prints the same layout as plain text when stdout isn't a terminal
+    #[clap(long)]
+    once: bool,
+
+    /// Fetch once, write a single rendered frame to this file as plain text, and exit
+    #[clap(long)]
+    snapshot: Option<PathBuf>,
+
+    /// Directory for the files flui writes: relative --snapshot and --gpx paths go here, as
+    /// does the remembered display state; created if missing
+    /// [default: $XDG_CACHE_HOME/flui or ~/.cache/flui]
+    #[clap(long, env = "OUTPUT_DIR")]
+    output_dir: Option<PathBuf>,
+
+    #[clap(long, default_value_t = DEFAULT_SNAPSHOT_WIDTH)]
+    snapshot_width: u16,
+
+    #[clap(long, default_value_t = DEFAULT_SNAPSHOT_HEIGHT)]
+    snapshot_height: u16,
+
+    /// Serve the flight status as JSON over HTTP instead of running the TUI
+    #[cfg(feature = "serve")]
+    #[clap(long)]
+    serve: bool,
+
+    #[cfg(feature = "serve")]
+    #[clap(long, env = "PORT", default_value = "8080")]
+    port: u16,
+}
+
+#[derive(Subcommand, Debug)]
+enum CliCommand {
+    /// Show two alternative flights side by side, e.g. when deciding which to rebook onto
+    Compare { first: String, second: String },
 }
 
 #[derive(Debug)]
 pub struct Config {
     pub flight_number: String,
+    pub flight_number_from_stdin: bool,
     pub flight_aware_api_key: String,
     pub refresh_interval: u64,
+    pub near_landing_refresh_interval: u64,
+    pub stale_after_secs: u64,
+    pub refresh_jitter: bool,
+    pub api_timeout_secs: u64,
+    pub tcp_keepalive_secs: u64,
+    pub animate: bool,
+    pub max_fps: u32,
     pub alert_threshold_minutes: i64,
+    pub alert_trigger: AlertTrigger,
+    pub bell_at: Vec<u8>,
+    pub alert_command: Option<String>,
+    pub boarding_offset_minutes: i64,
+    pub eta_clock: bool,
+    pub eta_timezone: Option<chrono_tz::Tz>,
+    pub ui_delay_color_threshold: i64,
+    pub ident_format: ui::IdentFormat,
+    pub minimal: bool,
+    pub timetable: bool,
+    pub max_path_width: u16,
+    pub vertical_path: bool,
+    pub ascii: bool,
+    pub redact: bool,
+    pub mono: bool,
+    pub language: translations::Language,
+    pub block_times: bool,
+    pub delay_history: bool,
+    pub dashboard: bool,
+    pub key_map: KeyMap,
+    pub debug_panel: bool,
+    pub verbose: bool,
+    pub select_strategy: SelectionStrategy,
+    pub home_airport: Option<String>,
+    pub callsign: Option<String>,
+    pub departure_reference: flight_status::DepartureReference,
+    pub arrival_definition: flight_status::ArrivalDefinition,
+    pub connecting_flight: Option<String>,
+    pub board_flights: Vec<String>,
+    pub near: Option<nearby::SearchArea>,
+    pub board_density: ui::BoardDensity,
+    pub compare_flights: Option<(String, String)>,
+    pub timezones_file: Option<PathBuf>,
+    pub airline_names: bool,
+    pub airlines_file: Option<PathBuf>,
+    pub gpx_path: Option<PathBuf>,
+    pub exit_on_error: bool,
+    pub demo: bool,
+    pub once: bool,
+    pub snapshot_path: Option<PathBuf>,
+    pub output_dir: Option<PathBuf>,
+    pub snapshot_width: u16,
+    pub snapshot_height: u16,
+    pub serve_port: Option<u16>,
 }
 
+/// Defaults shared by the command line and `Config::from_options`
+const DEFAULT_NEAR_LANDING_REFRESH_INTERVAL: u64 = 30;
+const DEFAULT_STALE_AFTER_SECS: u64 = 60;
+const DEFAULT_API_TIMEOUT_SECS: u64 = 15;
+const DEFAULT_TCP_KEEPALIVE_SECS: u64 = 60;
+const DEFAULT_MAX_FPS: u32 = 10;
+const DEFAULT_SNAPSHOT_WIDTH: u16 = 80;
+const DEFAULT_SNAPSHOT_HEIGHT: u16 = 24;
+
 impl Config {
     pub fn from_options(
         flight_number: Option<String>,
@@ -71,13 +468,72 @@ impl Config {
         alert_threshold_minutes: i64,
     ) -> Result<Self, ConfigurationError> {
         let flight_number = flight_number.ok_or(ConfigurationError::MissingFlightNumber)?;
-        let flight_aware_api_key = api_key.ok_or(ConfigurationError::MissingApiKey)?;
+        // Keys pasted with a trailing newline or space would otherwise be rejected with a 401
+        let flight_aware_api_key = api_key
+            .ok_or(ConfigurationError::MissingApiKey)?
+            .trim()
+            .to_string();
+        if flight_aware_api_key.is_empty() {
+            return Err(ConfigurationError::BlankApiKey);
+        }
 
         Ok(Config {
             flight_number,
+            flight_number_from_stdin: false,
             flight_aware_api_key,
             refresh_interval,
+            near_landing_refresh_interval: DEFAULT_NEAR_LANDING_REFRESH_INTERVAL,
+            stale_after_secs: DEFAULT_STALE_AFTER_SECS,
+            refresh_jitter: true,
+            api_timeout_secs: DEFAULT_API_TIMEOUT_SECS,
+            tcp_keepalive_secs: DEFAULT_TCP_KEEPALIVE_SECS,
+            animate: true,
+            max_fps: DEFAULT_MAX_FPS,
             alert_threshold_minutes,
+            alert_trigger: AlertTrigger::Minutes(alert_threshold_minutes),
+            bell_at: Vec::new(),
+            alert_command: None,
+            boarding_offset_minutes: ui::DEFAULT_BOARDING_OFFSET_MINUTES,
+            eta_clock: false,
+            eta_timezone: None,
+            ui_delay_color_threshold: ui::DEFAULT_DELAY_COLOR_THRESHOLD_MINUTES,
+            ident_format: ui::IdentFormat::default(),
+            minimal: false,
+            timetable: false,
+            max_path_width: ui::DEFAULT_MAX_PATH_WIDTH,
+            vertical_path: false,
+            ascii: false,
+            redact: false,
+            mono: false,
+            language: translations::Language::default(),
+            block_times: false,
+            delay_history: false,
+            dashboard: false,
+            key_map: KeyMap::default(),
+            debug_panel: false,
+            verbose: false,
+            select_strategy: SelectionStrategy::default(),
+            home_airport: None,
+            callsign: None,
+            departure_reference: flight_status::DepartureReference::default(),
+            arrival_definition: flight_status::ArrivalDefinition::default(),
+            connecting_flight: None,
+            board_flights: Vec::new(),
+            near: None,
+            board_density: ui::BoardDensity::default(),
+            compare_flights: None,
+            timezones_file: None,
+            airline_names: false,
+            airlines_file: None,
+            gpx_path: None,
+            exit_on_error: false,
+            demo: false,
+            once: false,
+            snapshot_path: None,
+            output_dir: None,
+            snapshot_width: DEFAULT_SNAPSHOT_WIDTH,
+            snapshot_height: DEFAULT_SNAPSHOT_HEIGHT,
+            serve_port: None,
         })
     }
 }
@@ -87,7 +543,37 @@ fn create_flightaware_client(http_client: reqwest::Client, base_url: Option<&str
     Client::new_with_client(url, http_client)
 }
 
-fn create_authenticated_http_client(api_key: &str) -> reqwest::Client {
+/// How the HTTP client times out requests and holds on to connections between refreshes
+#[derive(Debug, Clone, Copy)]
+struct HttpClientOptions {
+    timeout: std::time::Duration,
+    /// Interval between TCP keepalive probes, or None to send none
+    tcp_keepalive: Option<std::time::Duration>,
+    /// How long an unused connection stays pooled before it's closed
+    pool_idle_timeout: std::time::Duration,
+}
+
+/// Slack on top of the slowest poll before an idle connection is dropped
+const POOL_IDLE_MARGIN: std::time::Duration = std::time::Duration::from_secs(30);
+
+impl HttpClientOptions {
+    /// Connections are kept a little longer than the slowest jittered poll, so every refresh
+    /// reuses the previous one's connection instead of paying for a new TLS handshake
+    fn from_config(config: &Config) -> Self {
+        let slowest_poll = std::time::Duration::from_secs(config.refresh_interval)
+            .mul_f64(1.0 + REFRESH_JITTER_FRACTION);
+        HttpClientOptions {
+            timeout: std::time::Duration::from_secs(config.api_timeout_secs),
+            tcp_keepalive: (config.tcp_keepalive_secs > 0)
+                .then(|| std::time::Duration::from_secs(config.tcp_keepalive_secs)),
+            pool_idle_timeout: slowest_poll + POOL_IDLE_MARGIN,
+        }
+    }
+}
+
+/// Build the HTTP client used for FlightAware requests
+/// reqwest never times out by default, so a stalled connection would otherwise hang the refresh task
+fn create_authenticated_http_client(api_key: &str, options: HttpClientOptions) -> reqwest::Client {
     let mut headers = reqwest::header::HeaderMap::new();
     headers.insert(
         "x-apikey",
@@ -96,528 +582,2870 @@ fn create_authenticated_http_client(api_key: &str) -> reqwest::Client {
 
     reqwest::Client::builder()
         .default_headers(headers)
+        .timeout(options.timeout)
+        .tcp_keepalive(options.tcp_keepalive)
+        .pool_idle_timeout(options.pool_idle_timeout)
         .build()
         .expect("Failed to build HTTP client")
 }
 
 fn get_config() -> Result<Config, ConfigurationError> {
     let args = CliArgs::parse();
-    println!("args: {args:?}");
-    Config::from_options(
-        args.flight_number,
-        args.api_key,
-        args.refresh_interval,
-        args.alert_threshold_minutes,
-    )
+    let saved_ui_state = ui_state::path(args.output_dir.as_deref())
+        .map(|path| ui_state::load(&path))
+        .unwrap_or_default();
+    let config = config_from_args(args, &saved_ui_state, std::io::stdin().lock())?;
+
+    // Lookups are cached for the whole session, so the file is loaded once up front
+    if let Some(path) = &config.timezones_file {
+        timezones::load_timezones_file(path)
+            .map_err(|error| ConfigurationError::InvalidTimezonesFile(path.clone(), error))?;
+    }
+    if let Some(path) = &config.airlines_file {
+        airlines::load_airlines_file(path)
+            .map_err(|error| ConfigurationError::InvalidAirlinesFile(path.clone(), error))?;
+    }
+    // Made up front so a directory that can't be written fails now, not after the session
+    if let Some(dir) = &config.output_dir {
+        paths::ensure_dir(dir).map_err(ConfigurationError::UnwritableOutputDir)?;
+    }
+
+    Ok(config)
 }
 
-/// Select the most relevant flight from a list of flights
-/// Returns the flight whose estimated arrival time is closest to (current_time - 2 hours)
-fn select_relevant_flight(
-    flights: &[flightaware::types::GetFlightResponseFlightsItem],
-) -> Option<&flightaware::types::GetFlightResponseFlightsItem> {
-    use chrono::Utc;
+/// Resolve the parsed arguments into a Config, reading the flight number from `stdin` when
+/// --stdin is set; this happens before the TUI takes over the terminal
+/// Toggles not given on the command line come from `saved_ui_state`, remembered from the last run
+fn config_from_args(
+    args: CliArgs,
+    saved_ui_state: &ui_state::UiState,
+    stdin: impl std::io::BufRead,
+) -> Result<Config, ConfigurationError> {
+    let flight_number = if args.stdin {
+        Some(read_flight_number(stdin)?)
+    } else {
+        args.flight_number
+    };
 
-    if flights.is_empty() {
-        return None;
-    }
+    let key_map = match &args.config {
+        Some(path) => {
+            let config_file = config_file::read_config_file(path)
+                .map_err(|error| ConfigurationError::InvalidConfigFile(path.clone(), error))?;
+            let (key_map, warnings) = KeyMap::from_table(&config_file.keys)
+                .map_err(ConfigurationError::InvalidKeyBindings)?;
+            for warning in warnings {
+                eprintln!("Warning: {}", warning);
+            }
+            key_map
+        }
+        None => KeyMap::default(),
+    };
+
+    let board_flights = match &args.flights_file {
+        Some(path) => flights_file::read_flights_file(path)
+            .map_err(|error| ConfigurationError::UnreadableFlightsFile(path.clone(), error))?,
+        None => Vec::new(),
+    };
 
-    // Target time is 2 hours ago from now
-    let now = Utc::now();
-    let target_time = now - chrono::Duration::hours(2);
+    let compare_flights = args.command.map(|command| match command {
+        CliCommand::Compare { first, second } => (first, second),
+    });
 
-    // Find the flight with estimated arrival closest to target time
-    flights
-        .iter()
-        .filter_map(|flight| {
-            flight.estimated_on.as_ref().map(|arrival| {
-                let diff = if arrival > &target_time {
-                    *arrival - target_time
-                } else {
-                    target_time - *arrival
-                };
-                (diff, flight)
-            })
-        })
-        .min_by_key(|(diff, _)| *diff)
-        .map(|(_, flight)| flight)
-        .or_else(|| flights.first())
-}
+    // The callsign or the first listed or compared flight stands in for --flight-number when
+    // none is given, --near picks its flight once running, and --demo never calls the API so
+    // it doesn't need either
+    let config = Config::from_options(
+        flight_number
+            .or_else(|| args.callsign.clone())
+            .or_else(|| board_flights.first().cloned())
+            .or_else(|| compare_flights.as_ref().map(|(first, _)| first.clone()))
+            .or_else(|| args.near.is_some().then(String::new))
+            .or_else(|| args.demo.then(|| demo::FLIGHT_NUMBER.to_string())),
+        args.api_key
+            .clone()
+            .or_else(|| args.demo.then(|| "demo".to_string())),
+        args.refresh_interval,
+        args.alert_threshold_minutes,
+    )?;
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let config = get_config().unwrap();
+    let config = Config {
+        flight_number_from_stdin: args.stdin,
+        near_landing_refresh_interval: args.near_landing_refresh_interval,
+        stale_after_secs: args.stale_after_secs,
+        refresh_jitter: !args.no_jitter,
+        api_timeout_secs: args.api_timeout_secs,
+        tcp_keepalive_secs: args.tcp_keepalive_secs,
+        animate: !args.no_animation,
+        max_fps: args.max_fps,
+        alert_trigger: args
+            .alert_at_progress
+            .map(AlertTrigger::Progress)
+            .unwrap_or(config.alert_trigger),
+        bell_at: args.bell_at,
+        alert_command: args.alert_command,
+        boarding_offset_minutes: args.boarding_offset_minutes,
+        eta_clock: args.eta_clock,
+        eta_timezone: args.eta_timezone,
+        ui_delay_color_threshold: args.ui_delay_color_threshold,
+        ident_format: args.ident_format.unwrap_or(saved_ui_state.ident_format),
+        minimal: args.minimal || (saved_ui_state.minimal && !args.no_minimal),
+        timetable: saved_ui_state.timetable,
+        max_path_width: args.max_path_width,
+        vertical_path: args.vertical_path,
+        ascii: args.ascii || !locale_is_utf8(&locale_from_env()),
+        redact: args.redact,
+        mono: args.mono,
+        language: args.lang,
+        block_times: args.block_times || (saved_ui_state.block_times && !args.no_block_times),
+        delay_history: args.delay_history,
+        dashboard: args.dashboard,
+        key_map,
+        debug_panel: args.debug,
+        verbose: args.verbose,
+        select_strategy: args.select_strategy,
+        home_airport: args.home_airport,
+        callsign: args.callsign,
+        departure_reference: args.departure_reference,
+        arrival_definition: args.arrival_definition,
+        connecting_flight: args.connecting_flight,
+        board_flights,
+        near: args.near.map(|center| nearby::SearchArea {
+            center,
+            radius_nm: args.radius,
+        }),
+        board_density: args.board_density.unwrap_or(saved_ui_state.board_density),
+        compare_flights,
+        timezones_file: args.timezones_file,
+        airline_names: args.airline_names,
+        airlines_file: args.airlines_file,
+        gpx_path: args
+            .gpx
+            .map(|path| paths::output_path(args.output_dir.as_deref(), &path)),
+        exit_on_error: args.exit_on_error,
+        demo: args.demo,
+        once: args.once,
+        snapshot_path: args
+            .snapshot
+            .map(|path| paths::output_path(args.output_dir.as_deref(), &path)),
+        output_dir: args.output_dir,
+        snapshot_width: args.snapshot_width,
+        snapshot_height: args.snapshot_height,
+        ..config
+    };
 
-    // Start mock server if httpmock feature is enabled
-    #[cfg(feature = "httpmock")]
-    let (_mock_base_url, _mock_server) = {
-        let (base_url, server) = mock_server::start_mock_server();
-        (base_url.clone(), server)
+    #[cfg(feature = "serve")]
+    let config = Config {
+        serve_port: args.serve.then_some(args.port),
+        ..config
     };
 
-    // Use mock server URL if available, otherwise use production
-    #[cfg(feature = "httpmock")]
-    let base_url = Some(_mock_base_url.as_str());
+    Ok(config)
+}
 
-    #[cfg(not(feature = "httpmock"))]
-    let base_url: Option<&str> = None;
+/// The first line of `stdin`, trimmed, as a flight number
+fn read_flight_number(mut stdin: impl std::io::BufRead) -> Result<String, ConfigurationError> {
+    let mut line = String::new();
+    stdin
+        .read_line(&mut line)
+        .map_err(ConfigurationError::UnreadableStdin)?;
 
-    let http_client = create_authenticated_http_client(&config.flight_aware_api_key);
-    let client = create_flightaware_client(http_client, base_url);
+    match line.trim() {
+        "" => Err(ConfigurationError::EmptyStdin),
+        flight_number => Ok(flight_number.to_string()),
+    }
+}
 
-    // Fetch initial flight data
-    let initial_flight_status = client
-        .get_flight(&config.flight_number, None, None, None, None, None)
-        .await;
-
-    let initial_view_model = match initial_flight_status {
-        Ok(response) => {
-            if let Some(flight) = select_relevant_flight(&response.flights) {
-                FlightStatusViewModel::from(flight)
-            } else {
-                println!("No flight data found for {}", config.flight_number);
-                return Ok(());
-            }
-        }
-        Err(e) => {
-            println!("Error fetching flight data: {}", e);
-            return Ok(());
-        }
+/// Parse a duration given as bare seconds ("90") or as hours, minutes and seconds with unit
+/// suffixes in that order ("2m", "1m30s", "1h"), into seconds
+fn parse_duration_secs(text: &str) -> Result<u64, String> {
+    let invalid = || {
+        format!(
+            "invalid duration \"{}\", expected e.g. 90, 90s, 2m or 1m30s",
+            text
+        )
     };
 
-    // Create channel for flight updates
-    let (tx, mut rx) = tokio::sync::mpsc::channel::<FlightStatusViewModel>(10);
-
-    // Spawn background task to fetch flight updates
-    let flight_number = config.flight_number.clone();
-    let refresh_interval = config.refresh_interval;
-    tokio::spawn(async move {
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(refresh_interval));
-        interval.tick().await; // Skip first tick (we already have initial data)
+    if let Ok(seconds) = text.parse::<u64>() {
+        return Ok(seconds);
+    }
+    if text.is_empty() {
+        return Err(invalid());
+    }
 
-        loop {
-            interval.tick().await;
+    let mut seconds: u64 = 0;
+    let mut remaining = text;
+    // Units already used, so each appears at most once and from largest to smallest
+    let mut units = [('h', 3600), ('m', 60), ('s', 1)].as_slice();
+    while !remaining.is_empty() {
+        let digits = remaining
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        let amount: u64 = remaining[..digits].parse().map_err(|_| invalid())?;
+        let unit = remaining[digits..].chars().next().ok_or_else(invalid)?;
+        let position = units
+            .iter()
+            .position(|(suffix, _)| *suffix == unit)
+            .ok_or_else(invalid)?;
+
+        seconds = amount
+            .checked_mul(units[position].1)
+            .and_then(|amount| seconds.checked_add(amount))
+            .ok_or_else(invalid)?;
+        units = &units[position + 1..];
+        remaining = &remaining[digits + unit.len_utf8()..];
+    }
 
-            let flight_status = client
-                .get_flight(&flight_number, None, None, None, None, None)
-                .await;
+    Ok(seconds)
+}
 
-            if let Ok(response) = flight_status
-                && let Some(flight) = select_relevant_flight(&response.flights)
-            {
-                let view_model = FlightStatusViewModel::from(flight);
-                // change
-                if tx.send(view_model).await.is_err() {
-                    // Channel closed, exit task
-                    break;
-                }
-            }
-        }
-    });
+/// The locale that governs character encoding, following the usual precedence of
+/// LC_ALL over LC_CTYPE over LANG
+fn locale_from_env() -> Option<String> {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+}
 
-    // Setup terminal
-    crossterm::terminal::enable_raw_mode()?;
-    let mut stdout = std::io::stdout();
-    crossterm::execute!(stdout, crossterm::terminal::EnterAlternateScreen)?;
+/// Whether the terminal can be expected to show Unicode glyphs
+/// With no locale set at all there is nothing to go on, so Unicode is assumed
+fn locale_is_utf8(locale: &Option<String>) -> bool {
+    locale.as_deref().is_none_or(|locale| {
+        let locale = locale.to_lowercase();
+        locale.contains("utf-8") || locale.contains("utf8")
+    })
+}
 
-    let backend = ratatui::backend::CrosstermBackend::new(stdout);
-    let mut terminal = ratatui::Terminal::new(backend)?;
+/// Resolve when the process receives SIGINT or SIGTERM
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("Failed to install SIGINT handler");
+    };
 
-    // Current view model
-    let mut current_view_model = initial_view_model;
-    let mut alert_triggered = false;
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
 
-    // Event loop
-    use crossterm::event::{self, Event, KeyCode};
-    loop {
-        // Check if we're approaching landing
-        let is_alert = current_view_model.is_approaching_landing(config.alert_threshold_minutes);
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
 
-        // Trigger terminal bell/flash on first alert
-        if is_alert && !alert_triggered {
-            // Ring the terminal bell
-            crossterm::execute!(terminal.backend_mut(), crossterm::style::Print("\x07"))?;
-            alert_triggered = true;
-        } else if !is_alert {
-            alert_triggered = false;
-        }
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
 
-        // Draw the UI
-        terminal.draw(|frame| {
-            ui::render_flight_status(frame, &current_view_model, is_alert);
-        })?;
+/// A freshly fetched flight: the view model plus the selected flight as pretty-printed JSON,
+/// kept for the debug panel, and whatever auxiliary data was fetched alongside it
+struct FlightUpdate {
+    view_model: FlightStatusViewModel,
+    raw_json: String,
+    fa_flight_id: String,
+    /// The aircraft's previous leg, when it was fetched and found
+    inbound: Option<FlightStatusViewModel>,
+    /// The aircraft's latest reported position, when it was fetched and known
+    position: Option<gpx::TrackPoint>,
+    /// How long FlightAware took to answer the flight request, when one was made
+    latency: Option<std::time::Duration>,
+    /// Every leg of a multi-leg flight in the order they're flown, this one included; empty
+    /// when the flight has a single leg
+    legs: Vec<FlightStatusViewModel>,
+}
 
-        // Check for updates or user input (with timeout)
-        if event::poll(std::time::Duration::from_millis(100))?
-            && let Event::Key(key) = event::read()?
-            && (key.code == KeyCode::Char('q') || key.code == KeyCode::Esc)
-        {
-            break;
+impl FlightUpdate {
+    fn new(
+        flight: &flightaware::types::GetFlightResponseFlightsItem,
+        departure_reference: flight_status::DepartureReference,
+        arrival_definition: flight_status::ArrivalDefinition,
+    ) -> Self {
+        FlightUpdate {
+            view_model: FlightStatusViewModel::from(flight)
+                .with_arrival_definition(arrival_definition)
+                .with_departure_reference(departure_reference),
+            raw_json: serde_json::to_string_pretty(flight)
+                .unwrap_or_else(|error| format!("Could not serialize flight: {}", error)),
+            fa_flight_id: flight.fa_flight_id.clone(),
+            inbound: None,
+            position: None,
+            latency: None,
+            legs: Vec::new(),
         }
+    }
 
-        // Check for flight updates (non-blocking)
-        if let Ok(updated_view_model) = rx.try_recv() {
-            current_view_model = updated_view_model;
+    /// Fill in the other legs flown on the same trip as `flight`, out of the listed `flights`
+    fn with_legs(
+        mut self,
+        flights: &[flightaware::types::GetFlightResponseFlightsItem],
+        flight: &flightaware::types::GetFlightResponseFlightsItem,
+        departure_reference: flight_status::DepartureReference,
+        arrival_definition: flight_status::ArrivalDefinition,
+    ) -> Self {
+        let legs = connected_legs(flights, flight);
+        if legs.len() > 1 {
+            self.legs = legs
+                .into_iter()
+                .map(|leg| {
+                    FlightStatusViewModel::from(leg)
+                        .with_arrival_definition(arrival_definition)
+                        .with_departure_reference(departure_reference)
+                })
+                .collect();
         }
+        self
     }
+}
 
-    // Restore terminal
-    crossterm::terminal::disable_raw_mode()?;
-    crossterm::execute!(
-        terminal.backend_mut(),
-        crossterm::terminal::LeaveAlternateScreen
-    )?;
-
-    Ok(())
+/// The scripted --demo flight `elapsed` into the demo, as if it had been fetched
+fn demo_update(elapsed: std::time::Duration) -> FlightUpdate {
+    let view_model = demo::demo_flight(elapsed, chrono::Utc::now());
+    FlightUpdate {
+        raw_json: serde_json::to_string_pretty(&view_model)
+            .unwrap_or_else(|error| format!("Could not serialize flight: {}", error)),
+        fa_flight_id: format!("{}-demo", demo::FLIGHT_NUMBER),
+        view_model,
+        inbound: None,
+        position: None,
+        latency: None,
+        legs: Vec::new(),
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Outcome of one refresh, sent from the refresh task to the UI
+enum RefreshEvent {
+    Updated(Box<FlightUpdate>),
+    /// FlightAware answered but has no flight matching the target
+    NotFound,
+    Failed(FetchErrorKind),
+}
 
-    #[test]
-    fn test_config_from_options_with_both_values() {
-        let result = Config::from_options(
-            Some("AA100".to_string()),
-            Some("test-api-key".to_string()),
-            5,
-            30,
-        );
+/// How often to poll, speeding up once the flight is close to landing
+#[derive(Debug, Clone, Copy)]
+struct RefreshSchedule {
+    interval: std::time::Duration,
+    near_landing_interval: std::time::Duration,
+    near_landing_trigger: AlertTrigger,
+    /// Randomize each wait so instances sharing an API key don't all poll at the same moment
+    jitter: bool,
+}
 
-        assert!(result.is_ok());
-        let config = result.unwrap();
-        assert_eq!(config.flight_number, "AA100");
-        assert_eq!(config.flight_aware_api_key, "test-api-key");
-        assert_eq!(config.refresh_interval, 5);
-        assert_eq!(config.alert_threshold_minutes, 30);
+/// Largest share of the interval the jitter adds or removes
+const REFRESH_JITTER_FRACTION: f64 = 0.2;
+
+impl RefreshSchedule {
+    fn from_config(config: &Config) -> Self {
+        RefreshSchedule {
+            interval: std::time::Duration::from_secs(config.refresh_interval),
+            near_landing_interval: std::time::Duration::from_secs(
+                config.near_landing_refresh_interval,
+            ),
+            near_landing_trigger: config.alert_trigger,
+            jitter: config.refresh_jitter,
+        }
     }
 
-    #[test]
-    fn test_config_from_options_missing_flight_number() {
-        let result = Config::from_options(None, Some("test-api-key".to_string()), 5, 30);
-
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            ConfigurationError::MissingFlightNumber => (),
-            _ => panic!("Expected MissingFlightNumber error"),
+    /// The delay randomly stretched or shrunk by up to 20% when jitter is enabled
+    fn jittered(&self, delay: std::time::Duration) -> std::time::Duration {
+        if !self.jitter {
+            return delay;
         }
-    }
 
-    #[test]
-    fn test_config_from_options_missing_api_key() {
-        let result = Config::from_options(Some("AA100".to_string()), None, 5, 30);
+        let factor =
+            rand::random_range(1.0 - REFRESH_JITTER_FRACTION..=1.0 + REFRESH_JITTER_FRACTION);
+        delay.mul_f64(factor)
+    }
 
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            ConfigurationError::MissingApiKey => (),
-            _ => panic!("Expected MissingApiKey error"),
+    /// Delay before the next fetch, based on the latest view model
+    fn interval_for(&self, view_model: &FlightStatusViewModel) -> std::time::Duration {
+        if view_model.is_alert_triggered(self.near_landing_trigger) {
+            self.near_landing_interval
+        } else {
+            self.interval
         }
     }
+}
 
-    #[test]
-    fn test_config_from_options_missing_both() {
-        let result = Config::from_options(None, None, 5, 30);
+/// How long a gate change stays on the banner unless a key press acknowledges it first
+const GATE_CHANGE_BANNER_DURATION: std::time::Duration = std::time::Duration::from_secs(10);
 
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            ConfigurationError::MissingFlightNumber => (),
-            _ => panic!("Expected MissingFlightNumber error"),
+/// Shown once no update has arrived for longer than --stale-after-secs
+const STALE_BANNER: &str = "Data may be stale — no update received from FlightAware recently";
+
+/// Whether data last updated `since_update` ago should be flagged as stale
+fn is_stale(since_update: std::time::Duration, stale_after: std::time::Duration) -> bool {
+    since_update > stale_after
+}
+
+/// The one warning the banner row has room for, most pressing first: a gate change and a failed
+/// alert command wait on a key press, and stale data outranks the quota warning since what's on
+/// screen can no longer be trusted
+fn banner_text<'a>(
+    gate_change: Option<&'a str>,
+    alert_command_error: Option<&'a str>,
+    quota_banner: Option<&'a str>,
+    stale: bool,
+) -> Option<&'a str> {
+    gate_change
+        .or(alert_command_error)
+        .or(stale.then_some(STALE_BANNER))
+        .or(quota_banner)
+}
+
+/// Record every milestone in `milestones` that `progress` has reached, returning true if any of
+/// them hadn't been reached before so the bell rings once per milestone
+fn cross_milestones(milestones: &[u8], fired: &mut HashSet<u8>, progress: f64) -> bool {
+    let mut crossed = false;
+    for &milestone in milestones {
+        if progress >= f64::from(milestone) && fired.insert(milestone) {
+            crossed = true;
         }
     }
+    crossed
+}
 
-    #[test]
-    fn test_configuration_error_display_flight_number() {
-        let error = ConfigurationError::MissingFlightNumber;
-        let message = format!("{}", error);
-        assert!(message.contains("Flight number is required"));
-        assert!(message.contains("--flight-number"));
-        assert!(message.contains("FLIGHT_NUMBER"));
-    }
+/// Tracks the landing alert so its bell rings and --alert-command runs as it comes on
+#[derive(Debug, Default)]
+struct LandingAlert {
+    /// Whether the alert was on at the last check
+    triggered: bool,
+    /// Whether --alert-command has run; it runs at most once per session
+    command_run: bool,
+}
 
-    #[test]
-    fn test_configuration_error_display_api_key() {
-        let error = ConfigurationError::MissingApiKey;
-        let message = format!("{}", error);
-        assert!(message.contains("FlightAware API key is required"));
-        assert!(message.contains("--api-key"));
-        assert!(message.contains("FLIGHTAWARE_API_KEY"));
+impl LandingAlert {
+    /// Record whether the alert is on, returning true when it has just come on
+    fn update(&mut self, is_alert: bool) -> bool {
+        let fired = is_alert && !self.triggered;
+        self.triggered = is_alert;
+        fired
     }
 
-    #[test]
-    fn test_select_relevant_flight_empty() {
-        let flights = vec![];
-        let result = select_relevant_flight(&flights);
-        assert!(result.is_none());
+    /// Start `command` the first time the alert fires, or return None when there is no command
+    /// or it has already run
+    fn run_command(
+        &mut self,
+        command: Option<&str>,
+    ) -> Option<std::io::Result<tokio::process::Child>> {
+        let command = command.filter(|_| !self.command_run)?;
+        self.command_run = true;
+        Some(spawn_alert_command(command))
     }
+}
 
-    #[test]
-    fn test_select_relevant_flight_picks_closest_to_target() {
-        use chrono::{TimeZone, Utc};
+/// Start `command` through the shell without waiting for it
+/// Its output is discarded so it can't draw over the TUI, and the child is reaped in the
+/// background whatever its exit status
+fn spawn_alert_command(command: &str) -> std::io::Result<tokio::process::Child> {
+    #[cfg(windows)]
+    let mut shell = tokio::process::Command::new("cmd");
+    #[cfg(windows)]
+    shell.arg("/C");
+    #[cfg(not(windows))]
+    let mut shell = tokio::process::Command::new("sh");
+    #[cfg(not(windows))]
+    shell.arg("-c");
+
+    shell
+        .arg(command)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+}
 
-        // Create test JSON with 3 flights with different arrival times
-        // We're testing at time 2025-11-16T13:24:30Z (current time)
-        // Target time would be 2025-11-16T11:24:30Z (2 hours ago)
-        let json_data = r#"{
-            "flights": [
-                {
-                    "ident": "AA100-OLD",
-                    "ident_icao": "AAL100",
-                    "ident_iata": "AA100",
-                    "fa_flight_id": "AAL100-1234-old",
-                    "operator": "AAL",
-                    "operator_icao": "AAL",
-                    "operator_iata": "AA",
-                    "flight_number": "100",
-                    "registration": "N12345",
-                    "atc_ident": null,
-                    "inbound_fa_flight_id": null,
-                    "codeshares": [],
-                    "codeshares_iata": [],
-                    "blocked": false,
-                    "diverted": false,
-                    "cancelled": false,
-                    "position_only": false,
-                    "origin": null,
-                    "destination": null,
-                    "departure_delay": 0,
-                    "arrival_delay": 0,
-                    "filed_ete": null,
-                    "scheduled_out": null,
-                    "estimated_out": null,
-                    "actual_out": null,
-                    "scheduled_off": "2025-11-16T07:00:00Z",
-                    "estimated_off": "2025-11-16T07:00:00Z",
-                    "actual_off": "2025-11-16T07:05:00Z",
-                    "scheduled_on": "2025-11-16T10:00:00Z",
-                    "estimated_on": "2025-11-16T10:00:00Z",
-                    "actual_on": "2025-11-16T10:10:00Z",
-                    "scheduled_in": null,
-                    "estimated_in": null,
-                    "actual_in": null,
-                    "progress_percent": 100,
-                    "status": "Landed",
-                    "aircraft_type": "B738",
-                    "route_distance": null,
-                    "filed_airspeed": null,
-                    "filed_altitude": null,
-                    "route": null,
-                    "baggage_claim": null,
-                    "seats_cabin_business": null,
-                    "seats_cabin_coach": null,
-                    "seats_cabin_first": null,
-                    "gate_origin": null,
-                    "gate_destination": null,
-                    "terminal_origin": null,
-                    "terminal_destination": null,
-                    "type": "Airline",
-                    "actual_runway_off": null,
-                    "actual_runway_on": null,
-                    "foresight_predictions_available": false
-                },
-                {
-                    "ident": "AA100-CURRENT",
-                    "ident_icao": "AAL100",
-                    "ident_iata": "AA100",
-                    "fa_flight_id": "AAL100-1234-current",
-                    "operator": "AAL",
-                    "operator_icao": "AAL",
-                    "operator_iata": "AA",
-                    "flight_number": "100",
-                    "registration": "N12346",
-                    "atc_ident": null,
-                    "inbound_fa_flight_id": null,
-                    "codeshares": [],
-                    "codeshares_iata": [],
-                    "blocked": false,
-                    "diverted": false,
-                    "cancelled": false,
-                    "position_only": false,
-                    "origin": null,
-                    "destination": null,
-                    "departure_delay": 0,
-                    "arrival_delay": 0,
-                    "filed_ete": null,
-                    "scheduled_out": null,
-                    "estimated_out": null,
-                    "actual_out": null,
-                    "scheduled_off": "2025-11-16T10:00:00Z",
-                    "estimated_off": "2025-11-16T10:00:00Z",
-                    "actual_off": "2025-11-16T10:05:00Z",
-                    "scheduled_on": "2025-11-16T11:30:00Z",
-                    "estimated_on": "2025-11-16T11:30:00Z",
-                    "actual_on": null,
-                    "scheduled_in": null,
-                    "estimated_in": null,
-                    "actual_in": null,
-                    "progress_percent": 45,
-                    "status": "En Route",
-                    "aircraft_type": "B738",
-                    "route_distance": null,
-                    "filed_airspeed": null,
-                    "filed_altitude": null,
-                    "route": null,
-                    "baggage_claim": null,
-                    "seats_cabin_business": null,
-                    "seats_cabin_coach": null,
-                    "seats_cabin_first": null,
-                    "gate_origin": null,
-                    "gate_destination": null,
-                    "terminal_origin": null,
-                    "terminal_destination": null,
-                    "type": "Airline",
-                    "actual_runway_off": null,
-                    "actual_runway_on": null,
-                    "foresight_predictions_available": false
-                },
-                {
-                    "ident": "AA100-FUTURE",
-                    "ident_icao": "AAL100",
-                    "ident_iata": "AA100",
-                    "fa_flight_id": "AAL100-1234-future",
-                    "operator": "AAL",
-                    "operator_icao": "AAL",
-                    "operator_iata": "AA",
-                    "flight_number": "100",
-                    "registration": "N12347",
-                    "atc_ident": null,
-                    "inbound_fa_flight_id": null,
-                    "codeshares": [],
-                    "codeshares_iata": [],
-                    "blocked": false,
-                    "diverted": false,
-                    "cancelled": false,
-                    "position_only": false,
-                    "origin": null,
-                    "destination": null,
-                    "departure_delay": 0,
-                    "arrival_delay": 0,
-                    "filed_ete": null,
-                    "scheduled_out": null,
-                    "estimated_out": null,
-                    "actual_out": null,
-                    "scheduled_off": "2025-11-16T14:00:00Z",
-                    "estimated_off": "2025-11-16T14:00:00Z",
-                    "actual_off": null,
-                    "scheduled_on": "2025-11-16T16:00:00Z",
-                    "estimated_on": "2025-11-16T16:00:00Z",
-                    "actual_on": null,
-                    "scheduled_in": null,
-                    "estimated_in": null,
-                    "actual_in": null,
-                    "progress_percent": 0,
-                    "status": "Scheduled",
-                    "aircraft_type": "B738",
-                    "route_distance": null,
-                    "filed_airspeed": null,
-                    "filed_altitude": null,
-                    "route": null,
-                    "baggage_claim": null,
-                    "seats_cabin_business": null,
-                    "seats_cabin_coach": null,
-                    "seats_cabin_first": null,
-                    "gate_origin": null,
-                    "gate_destination": null,
-                    "terminal_origin": null,
-                    "terminal_destination": null,
-                    "type": "Airline",
-                    "actual_runway_off": null,
-                    "actual_runway_on": null,
-                    "foresight_predictions_available": false
-                }
-            ],
-            "links": null,
-            "num_pages": 1
-        }"#;
+/// How long the status block stays inverted after the status changes
+const STATUS_FLASH_DURATION: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// How often an otherwise unchanged frame is redrawn, for the clock-derived texts such as the
+/// update age, whose finest unit is a second
+const IDLE_REDRAW_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Whether to draw a frame `since_draw` after the last one
+/// A change in the data or view (`dirty`) or a running animation is drawn as soon as the frame
+/// rate cap allows; otherwise only the once-a-second clock texts can move
+fn should_redraw(
+    dirty: bool,
+    animating: bool,
+    since_draw: std::time::Duration,
+    frame_interval: std::time::Duration,
+) -> bool {
+    since_draw >= frame_interval && (dirty || animating || since_draw >= IDLE_REDRAW_INTERVAL)
+}
 
-        let response: flightaware::types::GetFlightResponse =
-            serde_json::from_str(json_data).expect("Failed to parse test JSON");
+/// Whether the status block should still be flashing for a status change at `status_changed_at`
+fn is_status_flashing(
+    status_changed_at: Option<std::time::Instant>,
+    now: std::time::Instant,
+) -> bool {
+    status_changed_at
+        .is_some_and(|changed_at| now.saturating_duration_since(changed_at) < STATUS_FLASH_DURATION)
+}
 
-        // At current time 2025-11-16T13:24:30Z, target is 11:24:30Z
-        // Flight 1 (OLD):     Arrives 10:00:00 - Distance from target: 1h 24m 30s
-        // Flight 2 (CURRENT): Arrives 11:30:00 - Distance from target: 5m 30s  ← CLOSEST
-        // Flight 3 (FUTURE):  Arrives 16:00:00 - Distance from target: 4h 35m 30s
+/// What a refresh task fetches on each cycle
+#[derive(Debug, Clone, Default)]
+struct RefreshTarget {
+    flight_number: String,
+    select_strategy: SelectionStrategy,
+    /// Airport whose legs win ties when picking among several
+    home_airport: Option<String>,
+    /// Only consider legs flown under this ATC callsign
+    callsign: Option<String>,
+    departure_reference: flight_status::DepartureReference,
+    arrival_definition: flight_status::ArrivalDefinition,
+    /// Once a leg has been selected the task sticks to its fa_flight_id for the rest of the session
+    locked_flight_id: Option<String>,
+    /// Also fetch the aircraft's previous leg, to predict knock-on delays
+    inbound: bool,
+    /// Also fetch the aircraft's latest position, for --gpx
+    position: bool,
+}
 
-        let selected = select_relevant_flight(&response.flights);
+/// Run one refresh of `target`: the flight itself and, once they're known, the inbound leg
+/// with `inbound_flight_id` and the locked leg's position, all requested concurrently
+/// Only the flight is essential; an auxiliary fetch that fails is left out of the update
+/// Returns `Ok(None)` when no leg matches the flight number
+async fn fetch_refresh(
+    client: &Client,
+    target: &RefreshTarget,
+    inbound_flight_id: Option<&str>,
+) -> Result<Option<FlightUpdate>, FetchErrorKind> {
+    let flight = async {
+        let started = std::time::Instant::now();
+        let response = client
+            .get_flight(&target.flight_number, None, None, None, None, None)
+            .await;
+        (response, started.elapsed())
+    };
+    let inbound = async {
+        let inbound_flight_id = inbound_flight_id.filter(|_| target.inbound)?;
+        let response = client
+            .get_flight(inbound_flight_id, None, None, None, None, None)
+            .await
+            .ok()?;
+        select_sticky_flight(
+            &response.flights,
+            Some(inbound_flight_id),
+            SelectionStrategy::NextUpcoming,
+            None,
+            chrono::Utc::now(),
+        )
+        .map(FlightStatusViewModel::from)
+    };
+    let position = async {
+        let flight_id = target
+            .locked_flight_id
+            .as_deref()
+            .filter(|_| target.position)?;
+        let response = client.get_flight_position(flight_id).await.ok()?;
+        response
+            .last_position
+            .as_ref()
+            .map(|position| gpx::TrackPoint {
+                latitude: position.latitude,
+                longitude: position.longitude,
+                // Reported in hundreds of feet
+                altitude_feet: position.altitude * 100,
+                timestamp: position.timestamp,
+            })
+    };
 
-        assert!(selected.is_some());
-        let flight = selected.unwrap();
+    let ((flight, latency), inbound, position) = tokio::join!(flight, inbound, position);
+    let response = flight.map_err(|error| classify_fetch_error(&error))?;
+    let flights = dedupe_flights(&flights_with_callsign(
+        &response.flights,
+        target.callsign.as_deref(),
+    ));
+    let Some(flight) = select_sticky_flight(
+        &flights,
+        target.locked_flight_id.as_deref(),
+        target.select_strategy,
+        target.home_airport.as_deref(),
+        chrono::Utc::now(),
+    ) else {
+        return Ok(None);
+    };
 
-        // Should select AA100-CURRENT as it's closest to target time (11:24:30)
-        assert_eq!(flight.ident, "AA100-CURRENT");
-        assert_eq!(flight.fa_flight_id, "AAL100-1234-current");
+    let mut update = FlightUpdate::new(
+        flight,
+        target.departure_reference,
+        target.arrival_definition,
+    )
+    .with_legs(
+        &flights,
+        flight,
+        target.departure_reference,
+        target.arrival_definition,
+    );
+    update.inbound = inbound;
+    update.latency = Some(latency);
+    // A position only belongs to the update if it was fetched for the same leg
+    if target.locked_flight_id.as_deref() == Some(flight.fa_flight_id.as_str()) {
+        update.position = position;
     }
+    Ok(Some(update))
+}
 
-    #[test]
-    fn test_select_relevant_flight_fallback_to_first_when_no_estimated_arrival() {
-        let json_data = r#"{
-            "flights": [
-                {
-                    "ident": "AA100-FIRST",
-                    "ident_icao": "AAL100",
-                    "ident_iata": "AA100",
-                    "fa_flight_id": "AAL100-first",
-                    "operator": "AAL",
-                    "operator_icao": "AAL",
-                    "operator_iata": "AA",
-                    "flight_number": "100",
-                    "registration": "N12345",
-                    "atc_ident": null,
-                    "inbound_fa_flight_id": null,
-                    "codeshares": [],
-                    "codeshares_iata": [],
-                    "blocked": false,
-                    "diverted": false,
-                    "cancelled": true,
-                    "position_only": false,
-                    "origin": null,
-                    "destination": null,
-                    "departure_delay": 0,
-                    "arrival_delay": 0,
-                    "filed_ete": null,
-                    "scheduled_out": null,
-                    "estimated_out": null,
-                    "actual_out": null,
-                    "scheduled_off": "2025-11-16T10:00:00Z",
-                    "estimated_off": null,
-                    "actual_off": null,
-                    "scheduled_on": "2025-11-16T14:00:00Z",
-                    "estimated_on": null,
-                    "actual_on": null,
-                    "scheduled_in": null,
-                    "estimated_in": null,
-                    "actual_in": null,
-                    "progress_percent": 0,
-                    "status": "Cancelled",
-                    "aircraft_type": null,
-                    "route_distance": null,
-                    "filed_airspeed": null,
-                    "filed_altitude": null,
-                    "route": null,
-                    "baggage_claim": null,
-                    "seats_cabin_business": null,
-                    "seats_cabin_coach": null,
-                    "seats_cabin_first": null,
-                    "gate_origin": null,
-                    "gate_destination": null,
-                    "terminal_origin": null,
-                    "terminal_destination": null,
-                    "type": "Airline",
-                    "actual_runway_off": null,
-                    "actual_runway_on": null,
-                    "foresight_predictions_available": false
+/// The inbound leg worth fetching alongside `view_model`: only until the flight departs, as
+/// after that a late inbound aircraft can no longer hold it up
+fn inbound_flight_id_to_fetch(view_model: &FlightStatusViewModel) -> Option<String> {
+    view_model
+        .inbound_fa_flight_id
+        .clone()
+        .filter(|_| view_model.actual_departure_time().is_none())
+}
+
+/// Spawn a background task that polls the API and sends each new flight (or failure) over a channel
+/// When `initial_view_model` is given the first fetch waits for the schedule instead of
+/// running immediately, since the caller already has that data; `refresh_now` skips the wait
+/// Polling stops, closing the channel, once the flight is complete
+fn spawn_refresh_task(
+    client: Client,
+    mut target: RefreshTarget,
+    schedule: RefreshSchedule,
+    initial_view_model: Option<&FlightStatusViewModel>,
+    refresh_now: Arc<tokio::sync::Notify>,
+) -> tokio::sync::mpsc::Receiver<RefreshEvent> {
+    let (tx, rx) = tokio::sync::mpsc::channel::<RefreshEvent>(10);
+    let mut delay = initial_view_model
+        .map(|view_model| schedule.interval_for(view_model))
+        .unwrap_or_default();
+    // Named by the latest update, so the inbound leg is fetched from the next cycle on
+    let mut inbound_flight_id = initial_view_model.and_then(inbound_flight_id_to_fetch);
+    let initial_view_model_complete =
+        initial_view_model.is_some_and(FlightStatusViewModel::is_complete);
+
+    tokio::spawn(async move {
+        if initial_view_model_complete {
+            return;
+        }
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(schedule.jittered(delay)) => {}
+                _ = refresh_now.notified() => {}
+            }
+            if delay.is_zero() {
+                // Don't spin on failures after an immediate first fetch
+                delay = schedule.interval;
+            }
+
+            let mut complete = false;
+            let event = match fetch_refresh(&client, &target, inbound_flight_id.as_deref()).await {
+                Ok(Some(update)) => {
+                    complete = update.view_model.is_complete();
+                    target.locked_flight_id = Some(update.fa_flight_id.clone());
+                    inbound_flight_id = inbound_flight_id_to_fetch(&update.view_model);
+                    delay = schedule.interval_for(&update.view_model);
+                    RefreshEvent::Updated(Box::new(update))
                 }
-            ],
-            "links": null,
-            "num_pages": 1
-        }"#;
+                Ok(None) => RefreshEvent::NotFound,
+                Err(kind) => RefreshEvent::Failed(kind),
+            };
 
-        let response: flightaware::types::GetFlightResponse =
-            serde_json::from_str(json_data).expect("Failed to parse test JSON");
+            if tx.send(event).await.is_err() || complete {
+                // Channel closed or nothing left to poll for, exit task
+                break;
+            }
+        }
+    });
+
+    rx
+}
+
+/// How often --demo advances its scripted flight
+const DEMO_UPDATE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
 
-        let selected = select_relevant_flight(&response.flights);
+/// Stand in for the refresh task under --demo, sending the scripted flight straight away and
+/// then every second until it reaches the gate
+fn spawn_demo_task() -> tokio::sync::mpsc::Receiver<RefreshEvent> {
+    let (tx, rx) = tokio::sync::mpsc::channel::<RefreshEvent>(10);
+    let started = std::time::Instant::now();
 
-        assert!(selected.is_some());
-        // Should fall back to first flight when none have estimated_on
-        assert_eq!(selected.unwrap().ident, "AA100-FIRST");
+    tokio::spawn(async move {
+        loop {
+            let update = demo_update(started.elapsed());
+            let complete = update.view_model.is_complete();
+            if tx
+                .send(RefreshEvent::Updated(Box::new(update)))
+                .await
+                .is_err()
+                || complete
+            {
+                break;
+            }
+            tokio::time::sleep(DEMO_UPDATE_INTERVAL).await;
+        }
+    });
+
+    rx
+}
+
+/// Add a polled position to the track unless it's the same report as the last one, which
+/// happens when the aircraft hasn't sent a new position since the previous poll
+fn record_track_point(track: &mut Vec<gpx::TrackPoint>, point: gpx::TrackPoint) {
+    if track
+        .last()
+        .is_none_or(|last| last.timestamp < point.timestamp)
+    {
+        track.push(point);
+    }
+}
+
+type Terminal = ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>;
+
+/// Write a file the user asked for, exiting with the reason when it can't be written
+fn write_output_or_exit(path: &Path, contents: impl AsRef<[u8]>) {
+    if let Err(error) = paths::write_file(path, contents) {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    }
+}
+
+/// Shown when the TUI can't start because there is no terminal to draw on
+const NOT_A_TERMINAL_MESSAGE: &str =
+    "flui requires an interactive terminal; use --once or --snapshot for non-interactive output";
+
+/// Exit with a clear message instead of failing inside the TUI setup when stdin or stdout
+/// isn't a terminal (piped output, some CI runners)
+/// Stdin may be a pipe when it carried the flight number, since key presses are then read
+/// from the controlling terminal instead
+fn require_interactive_terminal(config: &Config) {
+    use std::io::IsTerminal;
+
+    let stdin_ok = config.flight_number_from_stdin || std::io::stdin().is_terminal();
+    if !(stdin_ok && std::io::stdout().is_terminal()) {
+        eprintln!("{}", NOT_A_TERMINAL_MESSAGE);
+        std::process::exit(1);
+    }
+}
+
+fn setup_terminal() -> std::io::Result<Terminal> {
+    crossterm::terminal::enable_raw_mode().map_err(|error| {
+        std::io::Error::new(
+            error.kind(),
+            format!("{} ({})", NOT_A_TERMINAL_MESSAGE, error),
+        )
+    })?;
+    let mut stdout = std::io::stdout();
+    crossterm::execute!(stdout, crossterm::terminal::EnterAlternateScreen)?;
+
+    let backend = ratatui::backend::CrosstermBackend::new(stdout);
+    ratatui::Terminal::new(backend)
+}
+
+/// Draw the flight status once below the cursor, without the alternate screen or the event loop,
+/// leaving the cursor on the line after it so the layout stays in the scrollback
+fn print_once(
+    view_model: &FlightStatusViewModel,
+    alert_mode: bool,
+    options: &ui::RenderOptions,
+) -> std::io::Result<()> {
+    use std::io::IsTerminal;
+
+    let height = ui::flight_status_height(view_model, None, options);
+
+    if !std::io::stdout().is_terminal() {
+        println!(
+            "{}",
+            ui::render_to_string(view_model, alert_mode, None, options, 80, height)
+        );
+        return Ok(());
+    }
+
+    let backend = ratatui::backend::CrosstermBackend::new(std::io::stdout());
+    let mut terminal = ratatui::Terminal::with_options(
+        backend,
+        ratatui::TerminalOptions {
+            viewport: ratatui::Viewport::Inline(height),
+        },
+    )?;
+    let bottom = terminal
+        .draw(|frame| {
+            ui::render_flight_status(frame, view_model, alert_mode, None, options);
+            ui::finish_frame(frame.buffer_mut(), options);
+        })?
+        .area
+        .bottom();
+
+    terminal.set_cursor_position((0, bottom.saturating_sub(1)))?;
+    println!();
+    Ok(())
+}
+
+/// Remember the display toggles for the next run; failing to is only worth a warning
+fn save_ui_state(config: &Config, render_options: &ui::RenderOptions) {
+    let Some(path) = ui_state::path(config.output_dir.as_deref()) else {
+        return;
+    };
+    if let Err(error) = ui_state::save(&path, &ui_state::UiState::from_options(render_options)) {
+        eprintln!("Warning: display settings weren't saved. {}", error);
+    }
+}
+
+fn restore_terminal(terminal: &mut Terminal) -> std::io::Result<()> {
+    crossterm::terminal::disable_raw_mode()?;
+    crossterm::execute!(
+        terminal.backend_mut(),
+        crossterm::terminal::LeaveAlternateScreen
+    )
+}
+
+/// Listen for SIGINT/SIGTERM so the terminal is restored when stopped by a process manager
+fn spawn_shutdown_listener() -> tokio::sync::oneshot::Receiver<()> {
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        let _ = shutdown_tx.send(());
+    });
+    shutdown_rx
+}
+
+/// Wait briefly for a key press
+fn read_key() -> std::io::Result<Option<KeyEvent>> {
+    use crossterm::event::{self, Event};
+
+    if event::poll(std::time::Duration::from_millis(100))?
+        && let Event::Key(key) = event::read()?
+    {
+        return Ok(Some(key));
+    }
+
+    Ok(None)
+}
+
+/// Display state changed from the keyboard while the TUI runs
+struct ViewState {
+    render_options: ui::RenderOptions,
+    show_help: bool,
+    show_debug: bool,
+    debug_scroll: u16,
+    /// FlightAware page of the tracked flight, for the open-in-browser key
+    flight_url: Option<String>,
+}
+
+impl ViewState {
+    fn new(render_options: ui::RenderOptions, show_debug: bool) -> Self {
+        ViewState {
+            render_options,
+            show_help: false,
+            show_debug,
+            debug_scroll: 0,
+            flight_url: None,
+        }
+    }
+}
+
+/// Dispatch a key press through the key map; returns true when the user asked to quit
+/// Raw mode delivers Ctrl+C as a key event rather than SIGINT, which is why it's bound to quit by default
+fn handle_key(
+    key: &KeyEvent,
+    key_map: &KeyMap,
+    view: &mut ViewState,
+    refresh_now: &tokio::sync::Notify,
+) -> bool {
+    match key_map.action_for(key) {
+        Some(Action::Quit) => return true,
+        Some(Action::Refresh) => refresh_now.notify_one(),
+        Some(Action::ToggleHelp) => view.show_help = !view.show_help,
+        Some(Action::CycleIdent) => {
+            view.render_options.ident_format = view.render_options.ident_format.next();
+        }
+        Some(Action::ToggleDebug) => view.show_debug = !view.show_debug,
+        Some(Action::ToggleMinimal) => view.render_options.minimal = !view.render_options.minimal,
+        Some(Action::ToggleTimetable) => {
+            view.render_options.timetable = !view.render_options.timetable;
+        }
+        Some(Action::ToggleBlockTimes) => {
+            view.render_options.block_times = !view.render_options.block_times;
+        }
+        Some(Action::ToggleBoardDensity) => {
+            view.render_options.board_density = view.render_options.board_density.toggled();
+        }
+        Some(Action::OpenInBrowser) => {
+            if let Some(url) = &view.flight_url {
+                open_in_browser(url);
+            }
+        }
+        Some(Action::ScrollUp) => view.debug_scroll = view.debug_scroll.saturating_sub(1),
+        Some(Action::ScrollDown) => view.debug_scroll = view.debug_scroll.saturating_add(1),
+        // Only the --near board has flights to pick from
+        Some(Action::Select) | None => {}
+    }
+
+    false
+}
+
+/// Open `url` in the default browser, detached so its output can't reach the TUI
+/// Failures are ignored, as the URL stays on screen to copy by hand
+#[cfg(feature = "open")]
+fn open_in_browser(url: &str) {
+    let _ = open::that_detached(url);
+}
+
+/// Without the `open` feature the URL on screen is all there is
+#[cfg(not(feature = "open"))]
+fn open_in_browser(_url: &str) {}
+
+/// Fetch the flight to start tracking, along with its FlightAware id
+/// On failure, returns a message telling apart a flight number with no flights from a request
+/// that never reached FlightAware
+async fn fetch_initial_flight(
+    client: &Client,
+    config: &Config,
+) -> Result<(FlightUpdate, String), String> {
+    if config.demo {
+        let update = demo_update(std::time::Duration::ZERO);
+        let flight_id = update.fa_flight_id.clone();
+        return Ok((update, flight_id));
+    }
+
+    fetch_flight(
+        client,
+        &config.flight_number,
+        config.select_strategy,
+        config.home_airport.as_deref(),
+        config.callsign.as_deref(),
+        config.departure_reference,
+        config.arrival_definition,
+    )
+    .await?
+    .ok_or_else(|| no_flights_message(&config.flight_number))
+}
+
+/// Shown when FlightAware has no flight for the flight number the user asked for
+fn no_flights_message(flight_number: &str) -> String {
+    format!(
+        "No flights found for {} — check the flight number",
+        flight_number
+    )
+}
+
+/// Fetch a flight number and pick a leg, returning `None` when FlightAware has no matching flight
+async fn fetch_flight(
+    client: &Client,
+    flight_number: &str,
+    select_strategy: SelectionStrategy,
+    home_airport: Option<&str>,
+    callsign: Option<&str>,
+    departure_reference: flight_status::DepartureReference,
+    arrival_definition: flight_status::ArrivalDefinition,
+) -> Result<Option<(FlightUpdate, String)>, String> {
+    let response = client
+        .get_flight(flight_number, None, None, None, None, None)
+        .await
+        .map_err(|error| fetch_error_message(&error))?;
+
+    let flights = dedupe_flights(&flights_with_callsign(&response.flights, callsign));
+    Ok(
+        select_relevant_flight(&flights, select_strategy, home_airport, chrono::Utc::now()).map(
+            |flight| {
+                (
+                    FlightUpdate::new(flight, departure_reference, arrival_definition).with_legs(
+                        &flights,
+                        flight,
+                        departure_reference,
+                        arrival_definition,
+                    ),
+                    flight.fa_flight_id.clone(),
+                )
+            },
+        ),
+    )
+}
+
+/// What to print when a request made before the TUI opens fails
+fn fetch_error_message<E: fmt::Debug>(error: &flightaware::Error<E>) -> String {
+    match classify_fetch_error(error) {
+        FetchErrorKind::QuotaExceeded => format!(
+            "Error fetching flight data: {}\n{}",
+            error,
+            fetch_error::QUOTA_BANNER
+        ),
+        FetchErrorKind::Unreachable => {
+            format!("{} ({})", fetch_error::UNREACHABLE_MESSAGE, error)
+        }
+        FetchErrorKind::Other => format!("Error fetching flight data: {}", error),
+    }
+}
+
+/// Search for the airborne flights within `area`, nearest first
+async fn search_nearby(
+    client: &Client,
+    area: &nearby::SearchArea,
+) -> Result<Vec<nearby::NearbyFlight>, String> {
+    let response = client
+        .get_flights_by_search(None, None, Some(&area.query()))
+        .await
+        .map_err(|error| fetch_error_message(&error))?;
+    Ok(nearby::nearest_flights(&response.flights, area))
+}
+
+/// Show two alternative flights side by side
+/// Both are fetched up front so a flight that doesn't exist is reported as not found rather
+/// than loading forever; the ones that were found keep refreshing like the board
+async fn run_compare(
+    client: Client,
+    config: &Config,
+    (first, second): &(String, String),
+    render_options: ui::RenderOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
+    require_interactive_terminal(config);
+
+    let (first_result, second_result) = tokio::join!(
+        fetch_flight(
+            &client,
+            first,
+            config.select_strategy,
+            config.home_airport.as_deref(),
+            None,
+            config.departure_reference,
+            config.arrival_definition,
+        ),
+        fetch_flight(
+            &client,
+            second,
+            config.select_strategy,
+            config.home_airport.as_deref(),
+            None,
+            config.departure_reference,
+            config.arrival_definition,
+        ),
+    );
+    let (first_result, second_result) = match (first_result, second_result) {
+        (Ok(first_result), Ok(second_result)) => (first_result, second_result),
+        (Err(message), _) | (_, Err(message)) => {
+            println!("{}", message);
+            return Ok(());
+        }
+    };
+
+    let targets = [(first, first_result), (second, second_result)]
+        .into_iter()
+        .map(|(flight_number, (update, flight_id))| {
+            let target = RefreshTarget {
+                flight_number: flight_number.clone(),
+                select_strategy: config.select_strategy,
+                home_airport: config.home_airport.clone(),
+                departure_reference: config.departure_reference,
+                arrival_definition: config.arrival_definition,
+                locked_flight_id: Some(flight_id),
+                ..Default::default()
+            };
+            (target, Some(update.view_model))
+        })
+        .collect();
+
+    run_flights(
+        client,
+        config,
+        targets,
+        false,
+        render_options,
+        |frame, flights, _, options| ui::render_compare(frame, flights, options),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Track every flight in `targets` and show them together on a board
+/// With `pick` the arrow keys move a highlight and Enter closes the board, returning the index
+/// of the highlighted flight
+async fn run_board(
+    client: Client,
+    config: &Config,
+    targets: Vec<RefreshTarget>,
+    pick: bool,
+    render_options: ui::RenderOptions,
+) -> Result<Option<usize>, Box<dyn std::error::Error>> {
+    require_interactive_terminal(config);
+
+    run_flights(
+        client,
+        config,
+        targets.into_iter().map(|target| (target, None)).collect(),
+        pick,
+        render_options,
+        ui::render_board,
+    )
+    .await
+}
+
+/// The event loop shared by the board and --compare: keep every flight in `targets` refreshing,
+/// starting from its view model when one was already fetched, and draw them with `render` until
+/// the user quits
+/// With `pick` the arrow keys move a highlight, passed on to `render`, and Enter ends the loop,
+/// returning the index of the highlighted flight
+async fn run_flights(
+    client: Client,
+    config: &Config,
+    targets: Vec<(RefreshTarget, Option<FlightStatusViewModel>)>,
+    pick: bool,
+    render_options: ui::RenderOptions,
+    render: impl Fn(
+        &mut ratatui::Frame,
+        &[(String, Option<FlightStatusViewModel>)],
+        Option<usize>,
+        &ui::RenderOptions,
+    ),
+) -> Result<Option<usize>, Box<dyn std::error::Error>> {
+    let refresh_now = Arc::new(tokio::sync::Notify::new());
+    let help_lines = config.key_map.help_lines();
+    // The debug panel follows a single flight, so it isn't offered here
+    let mut view = ViewState::new(render_options, false);
+    let mut quota = QuotaTracker::default();
+    let mut selected = 0;
+    let mut picked = None;
+    let mut flights: Vec<(String, Option<FlightStatusViewModel>)> = Vec::new();
+    let mut receivers = Vec::new();
+    for (target, view_model) in targets {
+        let flight_number = target.flight_number.clone();
+        receivers.push(spawn_refresh_task(
+            client.clone(),
+            target,
+            RefreshSchedule::from_config(config),
+            view_model.as_ref(),
+            refresh_now.clone(),
+        ));
+        flights.push((flight_number, view_model));
+    }
+
+    let mut shutdown_rx = spawn_shutdown_listener();
+    let mut terminal = setup_terminal()?;
+
+    loop {
+        terminal.draw(|frame| {
+            render(
+                frame,
+                &flights,
+                pick.then_some(selected),
+                &view.render_options,
+            );
+            if let Some(banner) = quota.banner() {
+                ui::render_banner(frame, banner);
+            }
+            if view.show_help {
+                ui::render_help(frame, &help_lines);
+            }
+            ui::finish_frame(frame.buffer_mut(), &view.render_options);
+        })?;
+
+        if let Some(key) = read_key()? {
+            match config.key_map.action_for(&key).filter(|_| pick) {
+                Some(Action::ScrollUp) => selected = selected.saturating_sub(1),
+                Some(Action::ScrollDown) => {
+                    selected = (selected + 1).min(flights.len().saturating_sub(1));
+                }
+                Some(Action::Select) => {
+                    picked = Some(selected);
+                    break;
+                }
+                _ => {
+                    if handle_key(&key, &config.key_map, &mut view, &refresh_now) {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if shutdown_rx.try_recv().is_ok() {
+            break;
+        }
+
+        for ((_, view_model), rx) in flights.iter_mut().zip(receivers.iter_mut()) {
+            match rx.try_recv() {
+                Ok(RefreshEvent::Updated(update)) => {
+                    quota.record_success();
+                    *view_model = Some(update.view_model);
+                }
+                Ok(RefreshEvent::Failed(kind)) => quota.record_error(kind),
+                Ok(RefreshEvent::NotFound) | Err(_) => {}
+            }
+        }
+    }
+
+    restore_terminal(&mut terminal)?;
+    save_ui_state(config, &view.render_options);
+
+    Ok(picked)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = match get_config() {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    };
+
+    // Start mock server if httpmock feature is enabled
+    #[cfg(feature = "httpmock")]
+    let (_mock_base_url, _mock_server) = {
+        let (base_url, server) = mock_server::start_mock_server(config.verbose);
+        (base_url.clone(), server)
+    };
+
+    // Use mock server URL if available, otherwise use production
+    #[cfg(feature = "httpmock")]
+    let base_url = Some(_mock_base_url.as_str());
+
+    #[cfg(not(feature = "httpmock"))]
+    let base_url: Option<&str> = None;
+
+    let http_client = create_authenticated_http_client(
+        &config.flight_aware_api_key,
+        HttpClientOptions::from_config(&config),
+    );
+    let client = create_flightaware_client(http_client, base_url);
+
+    let mut render_options = ui::RenderOptions {
+        delay_color_threshold_minutes: config.ui_delay_color_threshold,
+        ident_format: config.ident_format,
+        minimal: config.minimal,
+        max_path_width: config.max_path_width,
+        vertical_path: config.vertical_path,
+        timetable: config.timetable,
+        queried_ident: Some(config.flight_number.clone()),
+        ascii: config.ascii,
+        mono: config.mono,
+        translations: translations::Translations::for_language(config.language),
+        block_times: config.block_times,
+        dashboard: config.dashboard,
+        board_density: config.board_density,
+        flash_status: false,
+        frame_count: 0,
+        animate: config.animate,
+        redact: config.redact,
+        boarding_offset_minutes: config.boarding_offset_minutes,
+        eta_clock: config.eta_clock,
+        eta_timezone: config.eta_timezone,
+        home_airport: config.home_airport.clone(),
+        airline_names: config.airline_names,
+        // Set each frame from the panels drawn under the layout
+        below_status_rows: 0,
+    };
+
+    if let Some(compare_flights) = &config.compare_flights {
+        return run_compare(client, &config, compare_flights, render_options).await;
+    }
+
+    if !config.board_flights.is_empty() {
+        let targets = config
+            .board_flights
+            .iter()
+            .map(|flight_number| RefreshTarget {
+                flight_number: flight_number.clone(),
+                select_strategy: config.select_strategy,
+                home_airport: config.home_airport.clone(),
+                departure_reference: config.departure_reference,
+                arrival_definition: config.arrival_definition,
+                ..Default::default()
+            })
+            .collect();
+        run_board(client, &config, targets, false, render_options).await?;
+        return Ok(());
+    }
+
+    // --near lists the flights overhead on a board, each locked to the leg that was found,
+    // and goes on to track the one picked
+    let mut picked_flight_id = None;
+    if let Some(area) = config.near {
+        let nearby = match search_nearby(&client, &area).await {
+            Ok(nearby) => nearby,
+            Err(message) => {
+                println!("{}", message);
+                return Ok(());
+            }
+        };
+        if nearby.is_empty() {
+            println!(
+                "No flights found within {} nm of {}, {}",
+                area.radius_nm, area.center.latitude, area.center.longitude
+            );
+            return Ok(());
+        }
+
+        let targets = nearby
+            .iter()
+            .map(|flight| RefreshTarget {
+                flight_number: flight.ident.clone(),
+                select_strategy: config.select_strategy,
+                departure_reference: config.departure_reference,
+                arrival_definition: config.arrival_definition,
+                locked_flight_id: Some(flight.fa_flight_id.clone()),
+                ..Default::default()
+            })
+            .collect();
+        let picked = run_board(
+            client.clone(),
+            &config,
+            targets,
+            true,
+            render_options.clone(),
+        )
+        .await?;
+        let Some(flight) = picked.map(|index| &nearby[index]) else {
+            return Ok(());
+        };
+        config.flight_number = flight.ident.clone();
+        render_options.queried_ident = Some(flight.ident.clone());
+        picked_flight_id = Some(flight.fa_flight_id.clone());
+    }
+
+    // The headless modes need a flight to show, so they fetch it up front and give up on the
+    // first failure; the TUI opens straight away and gets its first flight from the refresh
+    // task like every later one
+    let headless = config.snapshot_path.is_some() || config.once || config.serve_port.is_some();
+    let (initial_update, initial_flight_id) = if headless {
+        match fetch_initial_flight(&client, &config).await {
+            Ok((update, flight_id)) => (Some(update), Some(flight_id)),
+            Err(message) => {
+                println!("{}", message);
+                return Ok(());
+            }
+        }
+    } else {
+        (None, picked_flight_id)
+    };
+
+    // Write a single headless frame and exit
+    if let (Some(path), Some(initial_update)) = (&config.snapshot_path, &initial_update) {
+        let is_alert = initial_update
+            .view_model
+            .is_alert_triggered(config.alert_trigger);
+        let snapshot = ui::render_to_string(
+            &initial_update.view_model,
+            is_alert,
+            None,
+            &render_options,
+            config.snapshot_width,
+            config.snapshot_height,
+        );
+        write_output_or_exit(path, snapshot);
+        return Ok(());
+    }
+
+    if config.once
+        && let Some(initial_update) = &initial_update
+    {
+        let is_alert = initial_update
+            .view_model
+            .is_alert_triggered(config.alert_trigger);
+        print_once(&initial_update.view_model, is_alert, &render_options)?;
+        return Ok(());
+    }
+
+    let refresh_now = Arc::new(tokio::sync::Notify::new());
+
+    // The onward leg is the next one to depart, regardless of how the main flight is chosen
+    let mut connection_rx = config.connecting_flight.clone().map(|connecting_flight| {
+        spawn_refresh_task(
+            client.clone(),
+            RefreshTarget {
+                flight_number: connecting_flight,
+                select_strategy: SelectionStrategy::NextUpcoming,
+                departure_reference: config.departure_reference,
+                arrival_definition: config.arrival_definition,
+                ..Default::default()
+            },
+            RefreshSchedule::from_config(&config),
+            None,
+            refresh_now.clone(),
+        )
+    });
+
+    // The inbound leg only feeds the TUI's advisory, so the JSON server doesn't fetch it
+    let mut rx = if config.demo {
+        spawn_demo_task()
+    } else {
+        spawn_refresh_task(
+            client.clone(),
+            RefreshTarget {
+                flight_number: config.flight_number.clone(),
+                select_strategy: config.select_strategy,
+                home_airport: config.home_airport.clone(),
+                callsign: config.callsign.clone(),
+                departure_reference: config.departure_reference,
+                arrival_definition: config.arrival_definition,
+                locked_flight_id: initial_flight_id,
+                inbound: config.serve_port.is_none(),
+                position: config.gpx_path.is_some(),
+            },
+            RefreshSchedule::from_config(&config),
+            initial_update.as_ref().map(|update| &update.view_model),
+            refresh_now.clone(),
+        )
+    };
+
+    #[cfg(feature = "serve")]
+    if let Some(port) = config.serve_port {
+        let initial_view_model = initial_update
+            .map(|update| update.view_model)
+            .unwrap_or_default();
+        let state = std::sync::Arc::new(tokio::sync::RwLock::new(initial_view_model));
+        let writer = state.clone();
+        tokio::spawn(async move {
+            while let Some(event) = rx.recv().await {
+                if let RefreshEvent::Updated(update) = event {
+                    *writer.write().await = update.view_model;
+                }
+            }
+        });
+        let idents = server::IdentOptions {
+            queried_ident: Some(config.flight_number.clone()),
+            ident_format: config.ident_format,
+        };
+        server::serve(port, state, idents, shutdown_signal()).await?;
+        return Ok(());
+    }
+
+    require_interactive_terminal(&config);
+    let mut shutdown_rx = spawn_shutdown_listener();
+    let mut terminal = setup_terminal()?;
+
+    // Current flight, missing until the first successful fetch
+    let mut current = initial_update;
+    // Why the latest fetch before the first flight failed; until one fails the splash is shown
+    let mut startup_error: Option<String> = None;
+    // Printed after the terminal is restored when --exit-on-error gives up on the first fetch
+    let mut exit_message = None;
+    let mut connecting_view_model: Option<FlightStatusViewModel> = None;
+    // The aircraft's previous leg, fetched with each refresh to predict knock-on delays before
+    // the API reports them; the last one found is kept if a later fetch fails
+    let mut inbound_view_model: Option<FlightStatusViewModel> = None;
+    // Positions recorded for --gpx
+    let mut track: Vec<gpx::TrackPoint> = Vec::new();
+    // Delays seen on each refresh, for --delay-history
+    let mut delay_history = delay_history::DelayHistory::default();
+    if let Some(current) = &current {
+        delay_history.record(&current.view_model, chrono::Utc::now());
+    }
+    // Set once the refresh task has stopped polling a completed flight
+    let mut polling_stopped = false;
+    let mut landing_alert = LandingAlert::default();
+    // Why --alert-command couldn't be started, until a key press acknowledges it
+    let mut alert_command_error: Option<String> = None;
+    // --bell-at milestones already rung, or already passed when the flight was first seen;
+    // missing until then
+    let mut fired_milestones: Option<HashSet<u8>> = None;
+    let help_lines = config.key_map.help_lines();
+    let mut view = ViewState::new(render_options, config.debug_panel);
+    view.flight_url = current
+        .as_ref()
+        .and_then(|update| update.view_model.flightaware_url());
+    let mut quota = QuotaTracker::default();
+    let mut last_update = std::time::Instant::now();
+    // When the tracked flight's status last changed, to flash the status block
+    let mut status_changed_at = None;
+    // The latest departure gate change and when it was spotted, until it's acknowledged
+    let mut gate_change: Option<(flight_status::GateChange, std::time::Instant)> = None;
+    let stale_after = std::time::Duration::from_secs(config.stale_after_secs);
+    let frame_interval = std::time::Duration::from_secs(1) / config.max_fps;
+    // Anything that changes what's on screen sets `dirty` so the next frame is drawn promptly
+    let mut dirty = true;
+    let mut last_draw = std::time::Instant::now();
+    let mut last_size = terminal.size()?;
+
+    // Event loop
+    loop {
+        // Check if we're approaching landing
+        let is_alert = current
+            .as_ref()
+            .is_some_and(|update| update.view_model.is_alert_triggered(config.alert_trigger));
+
+        // Trigger terminal bell/flash on first alert
+        if landing_alert.update(is_alert) {
+            // Ring the terminal bell
+            crossterm::execute!(terminal.backend_mut(), crossterm::style::Print("\x07"))?;
+            if let Some(Err(error)) = landing_alert.run_command(config.alert_command.as_deref()) {
+                alert_command_error = Some(format!("Alert command failed to start: {}", error));
+                dirty = true;
+            }
+        }
+
+        if let Some(update) = &current {
+            let progress = update.view_model.progress_percentage();
+            match &mut fired_milestones {
+                Some(fired) => {
+                    if cross_milestones(&config.bell_at, fired, progress) {
+                        crossterm::execute!(
+                            terminal.backend_mut(),
+                            crossterm::style::Print("\x07")
+                        )?;
+                    }
+                }
+                None => {
+                    // Don't ring for milestones the flight had passed before tracking started
+                    let mut fired = HashSet::new();
+                    cross_milestones(&config.bell_at, &mut fired, progress);
+                    fired_milestones = Some(fired);
+                }
+            }
+        }
+
+        let since_update = last_update.elapsed();
+        let stale = !polling_stopped && current.is_some() && is_stale(since_update, stale_after);
+        let flash_status = view.render_options.animate
+            && is_status_flashing(status_changed_at, std::time::Instant::now());
+        if flash_status != view.render_options.flash_status {
+            view.render_options.flash_status = flash_status;
+            dirty = true;
+        }
+        if gate_change
+            .as_ref()
+            .is_some_and(|(_, spotted_at)| spotted_at.elapsed() >= GATE_CHANGE_BANNER_DURATION)
+        {
+            gate_change = None;
+            dirty = true;
+        }
+        let gate_change_text = gate_change
+            .as_ref()
+            .map(|(change, _)| ui::gate_change_text(change, &view.render_options));
+        let size = terminal.size()?;
+        if size != last_size {
+            last_size = size;
+            dirty = true;
+        }
+        let animating = flash_status
+            || current.as_ref().is_some_and(|current| {
+                ui::is_ticker_scrolling(&current.view_model, size.width, &view.render_options)
+                    || ui::is_taxi_wiggling(&current.view_model, &view.render_options)
+            });
+
+        // Draw the UI, skipping frames that wouldn't change
+        if should_redraw(dirty, animating, last_draw.elapsed(), frame_interval) {
+            dirty = false;
+            last_draw = std::time::Instant::now();
+            view.render_options.frame_count = view.render_options.frame_count.wrapping_add(1);
+            view.render_options.below_status_rows = current.as_ref().map_or(0, |current| {
+                ui::below_status_rows(
+                    &current.legs,
+                    config.delay_history.then_some(&delay_history),
+                )
+            });
+            terminal.draw(|frame| {
+                // The raw JSON names the flight, so it stays closed while redacting
+                if let Some(current) = &current
+                    && view.show_debug
+                    && !view.render_options.redact
+                {
+                    ui::render_debug(frame, &current.raw_json, view.debug_scroll, current.latency);
+                } else if let Some(current) = &current {
+                    ui::render_flight_status(
+                        frame,
+                        &current.view_model,
+                        is_alert,
+                        connecting_view_model.as_ref(),
+                        &view.render_options,
+                    );
+                    ui::render_leg_timeline(
+                        frame,
+                        &current.legs,
+                        &current.view_model,
+                        connecting_view_model.as_ref(),
+                        &view.render_options,
+                    );
+                    if config.delay_history {
+                        ui::render_delay_history(
+                            frame,
+                            &delay_history,
+                            &current.view_model,
+                            connecting_view_model.as_ref(),
+                            &view.render_options,
+                            ui::leg_timeline_height(&current.legs),
+                        );
+                    }
+                    // A predicted knock-on delay is more pressing than which way the delay is going
+                    let advisory = inbound_view_model
+                        .as_ref()
+                        .and_then(|inbound| {
+                            connection::InboundDelay::predict(inbound, &current.view_model)
+                        })
+                        .map(|inbound_delay| inbound_delay.to_string())
+                        .or_else(|| {
+                            delay_history
+                                .trend()
+                                .filter(|_| config.delay_history)
+                                .map(|trend| {
+                                    view.render_options.translations.trend(trend).to_string()
+                                })
+                        });
+                    if let Some(advisory) = &advisory {
+                        ui::render_advisory(frame, advisory);
+                    }
+                    // The API latency is a diagnostic, so it's only shown with --debug
+                    let latency = current
+                        .latency
+                        .filter(|_| config.debug_panel && !polling_stopped);
+                    let footer_width = if polling_stopped {
+                        ui::render_complete_footer(frame)
+                    } else {
+                        ui::render_footer(frame, since_update, stale, latency)
+                    };
+                    // The link names the flight, so it's left out while redacting, and it gives
+                    // way to an advisory on the same row
+                    if let Some(url) = view
+                        .flight_url
+                        .as_deref()
+                        .filter(|_| !view.render_options.redact && advisory.is_none())
+                    {
+                        ui::render_url_footer(frame, url, footer_width);
+                    }
+                } else {
+                    let flight_number =
+                        ui::shown_ident(&config.flight_number, &view.render_options);
+                    match &startup_error {
+                        // Errors can quote the request URL, which contains the flight number
+                        Some(error) => ui::render_connecting(
+                            frame,
+                            flight_number,
+                            Some(&error.replace(&config.flight_number, flight_number)),
+                        ),
+                        None => ui::render_splash(frame, flight_number),
+                    }
+                }
+                if let Some(banner) = banner_text(
+                    gate_change_text.as_deref(),
+                    alert_command_error.as_deref(),
+                    quota.banner(),
+                    stale,
+                ) {
+                    ui::render_banner(frame, banner);
+                }
+                if view.show_help {
+                    ui::render_help(frame, &help_lines);
+                }
+                ui::finish_frame(frame.buffer_mut(), &view.render_options);
+            })?;
+        }
+
+        // Check for updates or user input (with timeout)
+        if let Some(key) = read_key()? {
+            // Any key acknowledges a gate change or a failed alert command
+            gate_change = None;
+            alert_command_error = None;
+            dirty = true;
+            if handle_key(&key, &config.key_map, &mut view, &refresh_now) {
+                break;
+            }
+        }
+
+        // Exit on SIGINT/SIGTERM
+        if shutdown_rx.try_recv().is_ok() {
+            break;
+        }
+
+        // Check for flight updates (non-blocking)
+        match rx.try_recv() {
+            Ok(RefreshEvent::Updated(mut update)) => {
+                dirty = true;
+                quota.record_success();
+                last_update = std::time::Instant::now();
+                // Once the flight has left, the inbound leg is no longer fetched or shown
+                if update.view_model.actual_departure_time().is_some() {
+                    inbound_view_model = None;
+                } else if let Some(inbound) = update.inbound.take() {
+                    inbound_view_model = Some(inbound);
+                }
+                if let Some(point) = update.position.take() {
+                    record_track_point(&mut track, point);
+                }
+                delay_history.record(&update.view_model, chrono::Utc::now());
+                if current
+                    .as_ref()
+                    .is_some_and(|current| current.view_model.status != update.view_model.status)
+                {
+                    status_changed_at = Some(last_update);
+                }
+                if let Some(change) = current.as_ref().and_then(|current| {
+                    flight_status::GateChange::detect(&current.view_model, &update.view_model)
+                }) {
+                    crossterm::execute!(terminal.backend_mut(), crossterm::style::Print("\x07"))?;
+                    gate_change = Some((change, last_update));
+                }
+                view.flight_url = update.view_model.flightaware_url();
+                current = Some(*update);
+            }
+            Ok(RefreshEvent::Failed(kind)) => {
+                dirty = true;
+                quota.record_error(kind);
+                if current.is_none() {
+                    startup_error = Some(kind.message().to_string());
+                }
+            }
+            Ok(RefreshEvent::NotFound) => {
+                dirty = true;
+                if current.is_none() {
+                    startup_error = Some(no_flights_message(&config.flight_number));
+                }
+            }
+            // The refresh task only ends once the flight is complete
+            Err(tokio::sync::mpsc::error::TryRecvError::Disconnected) => {
+                dirty |= !polling_stopped;
+                polling_stopped = true;
+            }
+            Err(tokio::sync::mpsc::error::TryRecvError::Empty) => {}
+        }
+
+        // Scripts asked to fail fast rather than sit on the retry screen
+        if config.exit_on_error
+            && current.is_none()
+            && let Some(error) = &startup_error
+        {
+            exit_message = Some(error.clone());
+            break;
+        }
+
+        if let Some(connection_rx) = connection_rx.as_mut()
+            && let Ok(RefreshEvent::Updated(update)) = connection_rx.try_recv()
+        {
+            connecting_view_model = Some(update.view_model);
+            dirty = true;
+        }
+    }
+
+    // Restore terminal
+    restore_terminal(&mut terminal)?;
+    save_ui_state(&config, &view.render_options);
+
+    if let Some(message) = exit_message {
+        println!("{}", message);
+        return Ok(());
+    }
+
+    if let Some(path) = &config.gpx_path {
+        let name = ui::shown_ident(&config.flight_number, &view.render_options);
+        write_output_or_exit(path, gpx::to_gpx(&track, name));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_from_options_with_both_values() {
+        let result = Config::from_options(
+            Some("AA100".to_string()),
+            Some("test-api-key".to_string()),
+            5,
+            30,
+        );
+
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        assert_eq!(config.flight_number, "AA100");
+        assert_eq!(config.flight_aware_api_key, "test-api-key");
+        assert_eq!(config.refresh_interval, 5);
+        assert_eq!(config.alert_threshold_minutes, 30);
+    }
+
+    #[test]
+    fn test_callsign_stands_in_for_flight_number() {
+        let args = CliArgs::parse_from(["flui", "--callsign", "N123AB", "--api-key", "key"]);
+        let config =
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty()).unwrap();
+        assert_eq!(config.flight_number, "N123AB");
+        assert_eq!(config.callsign.as_deref(), Some("N123AB"));
+
+        let args = CliArgs::parse_from([
+            "flui",
+            "--flight-number",
+            "AS824",
+            "--callsign",
+            "ASA824",
+            "--api-key",
+            "key",
+        ]);
+        let config =
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty()).unwrap();
+        assert_eq!(config.flight_number, "AS824");
+        assert_eq!(config.callsign.as_deref(), Some("ASA824"));
+    }
+
+    #[test]
+    fn test_demo_needs_no_flight_number_or_api_key() {
+        let args = CliArgs::parse_from(["flui", "--demo"]);
+        let config =
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty()).unwrap();
+
+        assert!(config.demo);
+        assert_eq!(config.flight_number, demo::FLIGHT_NUMBER);
+    }
+
+    #[test]
+    fn test_config_from_options_trims_api_key() {
+        let config = Config::from_options(
+            Some("AA100".to_string()),
+            Some("  test-api-key\n".to_string()),
+            5,
+            30,
+        )
+        .unwrap();
+
+        assert_eq!(config.flight_aware_api_key, "test-api-key");
+    }
+
+    #[test]
+    fn test_config_from_options_blank_api_key() {
+        let result =
+            Config::from_options(Some("AA100".to_string()), Some(" \n".to_string()), 5, 30);
+
+        assert!(matches!(result, Err(ConfigurationError::BlankApiKey)));
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .starts_with("FlightAware API key is empty")
+        );
+    }
+
+    #[test]
+    fn test_config_from_options_missing_flight_number() {
+        let result = Config::from_options(None, Some("test-api-key".to_string()), 5, 30);
+
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            ConfigurationError::MissingFlightNumber => (),
+            _ => panic!("Expected MissingFlightNumber error"),
+        }
+    }
+
+    #[test]
+    fn test_config_from_options_missing_api_key() {
+        let result = Config::from_options(Some("AA100".to_string()), None, 5, 30);
+
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            ConfigurationError::MissingApiKey => (),
+            _ => panic!("Expected MissingApiKey error"),
+        }
+    }
+
+    #[test]
+    fn test_config_from_options_missing_both() {
+        let result = Config::from_options(None, None, 5, 30);
+
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            ConfigurationError::MissingFlightNumber => (),
+            _ => panic!("Expected MissingFlightNumber error"),
+        }
+    }
+
+    #[test]
+    fn test_refresh_schedule_speeds_up_near_landing() {
+        use chrono::{Duration, Utc};
+
+        let schedule = RefreshSchedule {
+            interval: std::time::Duration::from_secs(60),
+            near_landing_interval: std::time::Duration::from_secs(10),
+            near_landing_trigger: AlertTrigger::Minutes(30),
+            jitter: false,
+        };
+        let arriving_in = |minutes| FlightStatusViewModel {
+            estimated_arrival: Some((Utc::now() + Duration::minutes(minutes)).to_rfc3339()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            schedule.interval_for(&arriving_in(20)),
+            std::time::Duration::from_secs(10)
+        );
+        assert_eq!(
+            schedule.interval_for(&arriving_in(240)),
+            std::time::Duration::from_secs(60)
+        );
+        assert_eq!(
+            schedule.interval_for(&FlightStatusViewModel::default()),
+            std::time::Duration::from_secs(60)
+        );
+    }
+
+    #[test]
+    fn test_is_stale() {
+        let stale_after = std::time::Duration::from_secs(60);
+
+        assert!(!is_stale(std::time::Duration::from_secs(5), stale_after));
+        assert!(!is_stale(std::time::Duration::from_secs(60), stale_after));
+        assert!(is_stale(std::time::Duration::from_secs(61), stale_after));
+    }
+
+    #[test]
+    fn test_cross_milestones_fires_each_once() {
+        let milestones = [50, 90];
+        let mut fired = HashSet::new();
+
+        assert!(!cross_milestones(&milestones, &mut fired, 20.0));
+        assert!(cross_milestones(&milestones, &mut fired, 50.0));
+        assert!(!cross_milestones(&milestones, &mut fired, 60.0));
+        // A stale update dipping back below the milestone doesn't re-arm it
+        assert!(!cross_milestones(&milestones, &mut fired, 45.0));
+        assert!(!cross_milestones(&milestones, &mut fired, 55.0));
+        assert!(cross_milestones(&milestones, &mut fired, 95.0));
+        assert!(!cross_milestones(&milestones, &mut fired, 100.0));
+        assert_eq!(fired, HashSet::from([50, 90]));
+    }
+
+    #[test]
+    fn test_cross_milestones_jumping_past_several_rings_once() {
+        let mut fired = HashSet::new();
+
+        assert!(cross_milestones(&[25, 50, 75], &mut fired, 80.0));
+        assert_eq!(fired.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_alert_command_runs_on_first_alert() {
+        let mut alert = LandingAlert::default();
+        let command = Some("echo landing");
+
+        assert!(!alert.update(false));
+        assert!(alert.update(true));
+        let status = alert
+            .run_command(command)
+            .expect("command should run on the alert transition")
+            .unwrap()
+            .wait()
+            .await
+            .unwrap();
+        assert!(status.success());
+
+        // Staying in the alert doesn't fire again, and a later alert rings without the command
+        assert!(!alert.update(true));
+        assert!(!alert.update(false));
+        assert!(alert.update(true));
+        assert!(alert.run_command(command).is_none());
+    }
+
+    #[test]
+    fn test_alert_without_command() {
+        let mut alert = LandingAlert::default();
+
+        assert!(alert.update(true));
+        assert!(alert.run_command(None).is_none());
+    }
+
+    #[test]
+    fn test_status_flash_window() {
+        let changed_at = std::time::Instant::now();
+
+        assert!(!is_status_flashing(None, changed_at));
+        assert!(is_status_flashing(Some(changed_at), changed_at));
+        assert!(is_status_flashing(
+            Some(changed_at),
+            changed_at + std::time::Duration::from_millis(900)
+        ));
+        assert!(!is_status_flashing(
+            Some(changed_at),
+            changed_at + STATUS_FLASH_DURATION
+        ));
+    }
+
+    #[test]
+    fn test_should_redraw() {
+        let ms = std::time::Duration::from_millis;
+        let frame_interval = ms(100);
+
+        // Changes wait for the frame rate cap, then draw
+        assert!(!should_redraw(true, false, ms(40), frame_interval));
+        assert!(should_redraw(true, false, ms(100), frame_interval));
+        // So do animations
+        assert!(!should_redraw(false, true, ms(40), frame_interval));
+        assert!(should_redraw(false, true, ms(150), frame_interval));
+        // Nothing changed: only the once-a-second clock texts need a new frame
+        assert!(!should_redraw(false, false, ms(500), frame_interval));
+        assert!(should_redraw(
+            false,
+            false,
+            IDLE_REDRAW_INTERVAL,
+            frame_interval
+        ));
+        // A cap slower than the idle interval still holds
+        assert!(!should_redraw(false, false, ms(1500), ms(2000)));
+    }
+
+    #[test]
+    fn test_near_from_args() {
+        let args = CliArgs::parse_from([
+            "flui",
+            "--near",
+            "47.45,-122.31",
+            "--radius",
+            "40",
+            "--api-key",
+            "key",
+        ]);
+        let config =
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty()).unwrap();
+        assert_eq!(
+            config.near,
+            Some(nearby::SearchArea {
+                center: nearby::Coordinates {
+                    latitude: 47.45,
+                    longitude: -122.31,
+                },
+                radius_nm: 40.0,
+            })
+        );
+
+        let args = CliArgs::parse_from(["flui", "--near", "47.45,-122.31", "--api-key", "key"]);
+        let config =
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty()).unwrap();
+        assert_eq!(config.near.unwrap().radius_nm, 25.0);
+
+        for bad in [["--near", "47.45"], ["--near", "95,10"], ["--radius", "0"]] {
+            let mut args = vec!["flui", "--near", "47.45,-122.31", "--api-key", "key"];
+            args.extend(bad);
+            assert!(CliArgs::try_parse_from(args).is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn test_max_path_width_from_args() {
+        let args = CliArgs::parse_from(["flui", "--flight-number", "AA100", "--api-key", "key"]);
+        assert_eq!(
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty())
+                .unwrap()
+                .max_path_width,
+            80
+        );
+
+        let args = CliArgs::parse_from([
+            "flui",
+            "--flight-number",
+            "AA100",
+            "--api-key",
+            "key",
+            "--max-path-width",
+            "120",
+        ]);
+        assert_eq!(
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty())
+                .unwrap()
+                .max_path_width,
+            120
+        );
+
+        assert!(CliArgs::try_parse_from(["flui", "--max-path-width", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_output_dir_from_args() {
+        let args = CliArgs::parse_from([
+            "flui",
+            "--flight-number",
+            "AA100",
+            "--api-key",
+            "key",
+            "--output-dir",
+            "/tmp/flui-out",
+            "--gpx",
+            "track.gpx",
+            "--snapshot",
+            "/var/snapshot.txt",
+        ]);
+        let config =
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty()).unwrap();
+        assert_eq!(config.output_dir, Some(PathBuf::from("/tmp/flui-out")));
+        assert_eq!(
+            config.gpx_path,
+            Some(PathBuf::from("/tmp/flui-out/track.gpx"))
+        );
+        assert_eq!(
+            config.snapshot_path,
+            Some(PathBuf::from("/var/snapshot.txt"))
+        );
+        assert_eq!(
+            ui_state::path(config.output_dir.as_deref()),
+            Some(PathBuf::from("/tmp/flui-out/ui_state.json"))
+        );
+
+        // Without one, relative paths go under the default output directory
+        let args = CliArgs::parse_from([
+            "flui",
+            "--flight-number",
+            "AA100",
+            "--api-key",
+            "key",
+            "--gpx",
+            "track.gpx",
+        ]);
+        let config =
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty()).unwrap();
+        assert_eq!(
+            config.gpx_path,
+            Some(paths::output_path(None, Path::new("track.gpx")))
+        );
+    }
+
+    #[test]
+    fn test_saved_ui_state_from_args() {
+        let saved = ui_state::UiState {
+            ident_format: ui::IdentFormat::Icao,
+            minimal: true,
+            timetable: true,
+            block_times: false,
+            board_density: ui::BoardDensity::Spacious,
+        };
+
+        let args = CliArgs::parse_from(["flui", "--flight-number", "AA100", "--api-key", "key"]);
+        let config = config_from_args(args, &saved, std::io::empty()).unwrap();
+        assert_eq!(config.ident_format, ui::IdentFormat::Icao);
+        assert!(config.minimal);
+        assert!(config.timetable);
+        assert!(!config.block_times);
+        assert_eq!(config.board_density, ui::BoardDensity::Spacious);
+
+        // Flags given this run win over what was saved
+        let args = CliArgs::parse_from([
+            "flui",
+            "--flight-number",
+            "AA100",
+            "--api-key",
+            "key",
+            "--ident-format",
+            "raw",
+            "--block-times",
+            "--board-density",
+            "compact",
+        ]);
+        let config = config_from_args(args, &saved, std::io::empty()).unwrap();
+        assert_eq!(config.ident_format, ui::IdentFormat::Raw);
+        assert!(config.block_times);
+        assert_eq!(config.board_density, ui::BoardDensity::Compact);
+
+        // A saved toggle can be turned off for this run too
+        let args = CliArgs::parse_from([
+            "flui",
+            "--flight-number",
+            "AA100",
+            "--api-key",
+            "key",
+            "--no-minimal",
+        ]);
+        assert!(
+            !config_from_args(args, &saved, std::io::empty())
+                .unwrap()
+                .minimal
+        );
+        let saved_block_times = ui_state::UiState {
+            block_times: true,
+            ..saved
+        };
+        let args = CliArgs::parse_from([
+            "flui",
+            "--flight-number",
+            "AA100",
+            "--api-key",
+            "key",
+            "--no-block-times",
+        ]);
+        let config = config_from_args(args, &saved_block_times, std::io::empty()).unwrap();
+        assert!(!config.block_times);
+    }
+
+    #[test]
+    fn test_max_fps_from_args() {
+        let args = CliArgs::parse_from(["flui", "--flight-number", "AA100", "--api-key", "key"]);
+        assert_eq!(
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty())
+                .unwrap()
+                .max_fps,
+            10
+        );
+
+        let args = CliArgs::parse_from([
+            "flui",
+            "--flight-number",
+            "AA100",
+            "--api-key",
+            "key",
+            "--max-fps",
+            "4",
+        ]);
+        assert_eq!(
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty())
+                .unwrap()
+                .max_fps,
+            4
+        );
+
+        assert!(CliArgs::try_parse_from(["flui", "--max-fps", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_record_track_point_skips_repeated_positions() {
+        use chrono::TimeZone;
+
+        let point = |minute| gpx::TrackPoint {
+            latitude: 47.4,
+            longitude: -122.3,
+            altitude_feet: 35000,
+            timestamp: chrono::Utc
+                .with_ymd_and_hms(2025, 1, 1, 12, minute, 0)
+                .unwrap(),
+        };
+        let mut track = Vec::new();
+
+        record_track_point(&mut track, point(0));
+        record_track_point(&mut track, point(0));
+        record_track_point(&mut track, point(3));
+
+        assert_eq!(track, vec![point(0), point(3)]);
+    }
+
+    #[test]
+    fn test_stale_after_defaults_to_a_minute() {
+        let config =
+            Config::from_options(Some("AA100".to_string()), Some("key".to_string()), 180, 30)
+                .unwrap();
+        assert_eq!(config.stale_after_secs, 60);
+    }
+
+    #[test]
+    fn test_stale_banner_outranks_quota_banner() {
+        let quota = Some("API quota likely exceeded");
+
+        assert_eq!(banner_text(None, None, quota, true), Some(STALE_BANNER));
+        assert_eq!(banner_text(None, None, quota, false), quota);
+        assert_eq!(banner_text(None, None, None, false), None);
+        assert_eq!(
+            banner_text(Some("Gate changed"), None, quota, true),
+            Some("Gate changed")
+        );
+    }
+
+    #[test]
+    fn test_parse_duration_secs() {
+        assert_eq!(parse_duration_secs("180"), Ok(180));
+        assert_eq!(parse_duration_secs("90s"), Ok(90));
+        assert_eq!(parse_duration_secs("2m"), Ok(120));
+        assert_eq!(parse_duration_secs("1m30s"), Ok(90));
+        assert_eq!(parse_duration_secs("1h5s"), Ok(3605));
+    }
+
+    #[test]
+    fn test_parse_duration_secs_rejects_invalid_input() {
+        for text in [
+            "", "90x", "90 s", "m", "1m30", "30s1m", "1m1m", "-5", "1.5m", "2M",
+        ] {
+            assert!(
+                parse_duration_secs(text).is_err(),
+                "{:?} should be rejected",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_refresh_interval_accepts_units() {
+        let args = CliArgs::parse_from([
+            "flui",
+            "--flight-number",
+            "AA100",
+            "--api-key",
+            "key",
+            "--refresh-interval",
+            "2m",
+            "--near-landing-refresh-interval",
+            "45s",
+        ]);
+        let config =
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty()).unwrap();
+
+        assert_eq!(config.refresh_interval, 120);
+        assert_eq!(config.near_landing_refresh_interval, 45);
+        assert!(CliArgs::try_parse_from(["flui", "--refresh-interval", "2 minutes"]).is_err());
+    }
+
+    #[test]
+    fn test_locale_is_utf8() {
+        assert!(locale_is_utf8(&Some("en_US.UTF-8".to_string())));
+        assert!(locale_is_utf8(&Some("de_DE.utf8".to_string())));
+        assert!(locale_is_utf8(&None));
+        assert!(!locale_is_utf8(&Some("C".to_string())));
+        assert!(!locale_is_utf8(&Some("en_US.ISO-8859-1".to_string())));
+    }
+
+    #[test]
+    fn test_refresh_jitter_stays_within_bounds() {
+        let schedule = RefreshSchedule {
+            interval: std::time::Duration::from_secs(60),
+            near_landing_interval: std::time::Duration::from_secs(10),
+            near_landing_trigger: AlertTrigger::Minutes(30),
+            jitter: true,
+        };
+        let delay = std::time::Duration::from_secs(100);
+
+        for _ in 0..1000 {
+            let jittered = schedule.jittered(delay);
+            assert!(jittered >= std::time::Duration::from_secs(80));
+            assert!(jittered <= std::time::Duration::from_secs(120));
+        }
+
+        let exact = RefreshSchedule {
+            jitter: false,
+            ..schedule
+        };
+        assert_eq!(exact.jittered(delay), delay);
+    }
+
+    #[tokio::test]
+    async fn test_http_client_times_out_on_slow_response() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/flights/AA100");
+                then.status(200)
+                    .delay(std::time::Duration::from_secs(2))
+                    .body("{}");
+            })
+            .await;
+
+        let http_client = create_authenticated_http_client(
+            "test-api-key",
+            HttpClientOptions {
+                timeout: std::time::Duration::from_millis(100),
+                tcp_keepalive: None,
+                pool_idle_timeout: std::time::Duration::from_secs(90),
+            },
+        );
+        let client = create_flightaware_client(http_client, Some(&server.base_url()));
+
+        let result = client
+            .get_flight("AA100", None, None, None, None, None)
+            .await;
+
+        match result {
+            Err(flightaware::Error::CommunicationError(error)) => assert!(error.is_timeout()),
+            other => panic!("Expected a timeout, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_http_client_options_from_config() {
+        let mut config =
+            Config::from_options(Some("AA100".to_string()), Some("key".to_string()), 180, 30)
+                .unwrap();
+        let options = HttpClientOptions::from_config(&config);
+        assert_eq!(options.timeout, std::time::Duration::from_secs(15));
+        assert_eq!(
+            options.tcp_keepalive,
+            Some(std::time::Duration::from_secs(60))
+        );
+        // Outlives the longest jittered wait of 216s
+        assert_eq!(
+            options.pool_idle_timeout,
+            std::time::Duration::from_secs(246)
+        );
+
+        config.tcp_keepalive_secs = 0;
+        assert_eq!(HttpClientOptions::from_config(&config).tcp_keepalive, None);
+    }
+
+    #[tokio::test]
+    async fn test_http_client_with_pool_options() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/flights/AA100")
+                    .header("x-apikey", "test-api-key");
+                then.status(200)
+                    .body(r#"{"flights": [], "links": null, "num_pages": 1}"#);
+            })
+            .await;
+
+        let config =
+            Config::from_options(Some("AA100".to_string()), Some("key".to_string()), 180, 30)
+                .unwrap();
+        let http_client = create_authenticated_http_client(
+            "test-api-key",
+            HttpClientOptions::from_config(&config),
+        );
+        let client = create_flightaware_client(http_client, Some(&server.base_url()));
+
+        for _ in 0..2 {
+            client
+                .get_flight("AA100", None, None, None, None, None)
+                .await
+                .unwrap();
+        }
+        mock.assert_calls_async(2).await;
+    }
+
+    /// A `/flights/search` result for an airborne flight last seen at `latitude`, `longitude`
+    fn search_result(ident: &str, latitude: f64, longitude: f64) -> serde_json::Value {
+        serde_json::json!({
+            "ident": ident,
+            "ident_icao": ident,
+            "ident_iata": null,
+            "fa_flight_id": format!("{}-1763277826-airline-1033p", ident),
+            "actual_off": "2025-11-16T10:00:00Z",
+            "actual_on": null,
+            "origin": null,
+            "destination": null,
+            "waypoints": [],
+            "first_position_time": "2025-11-16T10:00:00Z",
+            "last_position": {
+                "fa_flight_id": null,
+                "altitude": 120,
+                "altitude_change": "C",
+                "groundspeed": 280,
+                "heading": 180,
+                "latitude": latitude,
+                "longitude": longitude,
+                "timestamp": "2025-11-16T10:20:00Z",
+                "update_type": "A"
+            },
+            "bounding_box": null,
+            "ident_prefix": null,
+            "aircraft_type": "B738",
+            "foresight_predictions_available": false,
+            "predicted_out": null,
+            "predicted_off": null,
+            "predicted_on": null,
+            "predicted_in": null,
+            "predicted_out_source": null,
+            "predicted_off_source": null,
+            "predicted_on_source": null,
+            "predicted_in_source": null
+        })
+    }
+
+    #[tokio::test]
+    async fn test_search_nearby_keeps_flights_within_radius() {
+        use httpmock::prelude::*;
+
+        let area = nearby::SearchArea {
+            center: nearby::Coordinates {
+                latitude: 47.45,
+                longitude: -122.31,
+            },
+            radius_nm: 25.0,
+        };
+        let server = MockServer::start_async().await;
+        let body = serde_json::json!({
+            "flights": [
+                // About 18 nm north, 6 nm north, and in the box's corner 33 nm away
+                search_result("ASA100", 47.75, -122.31),
+                search_result("DAL200", 47.55, -122.31),
+                search_result("UAL300", 47.83, -121.75),
+            ],
+            "links": null,
+            "num_pages": 1
+        });
+        server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/flights/search")
+                    .query_param("query", area.query());
+                then.status(200).json_body(body);
+            })
+            .await;
+        let client = flightaware::Client::new(&server.base_url());
+
+        let flights = search_nearby(&client, &area).await.unwrap();
+        let idents: Vec<&str> = flights.iter().map(|flight| flight.ident.as_str()).collect();
+        assert_eq!(idents, ["DAL200", "ASA100"]);
+        assert_eq!(flights[0].fa_flight_id, "DAL200-1763277826-airline-1033p");
+    }
+
+    #[tokio::test]
+    async fn test_search_nearby_with_nothing_overhead() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/flights/search");
+                then.status(200)
+                    .body(r#"{"flights": [], "links": null, "num_pages": 1}"#);
+            })
+            .await;
+        let client = flightaware::Client::new(&server.base_url());
+        let area = nearby::SearchArea {
+            center: nearby::Coordinates {
+                latitude: 0.0,
+                longitude: 0.0,
+            },
+            radius_nm: 10.0,
+        };
+
+        assert_eq!(search_nearby(&client, &area).await, Ok(Vec::new()));
+    }
+
+    #[tokio::test]
+    async fn test_initial_fetch_with_no_flights() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/flights/AA100");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(r#"{"flights": [], "links": null, "num_pages": 1}"#);
+            })
+            .await;
+
+        let config =
+            Config::from_options(Some("AA100".to_string()), Some("key".to_string()), 180, 30)
+                .unwrap();
+        let client = flightaware::Client::new(&server.base_url());
+
+        match fetch_initial_flight(&client, &config).await {
+            Err(message) => {
+                assert_eq!(
+                    message,
+                    "No flights found for AA100 — check the flight number"
+                )
+            }
+            Ok(_) => panic!("Expected no flights to be found"),
+        }
+    }
+
+    const SAMPLE_FLIGHTS: &str = include_str!("../../flightaware/sample_flight_aware.json");
+    const SAMPLE_FLIGHT_ID: &str = "HAL824-1763277826-airline-1033p";
+    const SAMPLE_INBOUND_ID: &str = "HAL823-1763195983-airline-231p";
+
+    fn sample_refresh_target() -> RefreshTarget {
+        RefreshTarget {
+            flight_number: "HAL824".to_string(),
+            locked_flight_id: Some(SAMPLE_FLIGHT_ID.to_string()),
+            inbound: true,
+            position: true,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_refresh_combines_auxiliaries() {
+        use chrono::TimeZone;
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/flights/HAL824");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(SAMPLE_FLIGHTS);
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path(format!("/flights/{}", SAMPLE_INBOUND_ID));
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(SAMPLE_FLIGHTS.replace(SAMPLE_FLIGHT_ID, SAMPLE_INBOUND_ID));
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path(format!("/flights/{}/position", SAMPLE_FLIGHT_ID));
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(format!(
+                        r#"{{
+                            "ident": "HAL824",
+                            "fa_flight_id": "{SAMPLE_FLIGHT_ID}",
+                            "actual_off": "2025-11-16T08:00:00Z",
+                            "actual_on": null,
+                            "origin": {{"code": "RJAA", "airport_info_url": null}},
+                            "destination": {{"code": "PHNL", "airport_info_url": null}},
+                            "waypoints": [],
+                            "first_position_time": "2025-11-16T07:50:00Z",
+                            "last_position": {{
+                                "fa_flight_id": null,
+                                "altitude": 350,
+                                "altitude_change": "-",
+                                "groundspeed": 480,
+                                "heading": 95,
+                                "latitude": 30.5,
+                                "longitude": 170.25,
+                                "timestamp": "2025-11-16T12:00:00Z",
+                                "update_type": "A"
+                            }},
+                            "bounding_box": null,
+                            "ident_prefix": null,
+                            "aircraft_type": "A332",
+                            "foresight_predictions_available": false
+                        }}"#
+                    ));
+            })
+            .await;
+        let client = flightaware::Client::new(&server.base_url());
+
+        let update = fetch_refresh(&client, &sample_refresh_target(), Some(SAMPLE_INBOUND_ID))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(update.fa_flight_id, SAMPLE_FLIGHT_ID);
+        assert!(update.latency.is_some());
+        assert_eq!(
+            update.view_model.inbound_fa_flight_id.as_deref(),
+            Some(SAMPLE_INBOUND_ID)
+        );
+        assert_eq!(update.inbound.unwrap().flight_number, "HAL824");
+        assert_eq!(
+            update.position,
+            Some(gpx::TrackPoint {
+                latitude: 30.5,
+                longitude: 170.25,
+                altitude_feet: 35000,
+                timestamp: chrono::Utc
+                    .with_ymd_and_hms(2025, 11, 16, 12, 0, 0)
+                    .unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_inbound_fetched_only_until_departure() {
+        let scheduled = FlightStatusViewModel {
+            inbound_fa_flight_id: Some("HAL824-1".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            inbound_flight_id_to_fetch(&scheduled).as_deref(),
+            Some("HAL824-1")
+        );
+
+        let departed = FlightStatusViewModel {
+            actual_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            ..scheduled
+        };
+        assert_eq!(inbound_flight_id_to_fetch(&departed), None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_refresh_without_auxiliaries_when_they_fail() {
+        use httpmock::prelude::*;
+
+        // Only the flight itself is served; the inbound and position requests get a 404
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/flights/HAL824");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(SAMPLE_FLIGHTS);
+            })
+            .await;
+        let client = flightaware::Client::new(&server.base_url());
+
+        let update = fetch_refresh(&client, &sample_refresh_target(), Some(SAMPLE_INBOUND_ID))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(update.fa_flight_id, SAMPLE_FLIGHT_ID);
+        assert!(update.inbound.is_none());
+        assert!(update.position.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_refresh_fails_without_the_flight() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/flights/HAL824");
+                then.status(429);
+            })
+            .await;
+        let client = flightaware::Client::new(&server.base_url());
+
+        let result =
+            fetch_refresh(&client, &sample_refresh_target(), Some(SAMPLE_INBOUND_ID)).await;
+
+        assert!(matches!(result, Err(FetchErrorKind::QuotaExceeded)));
+    }
+
+    /// Start the refresh task the way the TUI does, with nothing fetched yet
+    fn spawn_startup_refresh(
+        server: &httpmock::MockServer,
+    ) -> tokio::sync::mpsc::Receiver<RefreshEvent> {
+        let schedule = RefreshSchedule {
+            interval: std::time::Duration::from_secs(3600),
+            near_landing_interval: std::time::Duration::from_secs(3600),
+            near_landing_trigger: AlertTrigger::Minutes(30),
+            jitter: false,
+        };
+        spawn_refresh_task(
+            flightaware::Client::new(&server.base_url()),
+            RefreshTarget {
+                flight_number: "HAL824".to_string(),
+                ..Default::default()
+            },
+            schedule,
+            None,
+            Arc::new(tokio::sync::Notify::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_startup_loads_first_flight_through_refresh_task() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/flights/HAL824");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(SAMPLE_FLIGHTS);
+            })
+            .await;
+
+        // The first fetch runs straight away rather than after a refresh interval
+        let mut rx = spawn_startup_refresh(&server);
+        let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .expect("the first fetch shouldn't wait for the schedule");
+
+        match event {
+            Some(RefreshEvent::Updated(update)) => {
+                assert_eq!(update.fa_flight_id, SAMPLE_FLIGHT_ID);
+                assert_eq!(update.view_model.flight_number, "HAL824");
+            }
+            _ => panic!("Expected the flight to load"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_startup_reports_missing_flight_through_refresh_task() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/flights/HAL824");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(r#"{"flights": [], "links": null, "num_pages": 1}"#);
+            })
+            .await;
+
+        let mut rx = spawn_startup_refresh(&server);
+        let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .unwrap();
+
+        assert!(matches!(event, Some(RefreshEvent::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_initial_fetch_when_unreachable() {
+        let config =
+            Config::from_options(Some("AA100".to_string()), Some("key".to_string()), 180, 30)
+                .unwrap();
+        let client = fetch_error::unreachable_client();
+
+        match fetch_initial_flight(&client, &config).await {
+            Err(message) => assert!(message.starts_with(fetch_error::UNREACHABLE_MESSAGE)),
+            Ok(_) => panic!("Expected the request to fail"),
+        }
+    }
+
+    #[test]
+    fn test_config_reads_flight_number_from_stdin() {
+        let args = CliArgs::parse_from(["flui", "--stdin", "--api-key", "key"]);
+        let config = config_from_args(
+            args,
+            &ui_state::UiState::default(),
+            std::io::Cursor::new("  AA100  \n"),
+        )
+        .unwrap();
+
+        assert_eq!(config.flight_number, "AA100");
+        assert!(config.flight_number_from_stdin);
+    }
+
+    #[test]
+    fn test_alert_trigger_from_args() {
+        let args = CliArgs::parse_from(["flui", "--flight-number", "AA100", "--api-key", "key"]);
+        let config =
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty()).unwrap();
+        assert_eq!(config.alert_trigger, AlertTrigger::Minutes(30));
+
+        let args = CliArgs::parse_from([
+            "flui",
+            "--flight-number",
+            "AA100",
+            "--api-key",
+            "key",
+            "--alert-at-progress",
+            "90",
+        ]);
+        let config =
+            config_from_args(args, &ui_state::UiState::default(), std::io::empty()).unwrap();
+        assert_eq!(config.alert_trigger, AlertTrigger::Progress(90.0));
+    }
+
+    #[test]
+    fn test_config_from_empty_stdin() {
+        let args = CliArgs::parse_from(["flui", "--stdin", "--api-key", "key"]);
+        let result = config_from_args(
+            args,
+            &ui_state::UiState::default(),
+            std::io::Cursor::new("\n"),
+        );
+
+        assert!(matches!(result, Err(ConfigurationError::EmptyStdin)));
+    }
+
+    #[test]
+    fn test_configuration_error_display_flight_number() {
+        let error = ConfigurationError::MissingFlightNumber;
+        let message = format!("{}", error);
+        assert!(message.contains("Flight number is required"));
+        assert!(message.contains("--flight-number"));
+        assert!(message.contains("FLIGHT_NUMBER"));
+    }
+
+    #[test]
+    fn test_configuration_error_display_api_key() {
+        let error = ConfigurationError::MissingApiKey;
+        let message = format!("{}", error);
+        assert!(message.contains("FlightAware API key is required"));
+        assert!(message.contains("--api-key"));
+        assert!(message.contains("FLIGHTAWARE_API_KEY"));
+    }
+
+    #[test]
+    fn test_configuration_error_display_key_bindings() {
+        let error = ConfigurationError::InvalidKeyBindings(KeyMapError::Unbound("quit"));
+        let message = format!("{}", error);
+        assert!(message.contains("[keys]"));
+        assert!(message.contains("\"quit\" has no keys"));
+    }
+
+    #[test]
+    fn test_configuration_error_display_flights_file() {
+        let error = ConfigurationError::UnreadableFlightsFile(
+            PathBuf::from("flights.txt"),
+            std::io::Error::from(std::io::ErrorKind::NotFound),
+        );
+        let message = format!("{}", error);
+        assert!(message.contains("Could not read flights file flights.txt"));
     }
 }
@@ -2,7 +2,9 @@
 use httpmock::prelude::*;
 
 #[cfg(feature = "httpmock")]
-pub fn start_mock_server() -> (String, httpmock::MockServer) {
+/// Start the mock server; its address is only announced when `verbose` is set, and then on
+/// stderr so it can't end up in the TUI or in `--once` output
+pub fn start_mock_server(verbose: bool) -> (String, httpmock::MockServer) {
     let server = httpmock::MockServer::start();
 
     // Load the sample flight data
@@ -17,8 +19,10 @@ pub fn start_mock_server() -> (String, httpmock::MockServer) {
     });
 
     let base_url = server.base_url();
-    println!("🚀 Mock FlightAware server started at: {}", base_url);
-    println!("   All flight queries will return sample data");
+    if verbose {
+        eprintln!("🚀 Mock FlightAware server started at: {}", base_url);
+        eprintln!("   All flight queries will return sample data");
+    }
 
     (base_url, server)
 }
@@ -0,0 +1,212 @@
+/// Nautical miles in one degree of latitude
+const NM_PER_DEGREE: f64 = 60.0;
+
+/// Mean radius of the Earth, in nautical miles
+const EARTH_RADIUS_NM: f64 = 3440.065;
+
+/// Widest --radius accepted; FlightAware's search gets slow and costly over larger boxes
+pub const MAX_RADIUS_NM: f64 = 500.0;
+
+/// A point on the ground, in decimal degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Parse a `LAT,LON` pair in decimal degrees, e.g. `47.45,-122.31`
+pub fn parse_coordinates(text: &str) -> Result<Coordinates, String> {
+    let invalid = || {
+        format!(
+            "invalid coordinates \"{}\", expected LAT,LON in decimal degrees, e.g. 47.45,-122.31",
+            text
+        )
+    };
+
+    let (latitude, longitude) = text.split_once(',').ok_or_else(invalid)?;
+    let latitude: f64 = latitude.trim().parse().map_err(|_| invalid())?;
+    let longitude: f64 = longitude.trim().parse().map_err(|_| invalid())?;
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(format!("latitude {} is outside -90 to 90", latitude));
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(format!("longitude {} is outside -180 to 180", longitude));
+    }
+
+    Ok(Coordinates {
+        latitude,
+        longitude,
+    })
+}
+
+/// Parse a search radius in nautical miles, above zero and at most `MAX_RADIUS_NM`
+pub fn parse_radius(text: &str) -> Result<f64, String> {
+    let radius: f64 = text
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid radius \"{}\", expected nautical miles", text))?;
+    if radius > 0.0 && radius <= MAX_RADIUS_NM {
+        Ok(radius)
+    } else {
+        Err(format!(
+            "radius must be above 0 and at most {} nautical miles",
+            MAX_RADIUS_NM
+        ))
+    }
+}
+
+/// The circle --near searches for flights in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchArea {
+    pub center: Coordinates,
+    pub radius_nm: f64,
+}
+
+impl SearchArea {
+    /// The `/flights/search` query for the box around the circle
+    /// The box's corners stick out past the radius, so results are filtered by distance after
+    pub fn query(&self) -> String {
+        let latitude_span = self.radius_nm / NM_PER_DEGREE;
+        // Degrees of longitude shrink towards the poles
+        let longitude_span =
+            latitude_span / self.center.latitude.to_radians().cos().max(f64::EPSILON);
+
+        format!(
+            "-latlong \"{:.4} {:.4} {:.4} {:.4}\"",
+            (self.center.latitude - latitude_span).max(-90.0),
+            (self.center.longitude - longitude_span).max(-180.0),
+            (self.center.latitude + latitude_span).min(90.0),
+            (self.center.longitude + longitude_span).min(180.0),
+        )
+    }
+}
+
+/// Great-circle distance between two points, in nautical miles
+fn distance_nm(from: Coordinates, to: Coordinates) -> f64 {
+    let latitude_delta = (to.latitude - from.latitude).to_radians();
+    let longitude_delta = (to.longitude - from.longitude).to_radians();
+    let a = (latitude_delta / 2.0).sin().powi(2)
+        + from.latitude.to_radians().cos()
+            * to.latitude.to_radians().cos()
+            * (longitude_delta / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_NM * a.sqrt().asin()
+}
+
+/// An airborne flight found inside the search area
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearbyFlight {
+    pub ident: String,
+    pub fa_flight_id: String,
+    pub distance_nm: f64,
+}
+
+/// The searched flights within the area's radius, nearest first
+/// Flights without a reported position can't be placed, so they're left out
+pub fn nearest_flights(
+    flights: &[flightaware::types::GetFlightsBySearchResponseFlightsItem],
+    area: &SearchArea,
+) -> Vec<NearbyFlight> {
+    let mut nearby: Vec<NearbyFlight> = flights
+        .iter()
+        .filter_map(|flight| {
+            let position = flight.last_position.as_ref()?;
+            let distance_nm = distance_nm(
+                area.center,
+                Coordinates {
+                    latitude: position.latitude,
+                    longitude: position.longitude,
+                },
+            );
+            (distance_nm <= area.radius_nm).then(|| NearbyFlight {
+                ident: flight.ident.clone(),
+                fa_flight_id: flight.fa_flight_id.clone(),
+                distance_nm,
+            })
+        })
+        .collect();
+    nearby.sort_by(|a, b| a.distance_nm.total_cmp(&b.distance_nm));
+    nearby
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEATTLE: Coordinates = Coordinates {
+        latitude: 47.45,
+        longitude: -122.31,
+    };
+
+    #[test]
+    fn test_parse_coordinates() {
+        assert_eq!(parse_coordinates("47.45,-122.31"), Ok(SEATTLE));
+        assert_eq!(parse_coordinates(" 47.45 , -122.31 "), Ok(SEATTLE));
+        assert_eq!(
+            parse_coordinates("-33.9,151"),
+            Ok(Coordinates {
+                latitude: -33.9,
+                longitude: 151.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_coordinates() {
+        for text in [
+            "",
+            "47.45",
+            "47.45;-122.31",
+            "north,west",
+            "47.45,-122.31,10",
+        ] {
+            assert!(parse_coordinates(text).is_err(), "{:?}", text);
+        }
+        assert!(parse_coordinates("91,0").unwrap_err().contains("latitude"));
+        assert!(
+            parse_coordinates("0,-180.5")
+                .unwrap_err()
+                .contains("longitude")
+        );
+    }
+
+    #[test]
+    fn test_parse_radius() {
+        assert_eq!(parse_radius("25"), Ok(25.0));
+        assert_eq!(parse_radius("2.5"), Ok(2.5));
+        for text in ["0", "-10", "501", "far"] {
+            assert!(parse_radius(text).is_err(), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_search_query_boxes_the_radius() {
+        let area = SearchArea {
+            center: Coordinates {
+                latitude: 60.0,
+                longitude: 10.0,
+            },
+            radius_nm: 30.0,
+        };
+        // Half a degree of latitude, and twice that in longitude at 60°N
+        assert_eq!(area.query(), "-latlong \"59.5000 9.0000 60.5000 11.0000\"");
+
+        let polar = SearchArea {
+            center: Coordinates {
+                latitude: 89.9,
+                longitude: 179.9,
+            },
+            radius_nm: 30.0,
+        };
+        assert!(polar.query().ends_with(" 90.0000 180.0000\""));
+    }
+
+    #[test]
+    fn test_distance_nm() {
+        let one_degree_north = Coordinates {
+            latitude: SEATTLE.latitude + 1.0,
+            ..SEATTLE
+        };
+        assert!((distance_nm(SEATTLE, one_degree_north) - 60.0).abs() < 0.1);
+        assert_eq!(distance_nm(SEATTLE, SEATTLE), 0.0);
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A file or directory flui couldn't write
+#[derive(Debug)]
+pub struct OutputError {
+    pub path: PathBuf,
+    pub error: std::io::Error,
+}
+
+impl fmt::Display for OutputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.error.kind() == std::io::ErrorKind::PermissionDenied {
+            write!(
+                f,
+                "Could not write {}: permission denied. Choose a directory you can write to with --output-dir",
+                self.path.display()
+            )
+        } else {
+            write!(f, "Could not write {}: {}", self.path.display(), self.error)
+        }
+    }
+}
+
+impl std::error::Error for OutputError {}
+
+/// `$XDG_CACHE_HOME/flui`, falling back to `~/.cache/flui`
+/// None when neither variable is set
+pub fn default_output_dir() -> Option<PathBuf> {
+    let cache_dir = std::env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    Some(cache_dir.join("flui"))
+}
+
+/// Where files flui keeps for itself go: the --output-dir when given, otherwise the default
+pub fn state_dir(output_dir: Option<&Path>) -> Option<PathBuf> {
+    output_dir
+        .map(Path::to_path_buf)
+        .or_else(default_output_dir)
+}
+
+/// A path given on the command line, placed under the --output-dir when it's relative
+/// Without an --output-dir, relative paths stay relative to the working directory
+pub fn resolve(output_dir: Option<&Path>, path: &Path) -> PathBuf {
+    match output_dir {
+        Some(dir) if path.is_relative() => dir.join(path),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Where a file named on the command line is written: relative paths go under the --output-dir,
+/// or the default output directory without one
+pub fn output_path(output_dir: Option<&Path>, path: &Path) -> PathBuf {
+    resolve(state_dir(output_dir).as_deref(), path)
+}
+
+/// Create `dir` and any missing parents
+pub fn ensure_dir(dir: &Path) -> Result<(), OutputError> {
+    std::fs::create_dir_all(dir).map_err(|error| OutputError {
+        path: dir.to_path_buf(),
+        error,
+    })
+}
+
+/// Write `contents` to `path`, creating its directory first if needed
+pub fn write_file(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), OutputError> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        ensure_dir(dir)?;
+    }
+    std::fs::write(path, contents).map_err(|error| OutputError {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let output_dir = Path::new("/tmp/flui-out");
+        assert_eq!(
+            resolve(Some(output_dir), Path::new("track.gpx")),
+            PathBuf::from("/tmp/flui-out/track.gpx")
+        );
+        assert_eq!(
+            resolve(Some(output_dir), Path::new("/var/track.gpx")),
+            PathBuf::from("/var/track.gpx")
+        );
+        assert_eq!(
+            resolve(None, Path::new("track.gpx")),
+            PathBuf::from("track.gpx")
+        );
+    }
+
+    #[test]
+    fn test_output_path() {
+        assert_eq!(
+            output_path(Some(Path::new("/tmp/flui-out")), Path::new("out.txt")),
+            PathBuf::from("/tmp/flui-out/out.txt")
+        );
+        assert_eq!(
+            output_path(None, Path::new("/var/out.txt")),
+            PathBuf::from("/var/out.txt")
+        );
+        assert_eq!(
+            output_path(None, Path::new("out.txt")),
+            resolve(default_output_dir().as_deref(), Path::new("out.txt"))
+        );
+    }
+
+    #[test]
+    fn test_state_dir_prefers_output_dir() {
+        assert_eq!(
+            state_dir(Some(Path::new("/tmp/flui-out"))),
+            Some(PathBuf::from("/tmp/flui-out"))
+        );
+    }
+
+    #[test]
+    fn test_write_file_creates_directory() {
+        let root = std::env::temp_dir().join(format!("flui-paths-{}", std::process::id()));
+        let path = resolve(Some(&root.join("nested")), Path::new("snapshot.txt"));
+
+        write_file(&path, "hello").unwrap();
+        assert!(root.join("nested").is_dir());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "hello");
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_permission_denied_message() {
+        let error = OutputError {
+            path: PathBuf::from("/root/flui"),
+            error: std::io::Error::from(std::io::ErrorKind::PermissionDenied),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Could not write /root/flui: permission denied. Choose a directory you can write to with --output-dir"
+        );
+    }
+}
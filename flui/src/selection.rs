@@ -0,0 +1,859 @@
+use chrono::{DateTime, Duration, Utc};
+use flightaware::types::GetFlightResponseFlightsItem;
+use std::borrow::Cow;
+
+/// Heuristic used to pick one flight when an ident matches several legs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum SelectionStrategy {
+    /// Estimated arrival closest to two hours ago, catching the leg that just landed or is about to
+    #[default]
+    ClosestToNow,
+    /// The leg that most recently departed
+    MostRecentDeparture,
+    /// The next leg that has not departed yet
+    NextUpcoming,
+    /// A leg that is currently airborne
+    ActiveEnRoute,
+}
+
+/// Select the most relevant flight from a list of flights using the given strategy
+/// Each strategy scores the flights it considers candidates (lower is better), and a leg to or
+/// from `home_airport` wins a tie;
+/// when no flight qualifies, falls back to the default heuristic and then to the first flight
+pub fn select_relevant_flight<'a>(
+    flights: &'a [GetFlightResponseFlightsItem],
+    strategy: SelectionStrategy,
+    home_airport: Option<&str>,
+    now: DateTime<Utc>,
+) -> Option<&'a GetFlightResponseFlightsItem> {
+    best_by_score(flights, strategy, home_airport, now)
+        .or_else(|| best_by_score(flights, SelectionStrategy::ClosestToNow, home_airport, now))
+        .or_else(|| flights.first())
+}
+
+/// Like `select_relevant_flight`, but keeps returning the flight with `locked_flight_id` while
+/// the API still lists it, so a session stays on the same leg as "now" moves past midnight UTC
+/// and the heuristic would otherwise drift onto a neighbouring leg
+pub fn select_sticky_flight<'a>(
+    flights: &'a [GetFlightResponseFlightsItem],
+    locked_flight_id: Option<&str>,
+    strategy: SelectionStrategy,
+    home_airport: Option<&str>,
+    now: DateTime<Utc>,
+) -> Option<&'a GetFlightResponseFlightsItem> {
+    locked_flight_id
+        .and_then(|id| flights.iter().find(|flight| flight.fa_flight_id == id))
+        .or_else(|| select_relevant_flight(flights, strategy, home_airport, now))
+}
+
+/// The legs flown under the ATC `callsign`, or every leg when not tracking by callsign
+pub fn flights_with_callsign<'a>(
+    flights: &'a [GetFlightResponseFlightsItem],
+    callsign: Option<&str>,
+) -> Cow<'a, [GetFlightResponseFlightsItem]> {
+    match callsign {
+        Some(callsign) => Cow::Owned(
+            flights
+                .iter()
+                .filter(|flight| flies_as(flight, callsign))
+                .cloned()
+                .collect(),
+        ),
+        None => Cow::Borrowed(flights),
+    }
+}
+
+/// Collapse legs listed more than once under the same `fa_flight_id`, which FlightAware
+/// occasionally does across pages; the copy with the most details filled in is kept, in the
+/// place the id first appeared
+pub fn dedupe_flights(
+    flights: &[GetFlightResponseFlightsItem],
+) -> Vec<GetFlightResponseFlightsItem> {
+    let mut deduped: Vec<GetFlightResponseFlightsItem> = Vec::with_capacity(flights.len());
+    for flight in flights {
+        match deduped
+            .iter_mut()
+            .find(|kept| kept.fa_flight_id == flight.fa_flight_id)
+        {
+            Some(kept) if completeness(flight) > completeness(kept) => *kept = flight.clone(),
+            Some(_) => {}
+            None => deduped.push(flight.clone()),
+        }
+    }
+    deduped
+}
+
+/// How many of the times, gates and progress a leg reports
+fn completeness(flight: &GetFlightResponseFlightsItem) -> usize {
+    let times = [
+        flight.scheduled_out,
+        flight.estimated_out,
+        flight.actual_out,
+        flight.scheduled_off,
+        flight.estimated_off,
+        flight.actual_off,
+        flight.scheduled_on,
+        flight.estimated_on,
+        flight.actual_on,
+        flight.scheduled_in,
+        flight.estimated_in,
+        flight.actual_in,
+    ];
+    let details = [
+        &flight.gate_origin,
+        &flight.gate_destination,
+        &flight.terminal_origin,
+        &flight.terminal_destination,
+        &flight.baggage_claim,
+    ];
+    times.iter().filter(|time| time.is_some()).count()
+        + details.iter().filter(|detail| detail.is_some()).count()
+        + usize::from(flight.progress_percent.is_some())
+}
+
+/// Longest time on the ground between two legs for them to count as one multi-leg flight; the
+/// same route flown on consecutive days is further apart than this
+const MAX_LEG_GAP_HOURS: i64 = 6;
+
+/// The legs flown under the ident on the same trip as `selected`, e.g. A→B and B→C around a
+/// selected B→C, in the order they're flown; just `selected` when it's a single leg
+pub fn connected_legs<'a>(
+    flights: &'a [GetFlightResponseFlightsItem],
+    selected: &'a GetFlightResponseFlightsItem,
+) -> Vec<&'a GetFlightResponseFlightsItem> {
+    let mut legs = vec![selected];
+    while let Some(before) = flights
+        .iter()
+        .filter(|flight| {
+            !legs
+                .iter()
+                .any(|leg| leg.fa_flight_id == flight.fa_flight_id)
+        })
+        .filter(|flight| continues_to(flight, legs[0]))
+        .max_by_key(|flight| leg_arrival(flight))
+    {
+        legs.insert(0, before);
+    }
+    while let Some(after) = flights
+        .iter()
+        .filter(|flight| {
+            !legs
+                .iter()
+                .any(|leg| leg.fa_flight_id == flight.fa_flight_id)
+        })
+        .filter(|flight| continues_to(legs[legs.len() - 1], flight))
+        .min_by_key(|flight| leg_departure(flight))
+    {
+        legs.push(after);
+    }
+    legs
+}
+
+/// Whether `next` leaves from where `leg` lands, soon after it lands
+fn continues_to(leg: &GetFlightResponseFlightsItem, next: &GetFlightResponseFlightsItem) -> bool {
+    let airport = |airport: &Option<flightaware::types::FlightAirportRef>| {
+        airport.as_ref().and_then(|airport| airport.code.clone())
+    };
+    let (Some(arrival), Some(departure)) = (leg_arrival(leg), leg_departure(next)) else {
+        return false;
+    };
+    let gap = departure - arrival;
+    airport(&leg.destination).is_some()
+        && airport(&leg.destination) == airport(&next.origin)
+        && gap >= Duration::zero()
+        && gap <= Duration::hours(MAX_LEG_GAP_HOURS)
+}
+
+fn leg_departure(flight: &GetFlightResponseFlightsItem) -> Option<DateTime<Utc>> {
+    flight.scheduled_out.or(flight.scheduled_off)
+}
+
+fn leg_arrival(flight: &GetFlightResponseFlightsItem) -> Option<DateTime<Utc>> {
+    flight.scheduled_in.or(flight.scheduled_on)
+}
+
+/// FlightAware leaves `atc_ident` empty when the callsign is the ident itself
+fn flies_as(flight: &GetFlightResponseFlightsItem, callsign: &str) -> bool {
+    flight
+        .atc_ident
+        .as_deref()
+        .unwrap_or(&flight.ident)
+        .eq_ignore_ascii_case(callsign)
+}
+
+fn best_by_score<'a>(
+    flights: &'a [GetFlightResponseFlightsItem],
+    strategy: SelectionStrategy,
+    home_airport: Option<&str>,
+    now: DateTime<Utc>,
+) -> Option<&'a GetFlightResponseFlightsItem> {
+    let score = match strategy {
+        SelectionStrategy::ClosestToNow => closest_to_now_score,
+        SelectionStrategy::MostRecentDeparture => most_recent_departure_score,
+        SelectionStrategy::NextUpcoming => next_upcoming_score,
+        SelectionStrategy::ActiveEnRoute => active_en_route_score,
+    };
+
+    flights
+        .iter()
+        .filter_map(|flight| score(flight, now).map(|score| (score, flight)))
+        .min_by_key(|(score, flight)| (*score, !touches_home_airport(flight, home_airport)))
+        .map(|(_, flight)| flight)
+}
+
+/// Whether the flight departs from or arrives at `home_airport`, given as any of the
+/// airport's codes
+fn touches_home_airport(flight: &GetFlightResponseFlightsItem, home_airport: Option<&str>) -> bool {
+    let Some(home_airport) = home_airport else {
+        return false;
+    };
+
+    [&flight.origin, &flight.destination]
+        .into_iter()
+        .flatten()
+        .any(|airport| {
+            [
+                airport.code.as_ref(),
+                airport.code_iata.as_ref(),
+                airport.code_icao.as_ref(),
+                airport.code_lid.as_ref(),
+            ]
+            .into_iter()
+            .flatten()
+            .any(|code| code.eq_ignore_ascii_case(home_airport))
+        })
+}
+
+/// Distance between the estimated arrival and two hours ago
+fn closest_to_now_score(
+    flight: &GetFlightResponseFlightsItem,
+    now: DateTime<Utc>,
+) -> Option<Duration> {
+    let target_time = now - Duration::hours(2);
+    flight
+        .estimated_on
+        .map(|arrival| (arrival - target_time).abs())
+}
+
+/// Time since departure, for flights that have (or should have) left already
+fn most_recent_departure_score(
+    flight: &GetFlightResponseFlightsItem,
+    now: DateTime<Utc>,
+) -> Option<Duration> {
+    let departure = flight.actual_off.or(flight.estimated_off)?;
+    (departure <= now).then(|| now - departure)
+}
+
+/// Time until departure, for flights that have not left yet
+fn next_upcoming_score(
+    flight: &GetFlightResponseFlightsItem,
+    now: DateTime<Utc>,
+) -> Option<Duration> {
+    if flight.actual_off.is_some() || flight.cancelled {
+        return None;
+    }
+    let departure = flight.estimated_off.or(flight.scheduled_off)?;
+    (departure > now).then(|| departure - now)
+}
+
+/// Time since wheels-up, for flights that are airborne (departed but not landed)
+fn active_en_route_score(
+    flight: &GetFlightResponseFlightsItem,
+    now: DateTime<Utc>,
+) -> Option<Duration> {
+    if flight.actual_on.is_some() {
+        return None;
+    }
+    flight.actual_off.map(|departure| now - departure)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    // Three legs of the same ident: one landed, one airborne, one scheduled
+    const PAST_CURRENT_FUTURE: &str = r#"{
+        "flights": [
+            {
+                "ident": "AA100-OLD",
+                "ident_icao": "AAL100",
+                "ident_iata": "AA100",
+                "fa_flight_id": "AAL100-1234-old",
+                "operator": "AAL",
+                "operator_icao": "AAL",
+                "operator_iata": "AA",
+                "flight_number": "100",
+                "registration": "N12345",
+                "atc_ident": null,
+                "inbound_fa_flight_id": null,
+                "codeshares": [],
+                "codeshares_iata": [],
+                "blocked": false,
+                "diverted": false,
+                "cancelled": false,
+                "position_only": false,
+                "origin": null,
+                "destination": null,
+                "departure_delay": 0,
+                "arrival_delay": 0,
+                "filed_ete": null,
+                "scheduled_out": null,
+                "estimated_out": null,
+                "actual_out": null,
+                "scheduled_off": "2025-11-16T07:00:00Z",
+                "estimated_off": "2025-11-16T07:00:00Z",
+                "actual_off": "2025-11-16T07:05:00Z",
+                "scheduled_on": "2025-11-16T10:00:00Z",
+                "estimated_on": "2025-11-16T10:00:00Z",
+                "actual_on": "2025-11-16T10:10:00Z",
+                "scheduled_in": null,
+                "estimated_in": null,
+                "actual_in": null,
+                "progress_percent": 100,
+                "status": "Landed",
+                "aircraft_type": "B738",
+                "route_distance": null,
+                "filed_airspeed": null,
+                "filed_altitude": null,
+                "route": null,
+                "baggage_claim": null,
+                "seats_cabin_business": null,
+                "seats_cabin_coach": null,
+                "seats_cabin_first": null,
+                "gate_origin": null,
+                "gate_destination": null,
+                "terminal_origin": null,
+                "terminal_destination": null,
+                "type": "Airline",
+                "actual_runway_off": null,
+                "actual_runway_on": null,
+                "foresight_predictions_available": false
+            },
+            {
+                "ident": "AA100-CURRENT",
+                "ident_icao": "AAL100",
+                "ident_iata": "AA100",
+                "fa_flight_id": "AAL100-1234-current",
+                "operator": "AAL",
+                "operator_icao": "AAL",
+                "operator_iata": "AA",
+                "flight_number": "100",
+                "registration": "N12346",
+                "atc_ident": null,
+                "inbound_fa_flight_id": null,
+                "codeshares": [],
+                "codeshares_iata": [],
+                "blocked": false,
+                "diverted": false,
+                "cancelled": false,
+                "position_only": false,
+                "origin": null,
+                "destination": null,
+                "departure_delay": 0,
+                "arrival_delay": 0,
+                "filed_ete": null,
+                "scheduled_out": null,
+                "estimated_out": null,
+                "actual_out": null,
+                "scheduled_off": "2025-11-16T10:00:00Z",
+                "estimated_off": "2025-11-16T10:00:00Z",
+                "actual_off": "2025-11-16T10:05:00Z",
+                "scheduled_on": "2025-11-16T11:30:00Z",
+                "estimated_on": "2025-11-16T11:30:00Z",
+                "actual_on": null,
+                "scheduled_in": null,
+                "estimated_in": null,
+                "actual_in": null,
+                "progress_percent": 45,
+                "status": "En Route",
+                "aircraft_type": "B738",
+                "route_distance": null,
+                "filed_airspeed": null,
+                "filed_altitude": null,
+                "route": null,
+                "baggage_claim": null,
+                "seats_cabin_business": null,
+                "seats_cabin_coach": null,
+                "seats_cabin_first": null,
+                "gate_origin": null,
+                "gate_destination": null,
+                "terminal_origin": null,
+                "terminal_destination": null,
+                "type": "Airline",
+                "actual_runway_off": null,
+                "actual_runway_on": null,
+                "foresight_predictions_available": false
+            },
+            {
+                "ident": "AA100-FUTURE",
+                "ident_icao": "AAL100",
+                "ident_iata": "AA100",
+                "fa_flight_id": "AAL100-1234-future",
+                "operator": "AAL",
+                "operator_icao": "AAL",
+                "operator_iata": "AA",
+                "flight_number": "100",
+                "registration": "N12347",
+                "atc_ident": null,
+                "inbound_fa_flight_id": null,
+                "codeshares": [],
+                "codeshares_iata": [],
+                "blocked": false,
+                "diverted": false,
+                "cancelled": false,
+                "position_only": false,
+                "origin": null,
+                "destination": null,
+                "departure_delay": 0,
+                "arrival_delay": 0,
+                "filed_ete": null,
+                "scheduled_out": null,
+                "estimated_out": null,
+                "actual_out": null,
+                "scheduled_off": "2025-11-16T14:00:00Z",
+                "estimated_off": "2025-11-16T14:00:00Z",
+                "actual_off": null,
+                "scheduled_on": "2025-11-16T16:00:00Z",
+                "estimated_on": "2025-11-16T16:00:00Z",
+                "actual_on": null,
+                "scheduled_in": null,
+                "estimated_in": null,
+                "actual_in": null,
+                "progress_percent": 0,
+                "status": "Scheduled",
+                "aircraft_type": "B738",
+                "route_distance": null,
+                "filed_airspeed": null,
+                "filed_altitude": null,
+                "route": null,
+                "baggage_claim": null,
+                "seats_cabin_business": null,
+                "seats_cabin_coach": null,
+                "seats_cabin_first": null,
+                "gate_origin": null,
+                "gate_destination": null,
+                "terminal_origin": null,
+                "terminal_destination": null,
+                "type": "Airline",
+                "actual_runway_off": null,
+                "actual_runway_on": null,
+                "foresight_predictions_available": false
+            }
+        ],
+        "links": null,
+        "num_pages": 1
+    }"#;
+
+    // A single cancelled leg with no estimated times
+    const CANCELLED_ONLY: &str = r#"{
+        "flights": [
+            {
+                "ident": "AA100-FIRST",
+                "ident_icao": "AAL100",
+                "ident_iata": "AA100",
+                "fa_flight_id": "AAL100-first",
+                "operator": "AAL",
+                "operator_icao": "AAL",
+                "operator_iata": "AA",
+                "flight_number": "100",
+                "registration": "N12345",
+                "atc_ident": null,
+                "inbound_fa_flight_id": null,
+                "codeshares": [],
+                "codeshares_iata": [],
+                "blocked": false,
+                "diverted": false,
+                "cancelled": true,
+                "position_only": false,
+                "origin": null,
+                "destination": null,
+                "departure_delay": 0,
+                "arrival_delay": 0,
+                "filed_ete": null,
+                "scheduled_out": null,
+                "estimated_out": null,
+                "actual_out": null,
+                "scheduled_off": "2025-11-16T10:00:00Z",
+                "estimated_off": null,
+                "actual_off": null,
+                "scheduled_on": "2025-11-16T14:00:00Z",
+                "estimated_on": null,
+                "actual_on": null,
+                "scheduled_in": null,
+                "estimated_in": null,
+                "actual_in": null,
+                "progress_percent": 0,
+                "status": "Cancelled",
+                "aircraft_type": null,
+                "route_distance": null,
+                "filed_airspeed": null,
+                "filed_altitude": null,
+                "route": null,
+                "baggage_claim": null,
+                "seats_cabin_business": null,
+                "seats_cabin_coach": null,
+                "seats_cabin_first": null,
+                "gate_origin": null,
+                "gate_destination": null,
+                "terminal_origin": null,
+                "terminal_destination": null,
+                "type": "Airline",
+                "actual_runway_off": null,
+                "actual_runway_on": null,
+                "foresight_predictions_available": false
+            }
+        ],
+        "links": null,
+        "num_pages": 1
+    }"#;
+
+    fn parse(json: &str) -> Vec<GetFlightResponseFlightsItem> {
+        let response: flightaware::types::GetFlightResponse =
+            serde_json::from_str(json).expect("Failed to parse test JSON");
+        response.flights
+    }
+
+    fn at(hour: u32, min: u32, sec: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 11, 16, hour, min, sec).unwrap()
+    }
+
+    fn select_ident(json: &str, strategy: SelectionStrategy, now: DateTime<Utc>) -> String {
+        let flights = parse(json);
+        select_relevant_flight(&flights, strategy, None, now)
+            .expect("Expected a flight to be selected")
+            .ident
+            .clone()
+    }
+
+    #[test]
+    fn test_select_relevant_flight_empty() {
+        let flights = vec![];
+        let result =
+            select_relevant_flight(&flights, SelectionStrategy::ClosestToNow, None, Utc::now());
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_select_relevant_flight_picks_closest_to_target() {
+        // At current time 2025-11-16T13:24:30Z, target is 11:24:30Z
+        // Flight 1 (OLD):     Arrives 10:00:00 - Distance from target: 1h 24m 30s
+        // Flight 2 (CURRENT): Arrives 11:30:00 - Distance from target: 5m 30s  ← CLOSEST
+        // Flight 3 (FUTURE):  Arrives 16:00:00 - Distance from target: 4h 35m 30s
+        let flights = parse(PAST_CURRENT_FUTURE);
+        let selected = select_relevant_flight(
+            &flights,
+            SelectionStrategy::ClosestToNow,
+            None,
+            at(13, 24, 30),
+        );
+
+        assert!(selected.is_some());
+        let flight = selected.unwrap();
+
+        // Should select AA100-CURRENT as it's closest to target time (11:24:30)
+        assert_eq!(flight.ident, "AA100-CURRENT");
+        assert_eq!(flight.fa_flight_id, "AAL100-1234-current");
+    }
+
+    #[test]
+    fn test_select_relevant_flight_fallback_to_first_when_no_estimated_arrival() {
+        let selected = select_ident(
+            CANCELLED_ONLY,
+            SelectionStrategy::ClosestToNow,
+            at(13, 0, 0),
+        );
+
+        // Should fall back to first flight when none have estimated_on
+        assert_eq!(selected, "AA100-FIRST");
+    }
+
+    #[test]
+    fn test_sticky_selection_keeps_locked_flight() {
+        let flights = parse(PAST_CURRENT_FUTURE);
+
+        // Locked onto CURRENT at 13:24; by the next day the heuristic alone prefers FUTURE
+        let later = at(13, 24, 30) + Duration::hours(12);
+        assert_eq!(
+            select_relevant_flight(&flights, SelectionStrategy::ClosestToNow, None, later)
+                .unwrap()
+                .ident,
+            "AA100-FUTURE"
+        );
+
+        let selected = select_sticky_flight(
+            &flights,
+            Some("AAL100-1234-current"),
+            SelectionStrategy::ClosestToNow,
+            None,
+            later,
+        )
+        .unwrap();
+        assert_eq!(selected.fa_flight_id, "AAL100-1234-current");
+    }
+
+    #[test]
+    fn test_sticky_selection_falls_back_when_locked_flight_gone() {
+        let flights = parse(PAST_CURRENT_FUTURE);
+
+        let selected = select_sticky_flight(
+            &flights,
+            Some("AAL100-no-longer-listed"),
+            SelectionStrategy::ClosestToNow,
+            None,
+            at(13, 24, 30),
+        )
+        .unwrap();
+        assert_eq!(selected.fa_flight_id, "AAL100-1234-current");
+
+        let unlocked = select_sticky_flight(
+            &flights,
+            None,
+            SelectionStrategy::NextUpcoming,
+            None,
+            at(13, 24, 30),
+        )
+        .unwrap();
+        assert_eq!(unlocked.ident, "AA100-FUTURE");
+    }
+
+    /// The first two legs of PAST_CURRENT_FUTURE arriving 30 minutes either side of 11:00,
+    /// two hours before 13:00, with the second one leaving from Seattle
+    fn equally_close_legs() -> Vec<GetFlightResponseFlightsItem> {
+        let mut response: serde_json::Value = serde_json::from_str(PAST_CURRENT_FUTURE).unwrap();
+        let flights = response["flights"].as_array_mut().unwrap();
+        flights.truncate(2);
+        flights[0]["estimated_on"] = serde_json::json!("2025-11-16T10:30:00Z");
+        flights[1]["estimated_on"] = serde_json::json!("2025-11-16T11:30:00Z");
+        flights[1]["origin"] = serde_json::json!({
+            "code": "KSEA",
+            "code_icao": "KSEA",
+            "code_iata": "SEA",
+            "code_lid": "SEA",
+            "timezone": "America/Los_Angeles",
+            "name": "Seattle-Tacoma Intl",
+            "city": "Seattle",
+            "airport_info_url": "/airports/KSEA"
+        });
+        serde_json::from_value::<flightaware::types::GetFlightResponse>(response)
+            .unwrap()
+            .flights
+    }
+
+    #[test]
+    fn test_flights_with_callsign() {
+        let mut flights = parse(PAST_CURRENT_FUTURE);
+        flights[1].atc_ident = Some("AAL100X".to_string());
+        let idents = |callsign| {
+            flights_with_callsign(&flights, callsign)
+                .iter()
+                .map(|flight| flight.ident.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(idents(None).len(), 3);
+        assert_eq!(idents(Some("aal100x")), ["AA100-CURRENT"]);
+        // Without an atc_ident the callsign is the ident
+        assert_eq!(idents(Some("AA100-OLD")), ["AA100-OLD"]);
+        assert!(idents(Some("N12345")).is_empty());
+    }
+
+    #[test]
+    fn test_dedupe_keeps_most_complete_copy() {
+        let mut flights = parse(PAST_CURRENT_FUTURE);
+        // A second, sparser listing of the current leg ahead of the full one
+        let sparse = GetFlightResponseFlightsItem {
+            ident: "AA100-SPARSE".to_string(),
+            estimated_on: None,
+            estimated_off: None,
+            actual_off: None,
+            ..flights[1].clone()
+        };
+        flights.insert(0, sparse);
+
+        let deduped = dedupe_flights(&flights);
+        let idents: Vec<&str> = deduped.iter().map(|flight| flight.ident.as_str()).collect();
+        assert_eq!(idents, ["AA100-CURRENT", "AA100-OLD", "AA100-FUTURE"]);
+
+        assert_eq!(
+            select_relevant_flight(
+                &deduped,
+                SelectionStrategy::ClosestToNow,
+                None,
+                at(13, 24, 30)
+            )
+            .unwrap()
+            .ident,
+            "AA100-CURRENT"
+        );
+    }
+
+    fn leg(
+        id: &str,
+        from: &str,
+        to: &str,
+        departs: DateTime<Utc>,
+        hours: i64,
+    ) -> GetFlightResponseFlightsItem {
+        let airport = |code: &str| {
+            serde_json::from_value(serde_json::json!({"code": code, "airport_info_url": null}))
+                .unwrap()
+        };
+        GetFlightResponseFlightsItem {
+            fa_flight_id: id.to_string(),
+            origin: Some(airport(from)),
+            destination: Some(airport(to)),
+            scheduled_out: None,
+            scheduled_off: Some(departs),
+            scheduled_on: Some(departs + Duration::hours(hours)),
+            scheduled_in: None,
+            ..parse(PAST_CURRENT_FUTURE)[0].clone()
+        }
+    }
+
+    fn three_legs() -> Vec<GetFlightResponseFlightsItem> {
+        vec![
+            leg("leg-2", "SFO", "LAX", at(11, 0, 0), 1),
+            leg("leg-1", "SEA", "SFO", at(8, 0, 0), 2),
+            leg("leg-3", "LAX", "SAN", at(13, 30, 0), 1),
+            // Tomorrow's first leg lands too late to join today's trip
+            leg(
+                "leg-1-tomorrow",
+                "SEA",
+                "SFO",
+                at(8, 0, 0) + Duration::days(1),
+                2,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_connected_legs() {
+        let flights = three_legs();
+        let ids = |selected: usize| {
+            connected_legs(&flights, &flights[selected])
+                .iter()
+                .map(|flight| flight.fa_flight_id.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ids(0), ["leg-1", "leg-2", "leg-3"]);
+        assert_eq!(ids(2), ["leg-1", "leg-2", "leg-3"]);
+        assert_eq!(ids(3), ["leg-1-tomorrow"]);
+    }
+
+    #[test]
+    fn test_connected_legs_single_leg() {
+        let flights = parse(PAST_CURRENT_FUTURE);
+        assert_eq!(connected_legs(&flights, &flights[1]).len(), 1);
+    }
+
+    #[test]
+    fn test_home_airport_breaks_a_tie() {
+        let flights = equally_close_legs();
+        let select = |home_airport| {
+            select_relevant_flight(
+                &flights,
+                SelectionStrategy::ClosestToNow,
+                home_airport,
+                at(13, 0, 0),
+            )
+            .unwrap()
+            .ident
+            .as_str()
+        };
+
+        // Without a home airport the tie goes to the first leg listed
+        assert_eq!(select(None), "AA100-OLD");
+        assert_eq!(select(Some("SEA")), "AA100-CURRENT");
+        assert_eq!(select(Some("ksea")), "AA100-CURRENT");
+        assert_eq!(select(Some("LAX")), "AA100-OLD");
+    }
+
+    #[test]
+    fn test_home_airport_does_not_beat_a_closer_leg() {
+        // Only FUTURE touches the home airport, but CURRENT is still much closer
+        let mut flights = parse(PAST_CURRENT_FUTURE);
+        flights[2].destination = equally_close_legs()[1].origin.clone();
+        let selected = select_relevant_flight(
+            &flights,
+            SelectionStrategy::ClosestToNow,
+            Some("SEA"),
+            at(13, 24, 30),
+        )
+        .unwrap();
+        assert_eq!(selected.ident, "AA100-CURRENT");
+    }
+
+    #[test]
+    fn test_most_recent_departure() {
+        // CURRENT left at 10:05, after OLD at 07:05; FUTURE has not left yet
+        assert_eq!(
+            select_ident(
+                PAST_CURRENT_FUTURE,
+                SelectionStrategy::MostRecentDeparture,
+                at(13, 24, 30)
+            ),
+            "AA100-CURRENT"
+        );
+        // Once FUTURE's 14:00 departure has passed it becomes the most recent
+        assert_eq!(
+            select_ident(
+                PAST_CURRENT_FUTURE,
+                SelectionStrategy::MostRecentDeparture,
+                at(15, 0, 0)
+            ),
+            "AA100-FUTURE"
+        );
+    }
+
+    #[test]
+    fn test_next_upcoming() {
+        assert_eq!(
+            select_ident(
+                PAST_CURRENT_FUTURE,
+                SelectionStrategy::NextUpcoming,
+                at(13, 24, 30)
+            ),
+            "AA100-FUTURE"
+        );
+    }
+
+    #[test]
+    fn test_next_upcoming_skips_departed_legs() {
+        // Before 10:00 CURRENT would be upcoming by its schedule, but it has already taken off
+        assert_eq!(
+            select_ident(
+                PAST_CURRENT_FUTURE,
+                SelectionStrategy::NextUpcoming,
+                at(9, 0, 0)
+            ),
+            "AA100-FUTURE"
+        );
+    }
+
+    #[test]
+    fn test_active_en_route() {
+        // Only CURRENT has actual_off without actual_on
+        assert_eq!(
+            select_ident(
+                PAST_CURRENT_FUTURE,
+                SelectionStrategy::ActiveEnRoute,
+                at(17, 0, 0)
+            ),
+            "AA100-CURRENT"
+        );
+    }
+
+    #[test]
+    fn test_active_en_route_falls_back_when_nothing_airborne() {
+        assert_eq!(
+            select_ident(
+                CANCELLED_ONLY,
+                SelectionStrategy::ActiveEnRoute,
+                at(13, 0, 0)
+            ),
+            "AA100-FIRST"
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use crate::flight_status::FlightStatusViewModel;
+use crate::ui::{self, IdentFormat};
+use axum::{Json, Router, extract::State, routing::get};
+use flui::FlightStatusDto;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Latest view model shared between the refresh task and the HTTP handlers
+pub type SharedViewModel = Arc<RwLock<FlightStatusViewModel>>;
+
+/// How the served flight was asked for, used to fill in the ident fields of /status
+#[derive(Debug, Clone, Default)]
+pub struct IdentOptions {
+    /// The flight number the user searched, which for a codeshare is the marketing airline's
+    pub queried_ident: Option<String>,
+    /// The form of the `ident` field
+    pub ident_format: IdentFormat,
+}
+
+#[derive(Clone)]
+struct AppState {
+    view_model: SharedViewModel,
+    idents: Arc<IdentOptions>,
+}
+
+/// The view model as served by /status, with the flight number spelled out every way a
+/// consumer might want it: `ident` in the --ident-format form and `marketing_ident` as searched,
+/// e.g. AA8500 for a codeshare British Airways flies
+fn status_dto(view_model: &FlightStatusViewModel, idents: &IdentOptions) -> FlightStatusDto {
+    let mut dto = FlightStatusDto::from(view_model);
+    dto.ident = ui::display_ident(view_model, idents.ident_format).to_string();
+    if let Some(queried) = idents
+        .queried_ident
+        .as_deref()
+        .map(|queried| queried.trim().to_uppercase())
+        .filter(|queried| !queried.is_empty())
+    {
+        dto.marketing_ident = queried;
+    }
+    dto
+}
+
+/// Build the HTTP router exposing the view model
+/// GET /status returns the latest view model as JSON, GET /health is a liveness probe
+pub fn router(state: SharedViewModel, idents: IdentOptions) -> Router {
+    Router::new()
+        .route("/status", get(status))
+        .route("/health", get(health))
+        .with_state(AppState {
+            view_model: state,
+            idents: Arc::new(idents),
+        })
+}
+
+/// Serve the view model on all interfaces at the given port until `shutdown` resolves
+pub async fn serve(
+    port: u16,
+    state: SharedViewModel,
+    idents: IdentOptions,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(("0.0.0.0", port)).await?;
+    println!(
+        "Serving flight status at http://{}/status",
+        listener.local_addr()?
+    );
+    axum::serve(listener, router(state, idents))
+        .with_graceful_shutdown(shutdown)
+        .await
+}
+
+async fn status(State(state): State<AppState>) -> Json<FlightStatusDto> {
+    let view_model = state.view_model.read().await;
+    Json(status_dto(&view_model, &state.idents))
+}
+
+async fn health() -> &'static str {
+    "ok"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flight_status::FlightStatus;
+
+    async fn spawn_server(state: SharedViewModel) -> String {
+        spawn_server_with(state, IdentOptions::default()).await
+    }
+
+    async fn spawn_server_with(state: SharedViewModel, idents: IdentOptions) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, router(state, idents)).await.unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_health_endpoint() {
+        let state = Arc::new(RwLock::new(FlightStatusViewModel::default()));
+        let base_url = spawn_server(state).await;
+
+        let response = reqwest::get(format!("{}/health", base_url)).await.unwrap();
+
+        assert!(response.status().is_success());
+        assert_eq!(response.text().await.unwrap(), "ok");
+    }
+
+    #[tokio::test]
+    async fn test_status_endpoint_reflects_updates() {
+        let state = Arc::new(RwLock::new(FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::OnTime,
+            ..Default::default()
+        }));
+        let base_url = spawn_server(state.clone()).await;
+
+        let body: serde_json::Value = reqwest::get(format!("{}/status", base_url))
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(body["version"], flui::dto::SCHEMA_VERSION);
+        assert_eq!(body["flight_number"], "AA100");
+        assert_eq!(body["status"], "OnTime");
+
+        state.write().await.status = FlightStatus::EnRoute;
+
+        let body: serde_json::Value = reqwest::get(format!("{}/status", base_url))
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(body["status"], "EnRoute");
+    }
+
+    #[tokio::test]
+    async fn test_status_includes_marketing_and_operating_idents() {
+        let state = Arc::new(RwLock::new(FlightStatusViewModel {
+            flight_number: "BAW123".to_string(),
+            ident_iata: Some("BA123".to_string()),
+            ident_icao: Some("BAW123".to_string()),
+            ..Default::default()
+        }));
+        let idents = IdentOptions {
+            queried_ident: Some(" aa8500 ".to_string()),
+            ident_format: IdentFormat::Iata,
+        };
+        let base_url = spawn_server_with(state, idents).await;
+
+        let body: serde_json::Value = reqwest::get(format!("{}/status", base_url))
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(body["marketing_ident"], "AA8500");
+        assert_eq!(body["operating_ident"], "BAW123");
+        assert_eq!(body["ident"], "BA123");
+        assert_eq!(body["flight_number"], "BAW123");
+    }
+
+    #[tokio::test]
+    async fn test_marketing_ident_falls_back_to_flight_number() {
+        let state = Arc::new(RwLock::new(FlightStatusViewModel {
+            flight_number: "AAL100".to_string(),
+            ..Default::default()
+        }));
+        let base_url = spawn_server(state).await;
+
+        let body: serde_json::Value = reqwest::get(format!("{}/status", base_url))
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(body["marketing_ident"], "AAL100");
+        assert_eq!(body["operating_ident"], "AAL100");
+        assert_eq!(body["ident"], "AAL100");
+    }
+}
@@ -0,0 +1,139 @@
+use crate::flight_status::FlightStatusViewModel;
+use std::fmt::Write;
+
+/// Horizontal space kept clear at each end of the track for the airport labels
+const MARGIN: f64 = 40.0;
+
+/// Render the flight's progress as a standalone SVG image: the flight number and percent on top,
+/// a track between the origin and destination with the plane placed by progress, and the
+/// airport codes underneath
+pub fn render_svg(view_model: &FlightStatusViewModel, width: u32, height: u32) -> String {
+    let width_f = f64::from(width);
+    let height_f = f64::from(height);
+    let progress = view_model.progress_percentage().clamp(0.0, 100.0);
+
+    let track_start = MARGIN;
+    let track_end = (width_f - MARGIN).max(track_start);
+    let track_y = height_f / 2.0;
+    let plane_x = track_start + (track_end - track_start) * progress / 100.0;
+
+    let origin = view_model.origin_airport.as_deref().unwrap_or("???");
+    let destination = view_model.destination_airport.as_deref().unwrap_or("???");
+
+    let mut svg = String::new();
+    // Writing to a String cannot fail
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif">"#
+    );
+    let _ = writeln!(
+        svg,
+        r##"  <rect width="{width}" height="{height}" rx="6" fill="#1e1e2e"/>"##
+    );
+    let _ = writeln!(
+        svg,
+        r##"  <text x="{track_start}" y="{:.1}" font-size="14" font-weight="bold" fill="#89dceb">{}</text>"##,
+        track_y - 18.0,
+        escape(&view_model.flight_number)
+    );
+    let _ = writeln!(
+        svg,
+        r##"  <text x="{track_end}" y="{:.1}" font-size="14" text-anchor="end" fill="#f9e2af">{:.0}%</text>"##,
+        track_y - 18.0,
+        progress
+    );
+
+    // Flown part of the track in yellow, the rest in grey
+    let _ = writeln!(
+        svg,
+        r##"  <line x1="{track_start}" y1="{track_y:.1}" x2="{track_end}" y2="{track_y:.1}" stroke="#585b70" stroke-width="2"/>"##
+    );
+    let _ = writeln!(
+        svg,
+        r##"  <line x1="{track_start}" y1="{track_y:.1}" x2="{plane_x:.1}" y2="{track_y:.1}" stroke="#f9e2af" stroke-width="2"/>"##
+    );
+    let _ = writeln!(
+        svg,
+        r##"  <circle cx="{track_start}" cy="{track_y:.1}" r="4" fill="#ffffff"/>"##
+    );
+    let _ = writeln!(
+        svg,
+        r##"  <circle cx="{track_end}" cy="{track_y:.1}" r="4" fill="#ffffff"/>"##
+    );
+
+    // Plane silhouette pointing right, centered on its position along the track
+    let _ = writeln!(
+        svg,
+        r##"  <path id="plane" transform="translate({plane_x:.1} {track_y:.1})" d="M 10 0 L -6 -3 L -8 -10 L -11 -10 L -9 -3 L -12 -2 L -12 2 L -9 3 L -11 10 L -8 10 L -6 3 Z" fill="#89dceb"/>"##
+    );
+
+    let _ = writeln!(
+        svg,
+        r##"  <text x="{track_start}" y="{:.1}" font-size="12" text-anchor="middle" fill="#cdd6f4">{}</text>"##,
+        track_y + 24.0,
+        escape(origin)
+    );
+    let _ = writeln!(
+        svg,
+        r##"  <text x="{track_end}" y="{:.1}" font-size="12" text-anchor="middle" fill="#cdd6f4">{}</text>"##,
+        track_y + 24.0,
+        escape(destination)
+    );
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+/// Escape text for use inside SVG elements
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view_model(progress_percent: i64) -> FlightStatusViewModel {
+        FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            origin_airport: Some("LAX".to_string()),
+            destination_airport: Some("JFK".to_string()),
+            progress_percent: Some(progress_percent),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_render_svg_contents() {
+        let svg = render_svg(&view_model(50), 400, 100);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains(">AA100</text>"));
+        assert!(svg.contains(">LAX</text>"));
+        assert!(svg.contains(">JFK</text>"));
+        assert!(svg.contains(">50%</text>"));
+        // Track runs from 40 to 360, so halfway is 200
+        assert!(svg.contains(r#"<path id="plane" transform="translate(200.0 50.0)""#));
+    }
+
+    #[test]
+    fn test_render_svg_plane_at_ends() {
+        assert!(render_svg(&view_model(0), 400, 100).contains("translate(40.0 50.0)"));
+        assert!(render_svg(&view_model(100), 400, 100).contains("translate(360.0 50.0)"));
+    }
+
+    #[test]
+    fn test_render_svg_escapes_text() {
+        let vm = FlightStatusViewModel {
+            flight_number: "<AA&100>".to_string(),
+            ..Default::default()
+        };
+
+        let svg = render_svg(&vm, 400, 100);
+        assert!(svg.contains("&lt;AA&amp;100&gt;"));
+        assert!(svg.contains(">???</text>"));
+    }
+}
@@ -0,0 +1,103 @@
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Mapping of major airports bundled with the binary, used when no --timezones-file is given
+const EMBEDDED_TIMEZONES: &str = include_str!("../data/airport_timezones.txt");
+
+/// Built once, on the first lookup or when a timezones file is loaded at startup
+static AIRPORT_TIMEZONES: OnceLock<HashMap<String, Tz>> = OnceLock::new();
+
+/// Parse the timezones file format: an airport code and an IANA timezone name per line,
+/// separated by whitespace; blank lines and anything after a `#` are ignored
+pub fn parse_timezones(contents: &str) -> Result<HashMap<String, Tz>, String> {
+    let mut timezones = HashMap::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut fields = line.split_whitespace();
+        let (Some(code), Some(name), None) = (fields.next(), fields.next(), fields.next()) else {
+            return Err(format!(
+                "line {}: expected an airport code and a timezone",
+                index + 1
+            ));
+        };
+        let timezone = name
+            .parse::<Tz>()
+            .map_err(|_| format!("line {}: unknown timezone {}", index + 1, name))?;
+        timezones.insert(code.to_uppercase(), timezone);
+    }
+    Ok(timezones)
+}
+
+fn embedded_timezones() -> HashMap<String, Tz> {
+    parse_timezones(EMBEDDED_TIMEZONES).expect("embedded airport timezones are valid")
+}
+
+/// Load airport timezones from a file on top of the embedded set, so entries in the file
+/// add airports or override the bundled ones
+/// Must be called before the first lookup
+pub fn load_timezones_file(path: &Path) -> Result<(), String> {
+    let contents = std::fs::read_to_string(path).map_err(|error| error.to_string())?;
+    let mut timezones = embedded_timezones();
+    timezones.extend(parse_timezones(&contents)?);
+
+    AIRPORT_TIMEZONES
+        .set(timezones)
+        .map_err(|_| "airport timezones were already loaded".to_string())
+}
+
+/// The timezone of an airport by IATA code, if it's known
+pub fn timezone_for_airport(code: &str) -> Option<Tz> {
+    AIRPORT_TIMEZONES
+        .get_or_init(embedded_timezones)
+        .get(&code.to_uppercase())
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timezone_for_known_airport() {
+        assert_eq!(
+            timezone_for_airport("SEA"),
+            Some(chrono_tz::America::Los_Angeles)
+        );
+        assert_eq!(timezone_for_airport("nrt"), Some(chrono_tz::Asia::Tokyo));
+    }
+
+    #[test]
+    fn test_timezone_for_unknown_airport() {
+        assert_eq!(timezone_for_airport("XXX"), None);
+    }
+
+    #[test]
+    fn test_parse_timezones_skips_blanks_and_comments() {
+        let contents = "\
+# Regional airports
+bzn America/Denver
+
+  EYW America/New_York # Key West
+";
+
+        let timezones = parse_timezones(contents).unwrap();
+        assert_eq!(timezones.len(), 2);
+        assert_eq!(timezones["BZN"], chrono_tz::America::Denver);
+        assert_eq!(timezones["EYW"], chrono_tz::America::New_York);
+    }
+
+    #[test]
+    fn test_parse_timezones_rejects_unknown_timezone() {
+        assert_eq!(
+            parse_timezones("SEA America/Seattle"),
+            Err("line 1: unknown timezone America/Seattle".to_string())
+        );
+        assert!(parse_timezones("SEA").is_err());
+    }
+}
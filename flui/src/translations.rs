@@ -0,0 +1,197 @@
+use crate::delay_history::DelayTrend;
+use flui::flight_status::{FlightPhase, FlightStatus, RelativeDay, TimeSource};
+use serde::Deserialize;
+
+/// Language for status names and the fixed UI labels
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Language {
+    #[default]
+    En,
+    Es,
+}
+
+/// Display strings for one language, loaded from the JSON tables embedded from `locales/`
+#[derive(Debug, Clone, Deserialize)]
+pub struct Translations {
+    pub flight: String,
+    pub status: String,
+    pub estimated_arrival: String,
+    actual_arrival: String,
+    scheduled_arrival: String,
+    on_time: String,
+    delayed: String,
+    cancelled: String,
+    en_route: String,
+    early: String,
+    pub arrival_delay_since: String,
+    pre_departure: String,
+    taxiing: String,
+    climbing: String,
+    cruising: String,
+    descending: String,
+    landed: String,
+    at_gate: String,
+    yesterday: String,
+    today: String,
+    tomorrow: String,
+    pub eta: String,
+    pub progress: String,
+    pub gate: String,
+    pub delay: String,
+    /// Under the gate tile, whose gate it shows
+    pub arrival_gate: String,
+    pub departure_gate: String,
+    delay_worsening: String,
+    recovering: String,
+    stable: String,
+    pub legs: String,
+    pub scheduled_departure: String,
+    pub flight_was_cancelled: String,
+    pub rebook_or_refund: String,
+    pub arrives: String,
+    /// Labels the time on the local clock among the airports' times
+    pub local: String,
+    /// Noted beside the end of the flight path at the user's home airport
+    pub your_home_airport: String,
+}
+
+impl Translations {
+    pub fn for_language(language: Language) -> Self {
+        let table = match language {
+            Language::En => include_str!("../locales/en.json"),
+            Language::Es => include_str!("../locales/es.json"),
+        };
+        serde_json::from_str(table).expect("Embedded translation tables are valid")
+    }
+
+    pub fn status(&self, status: &FlightStatus) -> &str {
+        match status {
+            FlightStatus::OnTime => &self.on_time,
+            FlightStatus::Delayed => &self.delayed,
+            FlightStatus::Cancelled => &self.cancelled,
+            FlightStatus::EnRoute => &self.en_route,
+            FlightStatus::Early => &self.early,
+        }
+    }
+
+    /// Label for the arrival line, naming where the displayed time came from
+    pub fn arrival(&self, source: TimeSource) -> &str {
+        match source {
+            TimeSource::Actual => &self.actual_arrival,
+            TimeSource::Estimated => &self.estimated_arrival,
+            TimeSource::Scheduled => &self.scheduled_arrival,
+        }
+    }
+
+    pub fn phase(&self, phase: FlightPhase) -> &str {
+        match phase {
+            FlightPhase::PreDeparture => &self.pre_departure,
+            FlightPhase::Taxiing => &self.taxiing,
+            FlightPhase::Climb => &self.climbing,
+            FlightPhase::Cruise => &self.cruising,
+            FlightPhase::Descent => &self.descending,
+            FlightPhase::Landed => &self.landed,
+            FlightPhase::AtGate => &self.at_gate,
+        }
+    }
+
+    pub fn relative_day(&self, day: RelativeDay) -> &str {
+        match day {
+            RelativeDay::Yesterday => &self.yesterday,
+            RelativeDay::Today => &self.today,
+            RelativeDay::Tomorrow => &self.tomorrow,
+        }
+    }
+
+    pub fn trend(&self, trend: DelayTrend) -> &str {
+        match trend {
+            DelayTrend::Worsening => &self.delay_worsening,
+            DelayTrend::Recovering => &self.recovering,
+            DelayTrend::Stable => &self.stable,
+        }
+    }
+}
+
+impl Default for Translations {
+    fn default() -> Self {
+        Translations::for_language(Language::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_language_loads() {
+        for language in [Language::En, Language::Es] {
+            let translations = Translations::for_language(language);
+            assert!(!translations.flight.is_empty());
+            assert!(!translations.status(&FlightStatus::Early).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_english_matches_status_display() {
+        let translations = Translations::default();
+
+        for status in [
+            FlightStatus::OnTime,
+            FlightStatus::Delayed,
+            FlightStatus::Cancelled,
+            FlightStatus::EnRoute,
+            FlightStatus::Early,
+        ] {
+            assert_eq!(translations.status(&status), status.to_string());
+        }
+    }
+
+    #[test]
+    fn test_english_matches_phase_display() {
+        let translations = Translations::default();
+
+        for phase in [
+            FlightPhase::PreDeparture,
+            FlightPhase::Taxiing,
+            FlightPhase::Climb,
+            FlightPhase::Cruise,
+            FlightPhase::Descent,
+            FlightPhase::Landed,
+            FlightPhase::AtGate,
+        ] {
+            assert_eq!(translations.phase(phase), phase.to_string());
+        }
+    }
+
+    #[test]
+    fn test_english_matches_trend_display() {
+        let translations = Translations::default();
+
+        for trend in [
+            DelayTrend::Worsening,
+            DelayTrend::Recovering,
+            DelayTrend::Stable,
+        ] {
+            assert_eq!(translations.trend(trend), trend.to_string());
+        }
+    }
+
+    #[test]
+    fn test_spanish() {
+        let translations = Translations::for_language(Language::Es);
+
+        assert_eq!(translations.flight, "Vuelo");
+        assert_eq!(translations.estimated_arrival, "Llegada estimada");
+        assert_eq!(translations.arrival(TimeSource::Actual), "Llegada real");
+        assert_eq!(translations.status(&FlightStatus::Delayed), "Retrasado");
+        assert_eq!(translations.arrival_delay_since, "Retraso de llegada desde");
+        assert_eq!(translations.phase(FlightPhase::Cruise), "En crucero");
+        assert_eq!(translations.relative_day(RelativeDay::Tomorrow), "Mañana");
+        assert_eq!(translations.trend(DelayTrend::Stable), "Estable");
+        assert_eq!(translations.legs, "Tramos");
+        assert_eq!(
+            translations.flight_was_cancelled,
+            "Este vuelo fue cancelado"
+        );
+    }
+}
@@ -1,19 +1,328 @@
-use crate::flight_status::FlightStatusViewModel;
+use crate::airlines;
+use crate::big_text;
+use crate::connection::{ConnectionRisk, Layover};
+use crate::delay_history::DelayHistory;
+use crate::flight_status::{FlightPhase, FlightStatusViewModel, GateChange};
+use crate::translations::Translations;
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    text::{Line, Span, Text},
+    widgets::{
+        Bar, BarChart, BarGroup, Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table,
+        TableState,
+    },
 };
+use serde::{Deserialize, Serialize};
+
+/// Which form of the flight identifier to show as the flight number
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IdentFormat {
+    /// The ident as returned by FlightAware
+    #[default]
+    Raw,
+    /// Airline IATA code, e.g. AA100
+    Iata,
+    /// Airline ICAO code, e.g. AAL100
+    Icao,
+}
+
+impl IdentFormat {
+    /// The format after this one, for cycling with a key press
+    pub fn next(self) -> Self {
+        match self {
+            IdentFormat::Raw => IdentFormat::Iata,
+            IdentFormat::Iata => IdentFormat::Icao,
+            IdentFormat::Icao => IdentFormat::Raw,
+        }
+    }
+}
+
+/// How much room each flight gets on the board
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BoardDensity {
+    /// One line per flight, to fit as many flights on screen as possible
+    #[default]
+    Compact,
+    /// Three lines per flight, with a progress bar under the route
+    Spacious,
+}
+
+impl BoardDensity {
+    /// The other density, for toggling with a key press
+    pub fn toggled(self) -> Self {
+        match self {
+            BoardDensity::Compact => BoardDensity::Spacious,
+            BoardDensity::Spacious => BoardDensity::Compact,
+        }
+    }
+}
+
+/// Display preferences that don't come from the flight data
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+    /// Delays shorter than this many minutes keep the on-time color even when the status is Delayed
+    pub delay_color_threshold_minutes: i64,
+    pub ident_format: IdentFormat,
+    /// Leave out the flight path block and show progress as text under the arrival time
+    pub minimal: bool,
+    /// Widest the flight path block gets; on wider terminals it's centered at this width
+    pub max_path_width: u16,
+    /// Show every scheduled, estimated and actual time in a table instead of just the arrival
+    pub timetable: bool,
+    /// Flight number as the user entered it, to point out when another airline operates it
+    pub queried_ident: Option<String>,
+    /// Replace every glyph outside ASCII for terminals that can't show them
+    pub ascii: bool,
+    /// Convey status with bold, reverse and dim instead of color
+    pub mono: bool,
+    /// Status names and labels in the chosen language
+    pub translations: Translations,
+    /// Show the panel comparing scheduled and actual gate-to-gate time
+    pub block_times: bool,
+    /// Show the flight as a grid of large tiles when the terminal is big enough
+    pub dashboard: bool,
+    /// Rows per flight on the board
+    pub board_density: BoardDensity,
+    /// Invert the status block's colors to draw the eye to a status change that just happened
+    pub flash_status: bool,
+    /// Frames drawn so far, which pace the route ticker and the taxi wiggle; the caller
+    /// advances it on every draw
+    pub frame_count: usize,
+    /// Wiggle the airplane while it taxis; the caller also skips the status flash without it
+    pub animate: bool,
+    /// Show a placeholder instead of any flight number, for screenshots and demos
+    pub redact: bool,
+    /// Minutes before departure that boarding is assumed to start
+    pub boarding_offset_minutes: i64,
+    /// Show when the flight arrives as a clock time instead of counting down to it
+    pub eta_clock: bool,
+    /// Timezone for the arrival clock, the local one when None
+    pub eta_timezone: Option<chrono_tz::Tz>,
+    /// Airport code to mark as the user's home on the flight path
+    pub home_airport: Option<String>,
+    /// Show the airline's full name next to the flight number
+    pub airline_names: bool,
+    /// Draw the flight path as a column climbing from origin to destination when the terminal
+    /// is taller than it is wide
+    pub vertical_path: bool,
+    /// Rows the panels drawn under the flight status layout need, kept free when a vertical
+    /// flight path grows to fill the screen
+    pub below_status_rows: u16,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions {
+            delay_color_threshold_minutes: DEFAULT_DELAY_COLOR_THRESHOLD_MINUTES,
+            ident_format: IdentFormat::default(),
+            minimal: false,
+            max_path_width: DEFAULT_MAX_PATH_WIDTH,
+            timetable: false,
+            queried_ident: None,
+            ascii: false,
+            mono: false,
+            translations: Translations::default(),
+            block_times: false,
+            dashboard: false,
+            board_density: BoardDensity::default(),
+            flash_status: false,
+            frame_count: 0,
+            animate: true,
+            redact: false,
+            boarding_offset_minutes: DEFAULT_BOARDING_OFFSET_MINUTES,
+            eta_clock: false,
+            eta_timezone: None,
+            home_airport: None,
+            airline_names: false,
+            vertical_path: false,
+            below_status_rows: 0,
+        }
+    }
+}
+
+/// Widest the flight path block gets unless configured otherwise, so the airplane stays easy to
+/// spot on ultrawide terminals
+pub const DEFAULT_MAX_PATH_WIDTH: u16 = 80;
+
+/// Smallest delay, in minutes, colored as delayed unless configured otherwise
+pub const DEFAULT_DELAY_COLOR_THRESHOLD_MINUTES: i64 = 15;
+
+/// Minutes before departure that boarding is taken to start unless configured otherwise
+pub const DEFAULT_BOARDING_OFFSET_MINUTES: i64 = 40;
+
+/// Shown in place of every flight number when redacting
+pub const REDACTED_IDENT: &str = "FL###";
+
+/// A flight number as entered by the user, or the placeholder when redacting
+pub fn shown_ident<'a>(flight_number: &'a str, options: &RenderOptions) -> &'a str {
+    if options.redact {
+        REDACTED_IDENT
+    } else {
+        flight_number
+    }
+}
+
+/// Shown in place of every gate when redacting
+pub const REDACTED_GATE: &str = "Gate ##";
+
+/// The gate-change banner, with the placeholder in place of both gates when redacting
+pub fn gate_change_text(change: &GateChange, options: &RenderOptions) -> String {
+    if options.redact {
+        format!("Gate changed: {} → {}", REDACTED_GATE, REDACTED_GATE)
+    } else {
+        change.to_string()
+    }
+}
+
+/// The flight number to show for a flight, following the display preferences
+fn flight_ident<'a>(view_model: &'a FlightStatusViewModel, options: &RenderOptions) -> &'a str {
+    shown_ident(display_ident(view_model, options.ident_format), options)
+}
+
+/// The flight number in the preferred form, falling back to the raw ident when that form is absent
+pub fn display_ident(view_model: &FlightStatusViewModel, format: IdentFormat) -> &str {
+    let preferred = match format {
+        IdentFormat::Raw => None,
+        IdentFormat::Iata => view_model.ident_iata.as_deref(),
+        IdentFormat::Icao => view_model.ident_icao.as_deref(),
+    };
+    preferred.unwrap_or(&view_model.flight_number)
+}
+
+/// Blank rows kept around the flight status panels
+const LAYOUT_MARGIN: u16 = 2;
+
+/// A panel of the flight status layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    FlightNumber,
+    Status,
+    Arrival,
+    Cancelled,
+    FlightPath,
+    BlockTimes,
+    Connection,
+}
+
+/// Each panel in the flight status layout with its height, top to bottom
+/// Minimal mode drops the flight path and gives its progress line to the arrival block instead
+fn section_heights(
+    view_model: &FlightStatusViewModel,
+    has_connection: bool,
+    options: &RenderOptions,
+) -> Vec<(Section, u16)> {
+    let operated_by = options
+        .queried_ident
+        .as_deref()
+        .and_then(|queried_ident| view_model.operated_by(queried_ident));
+
+    // Flight number + codeshare operator + aircraft details
+    let flight_number_height =
+        3 + u16::from(operated_by.is_some()) + u16::from(details_text(view_model).is_some());
+    if view_model.status == crate::flight_status::FlightStatus::Cancelled {
+        // Status, then the cancellation notice in place of the arrival and progress panels
+        return vec![
+            (Section::FlightNumber, flight_number_height),
+            (Section::Status, 3),
+            (
+                Section::Cancelled,
+                2 + cancelled_lines(view_model, &options.translations).len() as u16,
+            ),
+        ];
+    }
+
+    let mut heights = vec![
+        (Section::FlightNumber, flight_number_height),
+        (Section::Status, 3),
+        // Estimated arrival or timetable
+        (
+            Section::Arrival,
+            if options.timetable { 6 } else { 3 } + u16::from(options.minimal),
+        ),
+    ];
+    if !options.minimal {
+        // Taller for airports + info + path
+        heights.push((Section::FlightPath, 6));
+    }
+    if options.block_times {
+        // Scheduled vs current block time bars
+        heights.push((Section::BlockTimes, 4));
+    }
+    if has_connection {
+        heights.push((Section::Connection, 3));
+    }
+    heights
+}
+
+/// Whether the flight path is drawn as a column: only when asked for and the area is taller
+/// than it is wide, and never in minimal mode where there's no path at all
+fn uses_vertical_path(area: ratatui::layout::Rect, options: &RenderOptions) -> bool {
+    options.vertical_path && !options.minimal && area.height > area.width
+}
+
+/// Whether the flight path grows to fill the height the rest of the layout leaves over
+fn is_path_growing(
+    area: ratatui::layout::Rect,
+    view_model: &FlightStatusViewModel,
+    options: &RenderOptions,
+) -> bool {
+    uses_vertical_path(area, options)
+        && view_model.status != crate::flight_status::FlightStatus::Cancelled
+}
+
+/// The operating airline's full name, or its code when the name isn't known
+fn airline_text(view_model: &FlightStatusViewModel) -> Option<&str> {
+    let codes = [
+        view_model.operator_icao.as_deref(),
+        view_model.operator_iata.as_deref(),
+    ];
+    codes
+        .iter()
+        .flatten()
+        .find_map(|code| airlines::airline_name(code))
+        .or(codes.into_iter().flatten().next())
+}
+
+/// Cabin seats and cruise altitude, whichever FlightAware has, on one line
+fn details_text(view_model: &FlightStatusViewModel) -> Option<String> {
+    let details: Vec<String> = [view_model.cabin_text(), view_model.cruise_altitude_text()]
+        .into_iter()
+        .flatten()
+        .collect();
+    (!details.is_empty()).then(|| details.join(" • "))
+}
+
+/// Rows needed to show the whole flight status layout, margins included
+pub fn flight_status_height(
+    view_model: &FlightStatusViewModel,
+    connection: Option<&FlightStatusViewModel>,
+    options: &RenderOptions,
+) -> u16 {
+    section_heights(view_model, connection.is_some(), options)
+        .iter()
+        .map(|(_, height)| height)
+        .sum::<u16>()
+        + 2 * LAYOUT_MARGIN
+}
 
 pub fn render_flight_status(
     frame: &mut Frame,
     view_model: &FlightStatusViewModel,
     alert_mode: bool,
+    connection: Option<&FlightStatusViewModel>,
+    options: &RenderOptions,
 ) {
     let area = frame.area();
 
+    if options.dashboard && fits_dashboard(area) {
+        render_dashboard(frame, view_model, alert_mode, options);
+        return;
+    }
+
     // Alert styling - use blinking red border when approaching landing
     let alert_style = if alert_mode {
         Style::default()
@@ -23,28 +332,92 @@ pub fn render_flight_status(
         Style::default()
     };
 
-    // Create layout with 4 rows for our 4 elements
+    // Create layout with 4 rows for our 4 elements, plus the connection panel when tracking one
+    // A vertical flight path takes whatever height the other panels, and those drawn under the
+    // layout, leave over
+    let vertical_path = is_path_growing(area, view_model, options);
+    let mut constraints: Vec<Constraint> =
+        section_heights(view_model, connection.is_some(), options)
+            .into_iter()
+            .map(|(section, height)| {
+                if vertical_path && section == Section::FlightPath {
+                    Constraint::Min(height)
+                } else {
+                    Constraint::Length(height)
+                }
+            })
+            .collect();
+    if vertical_path {
+        constraints.push(Constraint::Length(options.below_status_rows));
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .margin(2)
-        .constraints([
-            Constraint::Length(3), // Flight number
-            Constraint::Length(3), // Status
-            Constraint::Length(3), // Estimated arrival
-            Constraint::Length(6), // Flight path progress bar (taller for airports + info + path)
-        ])
+        .margin(LAYOUT_MARGIN)
+        .constraints(constraints)
         .split(area);
 
+    let operated_by = options
+        .queried_ident
+        .as_deref()
+        .and_then(|queried_ident| view_model.operated_by(queried_ident));
+
     // Flight Number - add alert styling
+    let ident = flight_ident(view_model, options);
     let flight_number_text = if alert_mode {
-        format!("Flight: {} ⚠️  LANDING SOON ⚠️", view_model.flight_number)
+        format!(
+            "{}: {} ⚠️  LANDING SOON ⚠️",
+            options.translations.flight, ident
+        )
+    } else {
+        format!("{}: {}", options.translations.flight, ident)
+    };
+    let mut flight_number_spans = vec![Span::raw(flight_number_text)];
+    if options.airline_names
+        && let Some(airline) = airline_text(view_model)
+    {
+        flight_number_spans.push(Span::raw(format!(" • {}", airline)));
+    }
+    // The callsign identifies the flight as much as its number, so it's hidden when redacting
+    if let Some(atc_ident) = view_model.atc_ident.as_deref().filter(|_| !options.redact) {
+        flight_number_spans.push(Span::raw(format!(" • Callsign {}", atc_ident)));
+    }
+    if view_model.foresight_predictions_available {
+        flight_number_spans.push(Span::styled(
+            " • Predictions available",
+            Style::default()
+                .fg(Color::DarkGray)
+                .remove_modifier(Modifier::BOLD),
+        ));
+    }
+    let mut flight_number_lines = vec![Line::from(flight_number_spans)];
+    if let Some(operated_by) = operated_by {
+        flight_number_lines.push(Line::styled(
+            operated_by,
+            Style::default()
+                .fg(Color::Yellow)
+                .remove_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(details) = details_text(view_model) {
+        flight_number_lines.push(Line::styled(
+            details,
+            Style::default()
+                .fg(Color::DarkGray)
+                .remove_modifier(Modifier::BOLD),
+        ));
+    }
+    // A double border while airborne signals that the flight is live right now
+    let border_type = if view_model.status == crate::flight_status::FlightStatus::EnRoute {
+        BorderType::Double
     } else {
-        format!("Flight: {}", view_model.flight_number)
+        BorderType::Plain
     };
-    let flight_number = Paragraph::new(flight_number_text)
+    let flight_number = Paragraph::new(flight_number_lines)
         .block(
             Block::default()
                 .borders(Borders::ALL)
+                .border_type(border_type)
                 .title("Flight Information")
                 .border_style(alert_style),
         )
@@ -58,84 +431,117 @@ pub fn render_flight_status(
     frame.render_widget(flight_number, chunks[0]);
 
     // Flight Status
-    let status_color = match view_model.status {
-        crate::flight_status::FlightStatus::OnTime => Color::Green,
-        crate::flight_status::FlightStatus::Delayed => Color::Yellow,
-        crate::flight_status::FlightStatus::Cancelled => Color::Red,
-        crate::flight_status::FlightStatus::EnRoute => Color::Blue,
+    let status_color = status_color(view_model, options);
+    let status_text = if view_model.is_overdue() {
+        format!(
+            "{}: {} (overdue)",
+            options.translations.status,
+            options.translations.status(&view_model.status)
+        )
+    } else {
+        format!(
+            "{}: {}",
+            options.translations.status,
+            options.translations.status(&view_model.status)
+        )
     };
-
-    let status_text = format!("Status: {}", view_model.status);
-    let status = Paragraph::new(status_text)
+    let mut status_spans = vec![Span::raw(status_text)];
+    // Before departure the boarding countdown says more than the phase would
+    let phase = view_model.phase();
+    if phase != crate::flight_status::FlightPhase::PreDeparture {
+        status_spans.push(Span::styled(
+            format!(" • {}", options.translations.phase(phase)),
+            Style::default()
+                .fg(Color::White)
+                .remove_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(boarding) = view_model.boarding_in(options.boarding_offset_minutes) {
+        status_spans.push(Span::styled(
+            format!(" • {}", boarding),
+            Style::default()
+                .fg(Color::White)
+                .remove_modifier(Modifier::BOLD),
+        ));
+    }
+    let status = Paragraph::new(Line::from(status_spans))
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(alert_style),
         )
-        .style(
+        .style(if options.flash_status {
             Style::default()
                 .fg(status_color)
-                .add_modifier(Modifier::BOLD),
-        );
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+        } else {
+            Style::default()
+                .fg(status_color)
+                .add_modifier(Modifier::BOLD)
+        });
     frame.render_widget(status, chunks[1]);
 
-    // Estimated Arrival Time
-    let arrival_time = view_model
-        .formatted_arrival_time()
-        .unwrap_or_else(|| "N/A".to_string());
-    let arrival_text = format!("Estimated Arrival: {}", arrival_time);
-    let arrival = Paragraph::new(arrival_text)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(alert_style),
-        )
-        .style(Style::default().fg(Color::White));
-    frame.render_widget(arrival, chunks[2]);
+    // A cancelled flight has no progress or arrival to show, so it gets its own layout
+    if view_model.status == crate::flight_status::FlightStatus::Cancelled {
+        render_cancelled(frame, chunks[2], view_model, alert_style, options);
+        return;
+    }
 
-    // Flight Path Progress Bar
-    render_flight_path(frame, chunks[3], view_model, alert_mode);
-}
+    // Estimated Arrival Time, or every time when the timetable is toggled on
+    if options.timetable {
+        render_timetable(frame, chunks[2], view_model, alert_style, options);
+    } else {
+        render_arrival(frame, chunks[2], view_model, alert_style, options);
+    }
 
-fn render_flight_path(
-    frame: &mut Frame,
-    area: ratatui::layout::Rect,
-    view_model: &FlightStatusViewModel,
-    alert_mode: bool,
-) {
-    let progress = view_model.progress_percentage();
+    // The remaining panels are each optional, in layout order
+    let mut optional_chunks = chunks[3..].iter().copied();
 
-    // Get airport codes, default to "???" if not available
-    let origin = view_model.origin_airport.as_deref().unwrap_or("???");
-    let destination = view_model.destination_airport.as_deref().unwrap_or("???");
+    // Flight Path Progress Bar
+    if !options.minimal
+        && let Some(area) = optional_chunks.next()
+    {
+        render_flight_path(frame, area, view_model, alert_mode, options);
+    }
 
-    // Calculate available width for the path (subtract borders and padding)
-    let available_width = area.width.saturating_sub(4) as usize; // 2 for borders, 2 for padding
+    if options.block_times
+        && let Some(area) = optional_chunks.next()
+    {
+        render_block_times(frame, area, view_model);
+    }
 
-    // Build the flight path visualization
-    let mut lines = vec![];
+    // Layover analysis against the onward flight
+    if let Some(outbound) = connection
+        && let Some(area) = optional_chunks.next()
+    {
+        render_connection(frame, area, view_model, outbound, options);
+    }
+}
 
-    // Line 1: Airport codes
-    let airport_line = format!(
-        "{:<width$}{:>width$}",
-        origin,
-        destination,
-        width = available_width / 2
-    );
-    lines.push(Line::from(Span::styled(
-        airport_line,
-        Style::default().fg(Color::White),
-    )));
+/// Smallest terminal the dashboard is drawn on; anything smaller gets the usual layout
+const DASHBOARD_MIN_WIDTH: u16 = 60;
+const DASHBOARD_MIN_HEIGHT: u16 = 16;
 
-    // Line 2: Progress info centered (percent and time remaining)
-    let progress_info = build_progress_info(view_model, available_width);
-    lines.push(progress_info);
+fn fits_dashboard(area: ratatui::layout::Rect) -> bool {
+    area.width >= DASHBOARD_MIN_WIDTH && area.height >= DASHBOARD_MIN_HEIGHT
+}
 
-    // Line 3: The flight path with airplane
-    let path = build_flight_path(available_width, progress);
-    lines.push(path);
+/// One stat on the dashboard: a label, the value in big type and an optional line of detail
+struct Tile {
+    label: String,
+    value: String,
+    detail: Option<String>,
+    color: Color,
+}
 
-    let alert_style = if alert_mode {
+/// The flight as a three by two grid of tiles, each stat large enough to read across a room
+fn render_dashboard(
+    frame: &mut Frame,
+    view_model: &FlightStatusViewModel,
+    alert_mode: bool,
+    options: &RenderOptions,
+) {
+    let border_style = if alert_mode {
         Style::default()
             .fg(Color::Red)
             .add_modifier(Modifier::BOLD | Modifier::RAPID_BLINK)
@@ -143,167 +549,4083 @@ fn render_flight_path(
         Style::default()
     };
 
-    let title = if alert_mode {
-        "⚠️  Flight Progress - LANDING SOON  ⚠️"
-    } else {
-        "Flight Progress"
+    let area = Layout::default()
+        .margin(LAYOUT_MARGIN)
+        .constraints([Constraint::Min(0)])
+        .split(frame.area())[0];
+    let rows = Layout::vertical([Constraint::Ratio(1, 2); 2]).split(area);
+    let cells = rows.iter().flat_map(|row| {
+        Layout::horizontal([Constraint::Ratio(1, 3); 3])
+            .split(*row)
+            .to_vec()
+    });
+
+    for (tile, cell) in dashboard_tiles(view_model, options, area.width / 3)
+        .into_iter()
+        .zip(cells)
+    {
+        render_tile(frame, cell, &tile, border_style, options);
+    }
+}
+
+fn dashboard_tiles(
+    view_model: &FlightStatusViewModel,
+    options: &RenderOptions,
+    tile_width: u16,
+) -> [Tile; 6] {
+    let status_color = status_color(view_model, options);
+    let phase = view_model.phase();
+    let progress = view_model.progress_percentage();
+    let bar_width = tile_width.saturating_sub(4) as usize;
+    let filled = render_fractional_bar(bar_width, progress);
+    let progress_bar = format!(
+        "{}{}",
+        filled,
+        "─".repeat(bar_width - filled.chars().count())
+    );
+
+    let eta = view_model
+        .arrival_time_with_source()
+        .and_then(|(arrival, _)| arrival.parse::<chrono::DateTime<chrono::Utc>>().ok())
+        .map(|arrival| match options.eta_timezone {
+            Some(timezone) => arrival.with_timezone(&timezone).format("%H:%M").to_string(),
+            None => arrival
+                .with_timezone(&chrono::Local)
+                .format("%H:%M")
+                .to_string(),
+        });
+
+    // The departure gate matters until the flight lands, the arrival gate after that
+    let (gate, gate_detail) = match view_model.arrival_gate_text() {
+        Some(arrival_gate) => (Some(arrival_gate), &options.translations.arrival_gate),
+        None => (
+            view_model.gate_origin.clone(),
+            &options.translations.departure_gate,
+        ),
     };
+    let gate = gate.map(|gate| {
+        if options.redact {
+            REDACTED_GATE.to_string()
+        } else {
+            gate
+        }
+    });
 
-    let paragraph = Paragraph::new(lines)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(title)
-                .border_style(alert_style),
-        )
-        .alignment(Alignment::Left);
+    let delay = match view_model.delay_minutes() {
+        Some(minutes) if minutes > 0 => format!("+{}m", minutes),
+        Some(minutes) if minutes < 0 => format!("{}m", minutes),
+        Some(_) => options
+            .translations
+            .status(&crate::flight_status::FlightStatus::OnTime)
+            .to_string(),
+        None => "—".to_string(),
+    };
 
-    frame.render_widget(paragraph, area);
+    [
+        Tile {
+            label: options.translations.flight.clone(),
+            value: flight_ident(view_model, options).to_string(),
+            detail: match (&view_model.origin_airport, &view_model.destination_airport) {
+                (Some(origin), Some(destination)) => Some(format!("{} → {}", origin, destination)),
+                _ => None,
+            },
+            color: Color::Cyan,
+        },
+        Tile {
+            label: options.translations.status.clone(),
+            value: options.translations.status(&view_model.status).to_string(),
+            detail: (phase != crate::flight_status::FlightPhase::PreDeparture)
+                .then(|| options.translations.phase(phase).to_string()),
+            color: status_color,
+        },
+        Tile {
+            label: options.translations.eta.clone(),
+            value: eta.unwrap_or_else(|| "—".to_string()),
+            detail: countdown_text(view_model, options, false),
+            color: Color::White,
+        },
+        Tile {
+            label: options.translations.progress.clone(),
+            value: format!("{:.0}%", progress),
+            detail: Some(progress_bar),
+            color: progress_color(progress),
+        },
+        Tile {
+            label: options.translations.gate.clone(),
+            value: gate.unwrap_or_else(|| "—".to_string()),
+            detail: Some(gate_detail.clone()),
+            color: Color::Yellow,
+        },
+        Tile {
+            label: options.translations.delay.clone(),
+            value: delay,
+            detail: None,
+            color: status_color,
+        },
+    ]
 }
 
-fn build_progress_info(view_model: &FlightStatusViewModel, width: usize) -> Line<'static> {
-    let progress = view_model.progress_percentage();
-    let time_remaining = view_model
-        .time_remaining()
-        .unwrap_or_else(|| "N/A".to_string());
+/// Draw a tile's value in big type when it fits, otherwise as plain bold text, centered with
+/// its detail underneath
+fn render_tile(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    tile: &Tile,
+    border_style: Style,
+    options: &RenderOptions,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(tile.label.as_str())
+        .border_style(border_style);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
 
-    let info_text = format!("{:.0}% • {}", progress, time_remaining);
-    let padding = (width.saturating_sub(info_text.len())) / 2;
+    let value_style = Style::default().fg(tile.color).add_modifier(Modifier::BOLD);
+    let detail_rows = u16::from(tile.detail.is_some());
+    // The ASCII fallback flattens half blocks into solid ones, so big glyphs are only used with
+    // full glyph support
+    let big = big_text::render(&tile.value).filter(|rows| {
+        !options.ascii
+            && rows[0].chars().count() <= inner.width as usize
+            && big_text::HEIGHT as u16 + detail_rows <= inner.height
+    });
 
-    let centered_text = format!("{:padding$}{}", "", info_text, padding = padding);
+    let mut lines: Vec<Line> = match big {
+        Some(rows) => rows
+            .into_iter()
+            .map(|row| Line::styled(row, value_style))
+            .collect(),
+        None => vec![Line::styled(tile.value.clone(), value_style)],
+    };
+    if let Some(detail) = &tile.detail {
+        lines.push(Line::styled(
+            detail.clone(),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
 
-    Line::from(Span::styled(
-        centered_text,
-        Style::default()
-            .fg(Color::Cyan)
-            .add_modifier(Modifier::BOLD),
+    let height = lines.len() as u16;
+    frame.render_widget(
+        Paragraph::new(lines).alignment(Alignment::Center),
+        centered_rows(inner, height),
+    );
+}
+
+/// The cancellation notice, with the scheduled times FlightAware still has for reference
+fn cancelled_lines<'a>(
+    view_model: &FlightStatusViewModel,
+    translations: &'a Translations,
+) -> Vec<Line<'a>> {
+    let scheduled = [
+        (
+            translations.scheduled_departure.as_str(),
+            view_model.scheduled_departure.as_deref(),
+        ),
+        (
+            translations.arrival(crate::flight_status::TimeSource::Scheduled),
+            view_model.scheduled_arrival.as_deref(),
+        ),
+    ];
+
+    std::iter::once(Line::styled(
+        translations.flight_was_cancelled.as_str(),
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
     ))
+    .chain(scheduled.into_iter().filter_map(|(label, time)| {
+        time.map(|time| {
+            Line::styled(
+                format!("{}: {}", label, timetable_time(Some(time))),
+                Style::default().fg(Color::White),
+            )
+        })
+    }))
+    .chain(std::iter::once(Line::styled(
+        translations.rebook_or_refund.as_str(),
+        Style::default().fg(Color::DarkGray),
+    )))
+    .collect()
 }
 
-fn build_flight_path(width: usize, progress: f64) -> Line<'static> {
-    if width < 10 {
-        return Line::from("");
-    }
+fn render_cancelled(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    view_model: &FlightStatusViewModel,
+    alert_style: Style,
+    options: &RenderOptions,
+) {
+    let translations = &options.translations;
+    let cancelled = Paragraph::new(cancelled_lines(view_model, translations)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(translations.status(&crate::flight_status::FlightStatus::Cancelled))
+            .border_style(alert_style),
+    );
+    frame.render_widget(cancelled, area);
+}
 
-    // Calculate airplane position (0-100% maps to start-end of path)
-    let progress_clamped = progress.clamp(0.0, 100.0);
-    let path_width = width.saturating_sub(2); // Leave room for dots at each end
-    let airplane_pos = ((path_width as f64 * progress_clamped / 100.0).round() as usize)
-        .min(path_width.saturating_sub(1));
+/// Width of the labels in front of the block time bars
+const BLOCK_LABEL_WIDTH: usize = 10;
+/// Width of the durations after the block time bars
+const BLOCK_DURATION_WIDTH: usize = 8;
 
-    let mut spans = vec![];
+/// Scheduled gate-to-gate time as a gray bar with the actual or estimated time under it,
+/// green when the flight is making up time and yellow when it's running long
+fn render_block_times(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    view_model: &FlightStatusViewModel,
+) {
+    let block = Block::default().borders(Borders::ALL).title("Block Time");
+    let width = block.inner(area).width as usize;
 
-    // Origin dot
-    spans.push(Span::styled("●", Style::default().fg(Color::White)));
+    let lines = match (
+        view_model.scheduled_block_time(),
+        view_model.current_block_time(),
+    ) {
+        (Some(scheduled), Some(current)) => {
+            let bar_width = width.saturating_sub(BLOCK_LABEL_WIDTH + BLOCK_DURATION_WIDTH);
+            let (scheduled_length, current_length) =
+                block_bar_lengths(scheduled, current, bar_width);
+            let current_color = if current <= scheduled {
+                Color::Green
+            } else {
+                Color::Yellow
+            };
 
-    // Build the path
-    for i in 0..path_width {
-        if i == airplane_pos {
-            // Airplane emoji or character
-            spans.push(Span::styled("✈", Style::default().fg(Color::Cyan)));
-        } else if i < airplane_pos {
-            // Trail behind the airplane
-            spans.push(Span::styled("─", Style::default().fg(Color::Yellow)));
-        } else {
-            // Empty path ahead
-            spans.push(Span::styled("─", Style::default().fg(Color::DarkGray)));
+            vec![
+                block_bar_line(
+                    "Scheduled",
+                    scheduled,
+                    scheduled_length,
+                    bar_width,
+                    Color::DarkGray,
+                ),
+                block_bar_line("Current", current, current_length, bar_width, current_color),
+            ]
         }
-    }
+        _ => vec![Line::styled(
+            "Gate times unavailable",
+            Style::default().fg(Color::DarkGray),
+        )],
+    };
 
-    // Destination dot
-    spans.push(Span::styled("●", Style::default().fg(Color::White)));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
 
-    Line::from(spans)
+/// Columns per bar in the delay history chart, wide enough for a label like -15m
+const DELAY_BAR_WIDTH: u16 = 5;
+const DELAY_BAR_GAP: u16 = 1;
+/// Fewest rows the delay history panel is drawn in: the borders, two bar rows and the label
+const DELAY_HISTORY_MIN_HEIGHT: u16 = 5;
+
+/// Rows the panels under the flight status layout need: the leg timeline, and the delay history
+/// when it's being charted and has samples
+pub fn below_status_rows(legs: &[FlightStatusViewModel], history: Option<&DelayHistory>) -> u16 {
+    let delay_history_rows = if history.is_some_and(|history| !history.is_empty()) {
+        DELAY_HISTORY_MIN_HEIGHT
+    } else {
+        0
+    };
+    leg_timeline_height(legs) + delay_history_rows
 }
 
-// Keep the old calculate_progress function for backwards compatibility in tests
-// but it's no longer used in the UI
-#[allow(dead_code)]
-fn calculate_progress(view_model: &FlightStatusViewModel) -> f64 {
-    // For now, return a default based on status
-    // In the future, we can calculate based on actual/estimated times and distance
-    match view_model.status {
-        crate::flight_status::FlightStatus::OnTime => {
-            // If we have actual departure but no actual arrival, assume 50% progress
-            if view_model.actual_departure.is_some() && view_model.actual_arrival.is_none() {
-                50.0
-            } else if view_model.actual_arrival.is_some() {
-                100.0
-            } else {
-                0.0
-            }
-        }
-        crate::flight_status::FlightStatus::EnRoute => 50.0,
-        crate::flight_status::FlightStatus::Cancelled => 0.0,
-        crate::flight_status::FlightStatus::Delayed => {
-            if view_model.actual_departure.is_some() {
-                50.0
-            } else {
-                0.0
-            }
-        }
+/// The space left under the flight status layout, `skip_rows` further down, inside the layout
+/// margin and above the footer row; a growing vertical flight path pushes it down to the
+/// `below_status_rows` kept free at the bottom
+/// None when it's shorter than `min_height` or the dashboard is showing instead of the layout
+fn below_status_area(
+    area: ratatui::layout::Rect,
+    view_model: &FlightStatusViewModel,
+    connection: Option<&FlightStatusViewModel>,
+    options: &RenderOptions,
+    skip_rows: u16,
+    min_height: u16,
+) -> Option<ratatui::layout::Rect> {
+    if options.dashboard && fits_dashboard(area) {
+        return None;
     }
+    let mut top = flight_status_height(view_model, connection, options) - LAYOUT_MARGIN;
+    if is_path_growing(area, view_model, options) {
+        top = top.max(
+            area.height
+                .saturating_sub(LAYOUT_MARGIN + options.below_status_rows),
+        );
+    }
+    let top = top + skip_rows;
+    let height = area.height.saturating_sub(top + 1);
+    if height < min_height || area.width <= 2 * LAYOUT_MARGIN {
+        return None;
+    }
+    Some(ratatui::layout::Rect {
+        x: area.x + LAYOUT_MARGIN,
+        y: area.y + top,
+        width: area.width - 2 * LAYOUT_MARGIN,
+        height,
+    })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::flight_status::FlightStatus;
-
-    #[test]
-    fn test_calculate_progress_scheduled() {
-        let vm = FlightStatusViewModel {
-            flight_number: "AA100".to_string(),
-            status: FlightStatus::OnTime,
-            scheduled_departure: Some("2025-11-16T10:00:00Z".to_string()),
-            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
-            estimated_departure: Some("2025-11-16T10:00:00Z".to_string()),
-            estimated_arrival: Some("2025-11-16T14:00:00Z".to_string()),
-            actual_departure: None,
-            actual_arrival: None,
-            progress_percent: Some(0),
-            origin_airport: None,
-            destination_airport: None,
-        };
+/// Rows the leg timeline takes under the flight status layout, none for a single-leg flight
+pub fn leg_timeline_height(legs: &[FlightStatusViewModel]) -> u16 {
+    if legs.len() > 1 { 3 } else { 0 }
+}
 
-        assert_eq!(calculate_progress(&vm), 0.0);
+/// Show every leg of a multi-leg flight in order under the flight status layout, each with its
+/// status, the tracked one highlighted; flown legs are dimmed
+pub fn render_leg_timeline(
+    frame: &mut Frame,
+    legs: &[FlightStatusViewModel],
+    view_model: &FlightStatusViewModel,
+    connection: Option<&FlightStatusViewModel>,
+    options: &RenderOptions,
+) {
+    let height = leg_timeline_height(legs);
+    if height == 0 {
+        return;
     }
+    let Some(area) = below_status_area(frame.area(), view_model, connection, options, 0, height)
+    else {
+        return;
+    };
 
-    #[test]
-    fn test_calculate_progress_enroute() {
-        let vm = FlightStatusViewModel {
+    let mut spans = Vec::new();
+    for (index, leg) in legs.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(" ── ", Style::default().fg(Color::DarkGray)));
+        }
+        let text = format!(
+            " {}→{} {} ",
+            leg.origin_airport.as_deref().unwrap_or("???"),
+            leg.destination_airport.as_deref().unwrap_or("???"),
+            options.translations.status(&leg.status)
+        );
+        let style = if leg.fa_flight_id.is_some() && leg.fa_flight_id == view_model.fa_flight_id {
+            Style::default()
+                .fg(status_color(leg, options))
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+        } else if leg.is_complete() {
+            Style::default().fg(Color::DarkGray)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        spans.push(Span::styled(text, style));
+    }
+
+    frame.render_widget(
+        Paragraph::new(Line::from(spans)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(options.translations.legs.as_str()),
+        ),
+        ratatui::layout::Rect { height, ..area },
+    );
+}
+
+/// Chart the delays seen so far in the space left under the flight status layout, `skip_rows`
+/// below it to make room for the leg timeline, newest on the right; skipped when there's no
+/// history yet, no room for it, or the dashboard is showing
+pub fn render_delay_history(
+    frame: &mut Frame,
+    history: &DelayHistory,
+    view_model: &FlightStatusViewModel,
+    connection: Option<&FlightStatusViewModel>,
+    options: &RenderOptions,
+    skip_rows: u16,
+) {
+    if history.is_empty() {
+        return;
+    }
+    let Some(panel) = below_status_area(
+        frame.area(),
+        view_model,
+        connection,
+        options,
+        skip_rows,
+        DELAY_HISTORY_MIN_HEIGHT,
+    ) else {
+        return;
+    };
+
+    // As many of the latest samples as fit across the panel
+    let fits = (panel.width.saturating_sub(2) / (DELAY_BAR_WIDTH + DELAY_BAR_GAP)).max(1) as usize;
+    let shown: Vec<_> = history
+        .samples()
+        .skip(history.samples().len().saturating_sub(fits))
+        .collect();
+    let bars: Vec<Bar> = shown
+        .iter()
+        .map(|sample| {
+            let color = if sample.delay_minutes >= options.delay_color_threshold_minutes {
+                Color::Red
+            } else {
+                Color::Green
+            };
+            // The minutes go under the bar rather than on it, so early arrivals, which have
+            // no bar, still show theirs
+            Bar::default()
+                .value(sample.delay_minutes.max(0) as u64)
+                .text_value(String::new())
+                .label(Line::from(format!("{}m", sample.delay_minutes)))
+                .style(Style::default().fg(color))
+        })
+        .collect();
+
+    let chart = BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "{} {}",
+            options.translations.arrival_delay_since,
+            shown[0].at.with_timezone(&chrono::Local).format("%H:%M")
+        )))
+        .bar_width(DELAY_BAR_WIDTH)
+        .bar_gap(DELAY_BAR_GAP)
+        .data(BarGroup::default().bars(&bars));
+    frame.render_widget(chart, panel);
+}
+
+/// Bar lengths for the scheduled and current block times, scaled so the longer one fills
+/// `width`
+fn block_bar_lengths(
+    scheduled: chrono::Duration,
+    current: chrono::Duration,
+    width: usize,
+) -> (usize, usize) {
+    let longest = scheduled.max(current).num_seconds();
+    if longest <= 0 {
+        return (0, 0);
+    }
+
+    let length = |duration: chrono::Duration| {
+        (duration.num_seconds().max(0) as f64 / longest as f64 * width as f64).round() as usize
+    };
+    (length(scheduled), length(current))
+}
+
+fn block_bar_line(
+    label: &'static str,
+    duration: chrono::Duration,
+    length: usize,
+    bar_width: usize,
+    color: Color,
+) -> Line<'static> {
+    let minutes = duration.num_minutes().max(0);
+
+    Line::from(vec![
+        Span::styled(
+            format!("{:<width$}", label, width = BLOCK_LABEL_WIDTH),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled("█".repeat(length), Style::default().fg(color)),
+        Span::raw(" ".repeat(bar_width - length)),
+        Span::styled(
+            format!(
+                "{:>width$}",
+                format!("{}h {:02}m", minutes / 60, minutes % 60),
+                width = BLOCK_DURATION_WIDTH
+            ),
+            Style::default().fg(Color::White),
+        ),
+    ])
+}
+
+/// The arrival time on the display clock, with the relative day in the display language
+fn arrival_time_text(
+    view_model: &FlightStatusViewModel,
+    options: &RenderOptions,
+) -> Option<String> {
+    view_model.localized_arrival_time(options.eta_timezone, |day| {
+        options.translations.relative_day(day).to_string()
+    })
+}
+
+fn render_arrival(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    view_model: &FlightStatusViewModel,
+    alert_style: Style,
+    options: &RenderOptions,
+) {
+    let mut arrival_time =
+        arrival_time_text(view_model, options).unwrap_or_else(|| "N/A".to_string());
+    if let Some(destination_time) = view_model.formatted_destination_arrival_time() {
+        arrival_time.push_str(&format!(" ({})", destination_time));
+    }
+    // Without any time the line keeps the estimate label, as that's what's awaited
+    let arrival_label = view_model
+        .arrival_time_with_source()
+        .map(|(_, source)| options.translations.arrival(source))
+        .unwrap_or(&options.translations.estimated_arrival);
+    let mut arrival_spans = vec![Span::raw(format!("{}: {}", arrival_label, arrival_time))];
+    if let Some(baggage) = view_model.baggage_claim_text() {
+        arrival_spans.push(Span::raw(" • "));
+        arrival_spans.push(Span::styled(
+            baggage,
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(diagram) = arrival_gate_diagram(view_model, options) {
+        arrival_spans.push(Span::raw(" • "));
+        arrival_spans.extend(diagram);
+    }
+    let mut arrival_lines = vec![Line::from(arrival_spans)];
+    if options.minimal {
+        arrival_lines.push(build_progress_info(view_model, 0, options));
+    }
+    let arrival = Paragraph::new(arrival_lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(alert_style),
+        )
+        .style(Style::default().fg(Color::White));
+    frame.render_widget(arrival, area);
+}
+
+/// A tiny picture of where the flight pulls in once it has landed, the destination's apron
+/// with the terminal and gate highlighted, e.g. "LAX ══[T2·B12]══"
+/// When redacting the gate and terminal give way to the placeholder
+fn arrival_gate_diagram(
+    view_model: &FlightStatusViewModel,
+    options: &RenderOptions,
+) -> Option<Vec<Span<'static>>> {
+    let gate = view_model.arrival_gate_text()?;
+    let gate = if options.redact {
+        REDACTED_GATE.to_string()
+    } else {
+        gate
+    };
+    let apron = Style::default().fg(Color::DarkGray);
+    Some(vec![
+        Span::styled(
+            format!(
+                "{} ══",
+                view_model.destination_airport.as_deref().unwrap_or("???")
+            ),
+            apron,
+        ),
+        Span::styled(
+            format!("[{}]", gate),
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("══", apron),
+    ])
+}
+
+/// Scheduled, estimated and actual times for departure and arrival, aligned in a table
+fn render_timetable(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    view_model: &FlightStatusViewModel,
+    alert_style: Style,
+    options: &RenderOptions,
+) {
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title("Timetable")
+        .border_style(alert_style);
+    if let Some(baggage) = view_model.baggage_claim_text() {
+        block = block.title_bottom(Line::styled(
+            baggage,
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [table_area, zones_area, progress_area] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Length(1),
+        Constraint::Min(0),
+    ])
+    .areas(inner);
+
+    let header = Row::new(["", "Scheduled", "Estimated", "Actual"]).style(
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    );
+    let row = |label: &'static str, times: [Option<&str>; 3]| {
+        Row::new(
+            std::iter::once(Cell::from(label).style(Style::default().fg(Color::Cyan))).chain(
+                times
+                    .into_iter()
+                    .map(|time| Cell::from(timetable_time(time))),
+            ),
+        )
+    };
+    let rows = [
+        row(
+            "Departure",
+            [
+                view_model.scheduled_departure.as_deref(),
+                view_model.estimated_departure.as_deref(),
+                view_model.actual_departure.as_deref(),
+            ],
+        ),
+        row(
+            "Arrival",
+            [
+                view_model.scheduled_arrival.as_deref(),
+                view_model.estimated_arrival.as_deref(),
+                view_model.actual_arrival.as_deref(),
+            ],
+        ),
+    ];
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(header)
+    .style(Style::default().fg(Color::White));
+    frame.render_widget(table, table_area);
+
+    if let Some(zones) = arrival_zones_text(view_model, &options.translations) {
+        frame.render_widget(
+            Paragraph::new(zones).style(Style::default().fg(Color::White)),
+            zones_area,
+        );
+    }
+
+    if options.minimal {
+        frame.render_widget(
+            Paragraph::new(build_progress_info(view_model, 0, options)),
+            progress_area,
+        );
+    }
+}
+
+/// The arrival across timezones, e.g. "Arrives 06:30 SEA / 23:30 NRT / 14:30 local"
+fn arrival_zones_text(
+    view_model: &FlightStatusViewModel,
+    translations: &Translations,
+) -> Option<String> {
+    let times = view_model.arrival_times_multi();
+    if times.is_empty() {
+        return None;
+    }
+
+    let times: Vec<String> = times
+        .into_iter()
+        .map(|(airport, time)| {
+            format!(
+                "{} {}",
+                time,
+                airport.as_deref().unwrap_or(&translations.local)
+            )
+        })
+        .collect();
+    Some(format!("{} {}", translations.arrives, times.join(" / ")))
+}
+
+/// A timetable cell: the time in the local timezone, or a dash when FlightAware doesn't have it
+fn timetable_time(time: Option<&str>) -> String {
+    time.and_then(|time| time.parse::<chrono::DateTime<chrono::Utc>>().ok())
+        .map(|time| {
+            time.with_timezone(&chrono::Local)
+                .format("%b %-d %-I:%M %p")
+                .to_string()
+        })
+        .unwrap_or_else(|| "—".to_string())
+}
+
+/// Color for a flight's status; minor delays below the threshold keep the on-time color
+fn status_color(view_model: &FlightStatusViewModel, options: &RenderOptions) -> Color {
+    match view_model.status {
+        crate::flight_status::FlightStatus::OnTime => Color::Green,
+        crate::flight_status::FlightStatus::Delayed
+            if view_model
+                .delay_minutes()
+                .is_some_and(|delay| delay < options.delay_color_threshold_minutes) =>
+        {
+            Color::Green
+        }
+        crate::flight_status::FlightStatus::Delayed => Color::Yellow,
+        crate::flight_status::FlightStatus::Cancelled => Color::Red,
+        crate::flight_status::FlightStatus::EnRoute => Color::Blue,
+        crate::flight_status::FlightStatus::Early => Color::LightGreen,
+    }
+}
+
+/// Render several tracked flights as a table, one row per flight
+/// Flights that haven't been fetched yet show as loading
+pub fn render_board(
+    frame: &mut Frame,
+    flights: &[(String, Option<FlightStatusViewModel>)],
+    selected: Option<usize>,
+    options: &RenderOptions,
+) {
+    let translations = &options.translations;
+    let header = Row::new([
+        translations.flight.as_str(),
+        translations.status.as_str(),
+        "Route",
+        "Arrival",
+        "Progress",
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    // Spacious rows carry a second line for the progress bar and a blank line after it
+    let sized = |row: Row<'static>| match options.board_density {
+        BoardDensity::Compact => row,
+        BoardDensity::Spacious => row.height(2).bottom_margin(1),
+    };
+
+    let rows = flights.iter().map(|(flight_number, view_model)| {
+        let Some(view_model) = view_model else {
+            return sized(Row::new([
+                Cell::from(shown_ident(flight_number, options).to_string()),
+                Cell::from("Loading…").style(Style::default().fg(Color::DarkGray)),
+            ]));
+        };
+
+        let route = format!(
+            "{} → {}",
+            view_model.origin_airport.as_deref().unwrap_or("???"),
+            view_model.destination_airport.as_deref().unwrap_or("???")
+        );
+        let progress = view_model.progress_percentage();
+        let route = match options.board_density {
+            BoardDensity::Compact => Text::from(route),
+            BoardDensity::Spacious => Text::from(vec![
+                Line::from(route),
+                board_progress_bar(progress, BOARD_ROUTE_WIDTH as usize),
+            ]),
+        };
+
+        sized(Row::new([
+            Cell::from(flight_ident(view_model, options).to_string()),
+            Cell::from(translations.status(&view_model.status).to_string())
+                .style(Style::default().fg(status_color(view_model, options))),
+            Cell::from(route),
+            Cell::from(arrival_time_text(view_model, options).unwrap_or_else(|| "N/A".to_string())),
+            Cell::from(format!("{:.0}%", progress))
+                .style(Style::default().fg(progress_color(progress))),
+        ]))
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(12),
+            Constraint::Length(BOARD_ROUTE_WIDTH),
+            Constraint::Min(10),
+            Constraint::Length(8),
+        ],
+    )
+    .header(header)
+    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Tracking {} flights", flights.len())),
+    );
+
+    // Rendering with the selection also scrolls the table to keep it in view
+    let mut state = TableState::default().with_selected(selected);
+    frame.render_stateful_widget(table, frame.area(), &mut state);
+}
+
+/// Width of the board's route column, which the spacious progress bar fills
+const BOARD_ROUTE_WIDTH: u16 = 14;
+
+/// A bar `width` cells wide, filled in proportion to the flight's progress
+fn board_progress_bar(progress: f64, width: usize) -> Line<'static> {
+    let filled = render_fractional_bar(width, progress);
+    let filled_cells = filled.chars().count();
+
+    Line::from(vec![
+        Span::styled(filled, Style::default().fg(progress_color(progress))),
+        Span::styled(
+            "─".repeat(width - filled_cells),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])
+}
+
+/// Left-aligned partial blocks, from one eighth of a cell up to seven eighths
+const EIGHTH_BLOCKS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// The filled part of a bar `width` cells wide at `progress` percent, in eighths of a cell:
+/// full blocks followed by a partial block for any remainder, so narrow bars still move
+/// smoothly
+fn render_fractional_bar(width: usize, progress: f64) -> String {
+    let eighths = (progress.clamp(0.0, 100.0) / 100.0 * (width * 8) as f64).round() as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    if let Some(partial) = (eighths % 8).checked_sub(1) {
+        bar.push(EIGHTH_BLOCKS[partial]);
+    }
+    bar
+}
+
+/// Render two alternative flights side by side to help choose between them
+/// A flight that FlightAware has no record of shows as not found in its column
+pub fn render_compare(
+    frame: &mut Frame,
+    flights: &[(String, Option<FlightStatusViewModel>)],
+    options: &RenderOptions,
+) {
+    let translations = &options.translations;
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(frame.area());
+
+    for ((flight_number, view_model), area) in flights.iter().zip(columns.iter()) {
+        let lines = match view_model {
+            Some(view_model) => {
+                let delay = match view_model.delay_minutes() {
+                    Some(minutes) if minutes > 0 => format!("+{}m", minutes),
+                    _ => translations
+                        .status(&crate::flight_status::FlightStatus::OnTime)
+                        .to_string(),
+                };
+                vec![
+                    Line::from(format!(
+                        "{} → {}",
+                        view_model.origin_airport.as_deref().unwrap_or("???"),
+                        view_model.destination_airport.as_deref().unwrap_or("???")
+                    )),
+                    Line::from(vec![
+                        Span::raw(format!("{}: ", translations.status)),
+                        Span::styled(
+                            translations.status(&view_model.status).to_string(),
+                            Style::default()
+                                .fg(status_color(view_model, options))
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                    ]),
+                    Line::from(format!(
+                        "ETA: {}",
+                        arrival_time_text(view_model, options).unwrap_or_else(|| "N/A".to_string())
+                    )),
+                    Line::from(format!("Delay: {}", delay)),
+                ]
+            }
+            None => vec![Line::styled("Not found", Style::default().fg(Color::Red))],
+        };
+
+        let title = view_model
+            .as_ref()
+            .map(|view_model| flight_ident(view_model, options).to_string())
+            .unwrap_or_else(|| shown_ident(flight_number, options).to_string());
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(Alignment::Center);
+        frame.render_widget(paragraph, *area);
+    }
+}
+
+/// Placeholder shown until the first successful fetch, with the most recent failure if any
+pub fn render_connecting(frame: &mut Frame, flight_number: &str, last_error: Option<&str>) {
+    let mut lines = vec![Line::styled(
+        format!("Connecting to FlightAware for {}…", flight_number),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    )];
+    if let Some(error) = last_error {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!("Last attempt: {}", error),
+            Style::default().fg(Color::Yellow),
+        ));
+        lines.push(Line::styled(
+            "Retrying in the background",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Flight Information"),
+        );
+
+    frame.render_widget(paragraph, centered_rows(frame.area(), 7));
+}
+
+/// Shown while the first lookup of the flight is in flight, before there is anything to track
+pub fn render_splash(frame: &mut Frame, flight_number: &str) {
+    let paragraph = Paragraph::new(vec![
+        Line::styled(
+            format!("Looking up {}…", flight_number),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Line::styled(
+            "Asking FlightAware for the latest data",
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::ALL).title("flui"));
+
+    frame.render_widget(paragraph, centered_rows(frame.area(), 4));
+}
+
+/// A full-width band of `height` rows in the vertical middle of `area`
+fn centered_rows(area: ratatui::layout::Rect, height: u16) -> ratatui::layout::Rect {
+    let height = height.min(area.height);
+    ratatui::layout::Rect {
+        y: area.y + (area.height - height) / 2,
+        height,
+        ..area
+    }
+}
+
+/// Draw a warning across the top row of the screen, over whatever is there
+pub fn render_banner(frame: &mut Frame, message: &str) {
+    let area = frame.area();
+    let row = ratatui::layout::Rect {
+        height: 1.min(area.height),
+        ..area
+    };
+    let banner = Paragraph::new(format!(" ⚠ {}", message)).style(
+        Style::default()
+            .fg(Color::White)
+            .bg(Color::Red)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    frame.render_widget(Clear, row);
+    frame.render_widget(banner, row);
+}
+
+/// Draw a predictive advisory across the bottom row of the screen
+pub fn render_advisory(frame: &mut Frame, message: &str) {
+    let area = frame.area();
+    let row = ratatui::layout::Rect {
+        y: area.bottom().saturating_sub(1),
+        height: 1.min(area.height),
+        ..area
+    };
+    let advisory = Paragraph::new(format!(" ⓘ {}", message)).style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::ITALIC),
+    );
+
+    frame.render_widget(Clear, row);
+    frame.render_widget(advisory, row);
+}
+
+/// Show how long ago the data was refreshed in the bottom-right corner, in red once it's stale,
+/// preceded by how long FlightAware took to answer when `latency` is given
+/// Returns the width the footer took
+pub fn render_footer(
+    frame: &mut Frame,
+    since_update: std::time::Duration,
+    stale: bool,
+    latency: Option<std::time::Duration>,
+) -> u16 {
+    let text = format!(
+        "{}updated {} ago ",
+        latency_prefix(latency),
+        format_age(since_update)
+    );
+    let style = if stale {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    render_corner(frame, text, style)
+}
+
+/// Replace the update age in the bottom-right corner once a completed flight is no longer polled
+/// Returns the width the footer took
+pub fn render_complete_footer(frame: &mut Frame) -> u16 {
+    render_corner(
+        frame,
+        "Flight complete — polling stopped ".to_string(),
+        Style::default().fg(Color::DarkGray),
+    )
+}
+
+/// Show the flight's FlightAware link in the bottom-left corner, left out when the terminal is
+/// too narrow to fit it beside the `footer_width` columns of footer on the right
+pub fn render_url_footer(frame: &mut Frame, url: &str, footer_width: u16) {
+    let area = frame.area();
+    let width = url.chars().count() as u16 + 1;
+    // A gap between the link and the footer on the right
+    let reserved = footer_width + 1;
+    if area.height == 0 || width + reserved > area.width {
+        return;
+    }
+    let corner = ratatui::layout::Rect {
+        x: area.x,
+        y: area.bottom() - 1,
+        width,
+        height: 1,
+    };
+
+    frame.render_widget(
+        Paragraph::new(format!(" {}", url)).style(Style::default().fg(Color::DarkGray)),
+        corner,
+    );
+}
+
+/// How long the latest flight request took, e.g. "API 240ms"
+fn latency_text(latency: std::time::Duration) -> String {
+    format!("API {}ms", latency.as_millis())
+}
+
+/// The latency ahead of the update age in the footer, or nothing when it isn't shown
+fn latency_prefix(latency: Option<std::time::Duration>) -> String {
+    latency
+        .map(|latency| format!("{} • ", latency_text(latency)))
+        .unwrap_or_default()
+}
+
+/// Draw `text` right-aligned on the bottom row, returning the width it took
+fn render_corner(frame: &mut Frame, text: String, style: Style) -> u16 {
+    let area = frame.area();
+    let width = (text.chars().count() as u16).min(area.width);
+    let corner = ratatui::layout::Rect {
+        x: area.right().saturating_sub(width),
+        y: area.bottom().saturating_sub(1),
+        width,
+        height: 1.min(area.height),
+    };
+
+    frame.render_widget(Paragraph::new(text).style(style), corner);
+    width
+}
+
+/// Compact age like "42s" or "3m"
+fn format_age(age: std::time::Duration) -> String {
+    let seconds = age.as_secs();
+    if seconds < 60 {
+        format!("{}s", seconds)
+    } else if seconds < 3600 {
+        format!("{}m", seconds / 60)
+    } else {
+        format!("{}h {}m", seconds / 3600, seconds % 3600 / 60)
+    }
+}
+
+/// Draw the key bindings in a box over the middle of the screen
+pub fn render_help(frame: &mut Frame, lines: &[String]) {
+    let area = frame.area();
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count() as u16 + 4)
+        .max()
+        .unwrap_or(0)
+        .max(20)
+        .min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup = ratatui::layout::Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let help = Paragraph::new(
+        lines
+            .iter()
+            .map(|line| Line::from(format!(" {}", line)))
+            .collect::<Vec<_>>(),
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Keys")
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(help, popup);
+}
+
+/// Show the raw API JSON for the current flight, scrolled down by `scroll` lines
+pub fn render_debug(
+    frame: &mut Frame,
+    raw_json: &str,
+    scroll: u16,
+    latency: Option<std::time::Duration>,
+) {
+    let line_count = raw_json.lines().count() as u16;
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title("Raw API JSON")
+        .border_style(Style::default().fg(Color::Magenta));
+    if let Some(latency) = latency {
+        block = block.title_bottom(Line::from(latency_text(latency)).right_aligned());
+    }
+    let debug = Paragraph::new(raw_json)
+        .block(block)
+        .scroll((scroll.min(line_count.saturating_sub(1)), 0));
+    frame.render_widget(debug, frame.area());
+}
+
+/// Render a single frame into an off-screen buffer and return its text content, without styling
+pub fn render_to_string(
+    view_model: &FlightStatusViewModel,
+    alert_mode: bool,
+    connection: Option<&FlightStatusViewModel>,
+    options: &RenderOptions,
+    width: u16,
+    height: u16,
+) -> String {
+    let backend = ratatui::backend::TestBackend::new(width, height);
+    let mut terminal =
+        ratatui::Terminal::new(backend).expect("In-memory terminal backend cannot fail");
+    terminal
+        .draw(|frame| {
+            render_flight_status(frame, view_model, alert_mode, connection, options);
+            finish_frame(frame.buffer_mut(), options);
+        })
+        .expect("In-memory terminal backend cannot fail");
+
+    buffer_text(terminal.backend().buffer())
+}
+
+/// Adapt a fully drawn frame to the terminal: monochrome styles and ASCII glyphs when asked for
+pub fn finish_frame(buffer: &mut ratatui::buffer::Buffer, options: &RenderOptions) {
+    if options.mono {
+        to_mono(buffer);
+    }
+    if options.ascii {
+        to_ascii(buffer);
+    }
+}
+
+/// Replace every color in a drawn frame with the terminal's default, keeping what the colors
+/// meant as modifiers: red (cancelled, alerts) and backgrounds (banners) are reversed,
+/// yellow (delays, warnings) is bold and dark gray (hints) is dim
+/// Runs over the finished buffer so every panel and overlay is covered
+pub fn to_mono(buffer: &mut ratatui::buffer::Buffer) {
+    for cell in buffer.content.iter_mut() {
+        let mut modifier = match cell.fg {
+            Color::Red | Color::LightRed => Modifier::REVERSED,
+            Color::Yellow | Color::LightYellow => Modifier::BOLD,
+            Color::DarkGray => Modifier::DIM,
+            _ => Modifier::empty(),
+        };
+        if cell.bg != Color::Reset {
+            modifier |= Modifier::REVERSED;
+        }
+
+        cell.modifier |= modifier;
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+        cell.underline_color = Color::Reset;
+    }
+}
+
+/// Swap every non-ASCII glyph in a drawn frame for an ASCII stand-in: box borders become
+/// `+`, `-` and `|`, the airplane `>`, dots `o`, warnings `!` and bullets `*`
+/// Runs over the finished buffer so overlays and text from the API are covered too
+pub fn to_ascii(buffer: &mut ratatui::buffer::Buffer) {
+    for cell in buffer.content.iter_mut() {
+        let Some(glyph) = cell.symbol().chars().next() else {
+            continue;
+        };
+        if glyph.is_ascii() {
+            continue;
+        }
+
+        let replacement = match glyph {
+            '─' | '━' | '═' | '—' | '–' => '-',
+            '│' | '┃' | '║' => '|',
+            '┌' | '┐' | '└' | '┘' | '├' | '┤' | '┬' | '┴' | '┼' | '╭' | '╮' | '╯' | '╰' | '╔'
+            | '╗' | '╚' | '╝' => '+',
+            '✈' | '→' => '>',
+            '●' | '○' | '◔' | '◑' | '◕' => 'o',
+            '█' | '▏' | '▎' | '▍' | '▌' | '▋' | '▊' | '▉' => '#',
+            // Half blocks from big type and the tops of chart bars
+            '▀' | '▄' | '▅' | '▆' | '▇' => '#',
+            '▁' | '▂' | '▃' => '_',
+            '⚠' => '!',
+            '•' => '*',
+            '·' => '.',
+            '…' => '.',
+            'ⓘ' => 'i',
+            _ => '?',
+        };
+        cell.set_char(replacement);
+    }
+}
+
+/// Text content of a buffer, one line per row with trailing whitespace removed
+fn buffer_text(buffer: &ratatui::buffer::Buffer) -> String {
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| {
+            row.iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_connection(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    inbound: &FlightStatusViewModel,
+    outbound: &FlightStatusViewModel,
+    options: &RenderOptions,
+) {
+    let (text, color) = match Layover::between(inbound, outbound) {
+        Some(layover) => {
+            let color = match layover.risk {
+                ConnectionRisk::Comfortable => Color::Green,
+                ConnectionRisk::Tight => Color::Yellow,
+                ConnectionRisk::Missed => Color::Red,
+            };
+            (layover.to_string(), color)
+        }
+        None => ("Layover unknown".to_string(), Color::DarkGray),
+    };
+
+    let connection = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Connection to {}", flight_ident(outbound, options))),
+        )
+        .style(Style::default().fg(color).add_modifier(Modifier::BOLD));
+    frame.render_widget(connection, area);
+}
+
+fn render_flight_path(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    view_model: &FlightStatusViewModel,
+    alert_mode: bool,
+    options: &RenderOptions,
+) {
+    let area = capped_path_area(area, options.max_path_width);
+    let progress = view_model.progress_percentage();
+
+    // Get airport codes, default to "???" if not available
+    let origin = view_model.origin_airport.as_deref().unwrap_or("???");
+    let destination = view_model.destination_airport.as_deref().unwrap_or("???");
+
+    // Calculate available width for the path (subtract borders and padding)
+    let available_width = area.width.saturating_sub(4) as usize; // 2 for borders, 2 for padding
+
+    // Build the flight path visualization
+    let mut lines = vec![];
+
+    // Destination, origin and progress info take three of the rows inside the borders
+    let vertical_height = area.height.saturating_sub(5) as usize;
+    let vertical =
+        uses_vertical_path(frame.area(), options) && vertical_height >= VERTICAL_PATH_MIN_HEIGHT;
+
+    if view_model.origin_airport.is_none() && view_model.destination_airport.is_none() {
+        // With neither airport known there is no route to draw, so only the progress is shown
+        lines.push(build_progress_info(view_model, available_width, options));
+    } else if view_model.is_local_flight() {
+        // Training/ferry flights return to where they started, so a straight A → B path is meaningless
+        lines.push(centered_line(
+            &format!("Local flight ({})", origin),
+            available_width,
+            Style::default().fg(Color::White),
+        ));
+        lines.push(build_progress_info(view_model, available_width, options));
+        lines.push(centered_line(
+            local_progress_glyph(progress),
+            available_width,
+            Style::default().fg(Color::Cyan),
+        ));
+    } else if vertical {
+        // Progress info on top, then the destination down to the origin with the airplane
+        // climbing the column between them
+        lines.push(build_progress_info(view_model, available_width, options));
+        let airport_style = Style::default().fg(Color::White);
+        lines.push(centered_line(destination, available_width, airport_style));
+        lines.extend(
+            build_vertical_flight_path(vertical_height, progress)
+                .into_iter()
+                .map(Line::centered),
+        );
+        lines.push(centered_line(origin, available_width, airport_style));
+    } else {
+        // Line 0: Cities, scrolling when they don't fit
+        if let Some(route) = view_model.route_text() {
+            let style = Style::default().fg(Color::DarkGray);
+            if route.chars().count() > available_width {
+                lines.push(Line::styled(
+                    ticker_window(&route, available_width, options.frame_count),
+                    style,
+                ));
+            } else {
+                lines.push(centered_line(&route, available_width, style));
+            }
+        }
+
+        // Line 1: Airport codes
+        lines.push(Line::from(Span::styled(
+            build_airport_line_with_home(
+                origin,
+                destination,
+                available_width,
+                options.home_airport.as_deref(),
+                &options.translations.your_home_airport,
+            ),
+            Style::default().fg(Color::White),
+        )));
+
+        // Line 2: Progress info centered (percent and time remaining)
+        let progress_info = build_progress_info(view_model, available_width, options);
+        lines.push(progress_info);
+
+        // Line 3: The flight path with airplane, which wiggles in from whichever end of the
+        // path it's taxiing at
+        let wiggle = if is_taxi_wiggling(view_model, options) {
+            taxi_wiggle(options.frame_count) as isize
+        } else {
+            0
+        };
+        let taxi_offset = match view_model.phase() {
+            FlightPhase::Landed => -wiggle,
+            _ => wiggle,
+        };
+        let path = build_flight_path(available_width, progress, taxi_offset);
+        lines.push(path);
+    }
+
+    let alert_style = if alert_mode {
+        Style::default()
+            .fg(Color::Red)
+            .add_modifier(Modifier::BOLD | Modifier::RAPID_BLINK)
+    } else {
+        Style::default()
+    };
+
+    let title = if alert_mode {
+        "⚠️  Flight Progress - LANDING SOON  ⚠️"
+    } else {
+        "Flight Progress"
+    };
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(alert_style),
+        )
+        .alignment(Alignment::Left);
+
+    frame.render_widget(paragraph, area);
+}
+
+/// `area` narrowed to at most `max_width` columns and centered within it
+fn capped_path_area(area: ratatui::layout::Rect, max_width: u16) -> ratatui::layout::Rect {
+    let width = area.width.min(max_width);
+    ratatui::layout::Rect {
+        x: area.x + (area.width - width) / 2,
+        width,
+        ..area
+    }
+}
+
+/// Percentage points between reported and time-based progress beyond which the progress is
+/// marked as doubtful
+const PROGRESS_DISCREPANCY_WARNING_POINTS: f64 = 30.0;
+
+/// Time left until arrival, or with `clock` the time it arrives, with a landed flight that has
+/// yet to reach the gate named in the display language
+fn countdown_text(
+    view_model: &FlightStatusViewModel,
+    options: &RenderOptions,
+    clock: bool,
+) -> Option<String> {
+    if view_model.is_taxiing_in() {
+        Some(options.translations.phase(FlightPhase::Landed).to_string())
+    } else if clock {
+        view_model.arrival_clock(options.eta_timezone)
+    } else {
+        view_model.time_remaining()
+    }
+}
+
+fn build_progress_info(
+    view_model: &FlightStatusViewModel,
+    width: usize,
+    options: &RenderOptions,
+) -> Line<'static> {
+    let progress = view_model.progress_percentage();
+    let time_remaining =
+        countdown_text(view_model, options, options.eta_clock).unwrap_or_else(|| "N/A".to_string());
+
+    let percent_text = format!("{:.0}%", progress);
+    // Flag progress that's far off what the clock suggests, so it's taken with a grain of salt
+    let discrepancy_text = if view_model
+        .progress_discrepancy()
+        .is_some_and(|discrepancy| discrepancy > PROGRESS_DISCREPANCY_WARNING_POINTS)
+    {
+        " ⚠"
+    } else {
+        ""
+    };
+    let remaining_text = format!(" • {}", time_remaining);
+    let speed_text = view_model
+        .average_speed_kt()
+        .map(|speed| format!(" • Avg {:.0} kt so far", speed))
+        .unwrap_or_default();
+    let info_width = percent_text.chars().count()
+        + discrepancy_text.chars().count()
+        + remaining_text.chars().count()
+        + speed_text.chars().count();
+    let padding = (width.saturating_sub(info_width)) / 2;
+
+    Line::from(vec![
+        Span::raw(" ".repeat(padding)),
+        Span::styled(
+            percent_text,
+            Style::default()
+                .fg(progress_color(progress))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(discrepancy_text, Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            remaining_text,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(speed_text, Style::default().fg(Color::DarkGray)),
+    ])
+}
+
+/// Color for a completion percentage, blending red (0%) through yellow (50%) to green (100%)
+fn progress_color(pct: f64) -> Color {
+    const STOPS: [(f64, (u8, u8, u8)); 3] = [
+        (0.0, (255, 0, 0)),
+        (50.0, (255, 255, 0)),
+        (100.0, (0, 255, 0)),
+    ];
+
+    let pct = pct.clamp(0.0, 100.0);
+    let upper = STOPS
+        .iter()
+        .position(|(stop, _)| pct <= *stop)
+        .unwrap_or(STOPS.len() - 1)
+        .max(1);
+    let (start_pct, (r0, g0, b0)) = STOPS[upper - 1];
+    let (end_pct, (r1, g1, b1)) = STOPS[upper];
+
+    let t = (pct - start_pct) / (end_pct - start_pct);
+    let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+    Color::Rgb(lerp(r0, r1), lerp(g0, g1), lerp(b0, b1))
+}
+
+/// Longest airport code shown; FlightAware falls back to the 4 character ICAO code when an
+/// airport has no IATA code
+const AIRPORT_CODE_WIDTH: usize = 4;
+
+fn airport_code(code: &str) -> String {
+    code.chars().take(AIRPORT_CODE_WIDTH).collect()
+}
+
+/// Origin flush left and destination flush right, spaced by the codes' actual lengths so the
+/// destination lines up with the end of the flight path whether it's an IATA or ICAO code
+fn build_airport_line(origin: &str, destination: &str, width: usize) -> String {
+    spread_line(&airport_code(origin), &airport_code(destination), width)
+}
+
+/// Like `build_airport_line`, with `note` beside the code of the user's home airport when there
+/// is room for it
+fn build_airport_line_with_home(
+    origin: &str,
+    destination: &str,
+    width: usize,
+    home_airport: Option<&str>,
+    note: &str,
+) -> String {
+    let is_home = |code: &str| home_airport.is_some_and(|home| home.eq_ignore_ascii_case(code));
+    let mut origin_label = airport_code(origin);
+    let mut destination_label = airport_code(destination);
+    if is_home(origin) {
+        origin_label = format!("{} ({})", origin_label, note);
+    } else if is_home(destination) {
+        destination_label = format!("({}) {}", note, destination_label);
+    }
+
+    if origin_label.chars().count() + destination_label.chars().count() >= width {
+        return build_airport_line(origin, destination, width);
+    }
+    spread_line(&origin_label, &destination_label, width)
+}
+
+/// `left` flush left and `right` flush right across `width`, at least a space apart
+fn spread_line(left: &str, right: &str, width: usize) -> String {
+    let gap = width
+        .saturating_sub(left.chars().count() + right.chars().count())
+        .max(1);
+
+    format!("{}{}{}", left, " ".repeat(gap), right)
+}
+
+/// Whether the route above the flight path scrolls as a ticker on a terminal `width` columns
+/// wide, so every frame differs from the last
+pub fn is_ticker_scrolling(
+    view_model: &FlightStatusViewModel,
+    width: u16,
+    options: &RenderOptions,
+) -> bool {
+    let has_route = (view_model.origin_airport.is_some()
+        || view_model.destination_airport.is_some())
+        && !view_model.is_local_flight();
+    // The same width as render_flight_path works with, inside the layout margin
+    let available_width = width
+        .saturating_sub(2 * LAYOUT_MARGIN)
+        .min(options.max_path_width)
+        .saturating_sub(4) as usize;
+    !options.minimal
+        && has_route
+        && view_model
+            .route_text()
+            .is_some_and(|route| route.chars().count() > available_width)
+}
+
+/// Gap between the end of a scrolling ticker's text and its next pass
+const TICKER_GAP: &str = "   ";
+
+/// A `width`-character window onto `text` scrolled by `offset`, wrapping around to the start
+/// after a gap; text that fits is returned as is
+fn ticker_window(text: &str, width: usize, offset: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+
+    let looped: Vec<char> = text.chars().chain(TICKER_GAP.chars()).collect();
+    let start = offset % looped.len();
+    looped.iter().cycle().skip(start).take(width).collect()
+}
+
+fn centered_line(text: &str, width: usize, style: Style) -> Line<'static> {
+    let padding = width.saturating_sub(text.chars().count()) / 2;
+    Line::from(Span::styled(
+        format!("{:padding$}{}", "", text, padding = padding),
+        style,
+    ))
+}
+
+/// Circular progress indicator for flights that return to their origin,
+/// filling a quarter of the circle at a time
+fn local_progress_glyph(progress: f64) -> &'static str {
+    const GLYPHS: [&str; 5] = ["○", "◔", "◑", "◕", "●"];
+    let index = (progress.clamp(0.0, 100.0) / 25.0).round() as usize;
+    GLYPHS[index]
+}
+
+/// Frames the taxiing airplane spends on each step of its wiggle
+const TAXI_WIGGLE_FRAMES_PER_STEP: usize = 3;
+
+/// Columns in from the end of the path the wiggle steps through, out and back
+const TAXI_WIGGLE_STEPS: [usize; 4] = [0, 1, 2, 1];
+
+/// How many columns in from the end of the path a taxiing airplane is drawn on `frame_count`
+fn taxi_wiggle(frame_count: usize) -> usize {
+    TAXI_WIGGLE_STEPS[frame_count / TAXI_WIGGLE_FRAMES_PER_STEP % TAXI_WIGGLE_STEPS.len()]
+}
+
+/// Whether the airplane is moving on the ground, taxiing out or in, and drawn wiggling; the
+/// wiggle needs frames drawn at a steady rate like the ticker
+pub fn is_taxi_wiggling(view_model: &FlightStatusViewModel, options: &RenderOptions) -> bool {
+    options.animate
+        && !options.minimal
+        && matches!(
+            view_model.phase(),
+            FlightPhase::Taxiing | FlightPhase::Landed
+        )
+}
+
+/// The path with the airplane at `progress`, moved `taxi_offset` columns along it
+fn build_flight_path(width: usize, progress: f64, taxi_offset: isize) -> Line<'static> {
+    if width < 10 {
+        return Line::from("");
+    }
+
+    // Calculate airplane position across the whole line, dots included
+    // At 0% and 100% the airplane replaces the origin/destination dot; in between it
+    // always sits on the path itself
+    let last = width - 1;
+    let airplane_pos = if progress <= 0.0 {
+        0
+    } else if progress >= 100.0 {
+        last
+    } else {
+        ((last as f64 * progress / 100.0).round() as usize).clamp(1, last - 1)
+    };
+    let airplane_pos = airplane_pos.saturating_add_signed(taxi_offset).min(last);
+
+    let spans = (0..width)
+        .map(|i| {
+            if i == airplane_pos {
+                // Airplane emoji or character
+                Span::styled("✈", Style::default().fg(Color::Cyan))
+            } else if i == 0 || i == last {
+                // Origin and destination dots
+                Span::styled("●", Style::default().fg(Color::White))
+            } else if i < airplane_pos {
+                // Trail behind the airplane
+                Span::styled("─", Style::default().fg(Color::Yellow))
+            } else {
+                // Empty path ahead
+                Span::styled("─", Style::default().fg(Color::DarkGray))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Line::from(spans)
+}
+
+/// Fewest rows a vertical flight path is drawn with; shorter areas keep the horizontal one
+const VERTICAL_PATH_MIN_HEIGHT: usize = 3;
+
+/// The flight path as a column, one line per row from the destination at the top down to the
+/// origin at the bottom, with the airplane climbing it as the flight progresses
+fn build_vertical_flight_path(height: usize, progress: f64) -> Vec<Line<'static>> {
+    if height < VERTICAL_PATH_MIN_HEIGHT {
+        return Vec::new();
+    }
+
+    // Counted up from the origin, placed the same way as on the horizontal path
+    let last = height - 1;
+    let airplane_pos = if progress <= 0.0 {
+        0
+    } else if progress >= 100.0 {
+        last
+    } else {
+        ((last as f64 * progress / 100.0).round() as usize).clamp(1, last - 1)
+    };
+
+    (0..height)
+        .rev()
+        .map(|i| {
+            let span = if i == airplane_pos {
+                Span::styled("✈", Style::default().fg(Color::Cyan))
+            } else if i == 0 || i == last {
+                Span::styled("●", Style::default().fg(Color::White))
+            } else if i < airplane_pos {
+                Span::styled("│", Style::default().fg(Color::Yellow))
+            } else {
+                Span::styled("│", Style::default().fg(Color::DarkGray))
+            };
+            Line::from(span)
+        })
+        .collect()
+}
+
+// Keep the old calculate_progress function for backwards compatibility in tests
+// but it's no longer used in the UI
+#[allow(dead_code)]
+fn calculate_progress(view_model: &FlightStatusViewModel) -> f64 {
+    // For now, return a default based on status
+    // In the future, we can calculate based on actual/estimated times and distance
+    match view_model.status {
+        crate::flight_status::FlightStatus::OnTime | crate::flight_status::FlightStatus::Early => {
+            // If we have actual departure but no actual arrival, assume 50% progress
+            if view_model.actual_departure.is_some() && view_model.actual_arrival.is_none() {
+                50.0
+            } else if view_model.actual_arrival.is_some() {
+                100.0
+            } else {
+                0.0
+            }
+        }
+        crate::flight_status::FlightStatus::EnRoute => 50.0,
+        crate::flight_status::FlightStatus::Cancelled => 0.0,
+        crate::flight_status::FlightStatus::Delayed => {
+            if view_model.actual_departure.is_some() {
+                50.0
+            } else {
+                0.0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flight_status::FlightStatus;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_flight_status_height_fits_layout() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            ..Default::default()
+        };
+        let options = RenderOptions::default();
+
+        let height = flight_status_height(&vm, None, &options);
+        assert_eq!(height, 19);
+
+        // The bottom border of the flight path sits just above the bottom margin
+        let text = render_to_string(&vm, false, None, &options, 60, height);
+        let lines: Vec<&str> = text.split('\n').collect();
+        assert_eq!(lines.len(), usize::from(height));
+        assert!(lines[usize::from(height - 3)].trim_start().starts_with('└'));
+
+        let minimal = RenderOptions {
+            minimal: true,
+            ..Default::default()
+        };
+        assert_eq!(flight_status_height(&vm, Some(&vm), &minimal), 17);
+    }
+
+    #[test]
+    fn test_ascii_mode_output_is_pure_ascii() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            origin_airport: Some("LAX".to_string()),
+            destination_airport: Some("JFK".to_string()),
+            progress_percent: Some(50),
+            foresight_predictions_available: true,
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            ascii: true,
+            ..Default::default()
+        };
+
+        let unicode = render_to_string(&vm, true, None, &RenderOptions::default(), 60, 24);
+        assert!(!unicode.is_ascii());
+
+        let ascii = render_to_string(&vm, true, None, &options, 60, 24);
+        assert!(ascii.is_ascii());
+        assert!(ascii.contains("LANDING SOON"));
+        assert!(ascii.contains("o---"));
+        assert!(ascii.contains('>'));
+        assert!(ascii.contains("Flight Progress - LANDING SOON  !"));
+    }
+
+    #[test]
+    fn test_ascii_mode_covers_overlays() {
+        let backend = ratatui::backend::TestBackend::new(80, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                render_board(
+                    frame,
+                    &[("AA100".to_string(), None)],
+                    None,
+                    &RenderOptions::default(),
+                );
+                render_banner(frame, "API quota likely exceeded — upgrade");
+                render_advisory(frame, "Prediction: likely delayed");
+                to_ascii(frame.buffer_mut());
+            })
+            .unwrap();
+
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(text.is_ascii());
+        assert!(text.contains(" ! API quota likely exceeded - upgrade"));
+        assert!(text.contains(" i Prediction"));
+        assert!(text.contains("Loading."));
+    }
+
+    #[test]
+    fn test_render_codeshare_operator() {
+        let vm = FlightStatusViewModel {
+            flight_number: "BAW123".to_string(),
+            operator_icao: Some("BAW".to_string()),
+            operator_iata: Some("BA".to_string()),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            queried_ident: Some("AA8500".to_string()),
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 60, 24);
+        let lines: Vec<&str> = text.lines().collect();
+        let flight_row = lines
+            .iter()
+            .position(|line| line.contains("Flight: BAW123"))
+            .unwrap();
+        assert!(lines[flight_row + 1].contains("Operated by British Airways"));
+
+        let own_airline = RenderOptions {
+            queried_ident: Some("BA123".to_string()),
+            ..Default::default()
+        };
+        assert!(!render_to_string(&vm, false, None, &own_airline, 60, 24).contains("Operated by"));
+    }
+
+    #[test]
+    fn test_ticker_window() {
+        // Text that fits doesn't scroll
+        assert_eq!(ticker_window("SFO → LAX", 20, 7), "SFO → LAX");
+
+        assert_eq!(ticker_window("ABCDEFGH", 5, 0), "ABCDE");
+        assert_eq!(ticker_window("ABCDEFGH", 5, 2), "CDEFG");
+        // Wraps around to the start after the gap
+        assert_eq!(ticker_window("ABCDEFGH", 5, 6), "GH   ");
+        assert_eq!(ticker_window("ABCDEFGH", 5, 9), "  ABC");
+        // A full pass of the text and the gap lands back at the start
+        assert_eq!(ticker_window("ABCDEFGH", 5, 11), "ABCDE");
+        // Counts characters, not bytes
+        assert_eq!(ticker_window("A → B → C", 5, 1), " → B ");
+    }
+
+    #[test]
+    fn test_render_route_ticker_on_narrow_terminal() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            origin_airport: Some("SFO".to_string()),
+            destination_airport: Some("LAX".to_string()),
+            origin_city: Some("San Francisco".to_string()),
+            destination_city: Some("Los Angeles".to_string()),
+            ..Default::default()
+        };
+        let route = "San Francisco (SFO) → Los Angeles (LAX)";
+
+        let wide = render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24);
+        assert!(wide.contains(route));
+
+        let render_narrow = |frame_count| {
+            let options = RenderOptions {
+                frame_count,
+                ..Default::default()
+            };
+            render_to_string(&vm, false, None, &options, 30, 24)
+        };
+        // 22 columns are left inside the margins and borders
+        assert!(render_narrow(0).contains("San Francisco (SFO) → "));
+        assert!(!render_narrow(0).contains(route));
+        assert!(render_narrow(4).contains("Francisco (SFO) → Los "));
+
+        assert!(is_ticker_scrolling(&vm, 30, &RenderOptions::default()));
+        assert!(!is_ticker_scrolling(&vm, 60, &RenderOptions::default()));
+    }
+
+    #[test]
+    fn test_render_cabin_seats() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            seats_cabin_business: Some(20),
+            seats_cabin_coach: Some(150),
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24);
+        let lines: Vec<&str> = text.lines().collect();
+        let flight_row = lines
+            .iter()
+            .position(|line| line.contains("Flight: AA100"))
+            .unwrap();
+        assert!(lines[flight_row + 1].contains("Cabin: 20J / 150Y"));
+
+        let no_seats = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            ..Default::default()
+        };
+        let text = render_to_string(&no_seats, false, None, &RenderOptions::default(), 60, 24);
+        assert!(!text.contains("Cabin"));
+        assert!(!text.contains("Cruise"));
+
+        let with_altitude = FlightStatusViewModel {
+            filed_altitude: Some(350),
+            ..vm
+        };
+        let text = render_to_string(
+            &with_altitude,
+            false,
+            None,
+            &RenderOptions::default(),
+            60,
+            24,
+        );
+        assert!(text.contains("Cabin: 20J / 150Y • Cruise: FL350"));
+    }
+
+    #[test]
+    fn test_arrival_label_names_time_source() {
+        let scheduled = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            ..Default::default()
+        };
+        let estimated = FlightStatusViewModel {
+            estimated_arrival: Some("2025-11-16T14:15:00Z".to_string()),
+            ..scheduled.clone()
+        };
+        let landed = FlightStatusViewModel {
+            actual_arrival: Some("2025-11-16T14:10:00Z".to_string()),
+            ..estimated.clone()
+        };
+        let options = RenderOptions::default();
+
+        let text = |vm| render_to_string(vm, false, None, &options, 90, 30);
+        assert!(text(&scheduled).contains("Scheduled Arrival: Nov 16, 2025"));
+        assert!(text(&estimated).contains("Estimated Arrival: Nov 16, 2025"));
+        assert!(text(&landed).contains("Actual Arrival: Nov 16, 2025"));
+    }
+
+    #[test]
+    fn test_render_timetable() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            scheduled_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            estimated_departure: Some("2025-11-16T10:20:00Z".to_string()),
+            actual_departure: Some("2025-11-16T10:25:00Z".to_string()),
+            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            estimated_arrival: Some("2025-11-16T14:15:00Z".to_string()),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            timetable: true,
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 90, 30);
+        assert!(text.contains("Timetable"));
+        assert!(!text.contains("Estimated Arrival:"));
+
+        let line_with = |label: &str| {
+            text.lines()
+                .find(|line| line.contains(label))
+                .unwrap()
+                .to_string()
+        };
+        let header = line_with("Scheduled");
+        assert!(header.contains("Estimated"));
+        assert!(header.contains("Actual"));
+
+        let departure = line_with("Departure");
+        assert!(departure.contains(&timetable_time(vm.scheduled_departure.as_deref())));
+        assert!(departure.contains(&timetable_time(vm.actual_departure.as_deref())));
+
+        // No actual arrival yet
+        let arrival = line_with("Arrival");
+        assert!(arrival.contains(&timetable_time(vm.estimated_arrival.as_deref())));
+        assert!(arrival.trim_end_matches(['│', ' ']).ends_with('—'));
+    }
+
+    #[test]
+    fn test_render_timetable_arrival_zones() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            origin_airport: Some("SEA".to_string()),
+            destination_airport: Some("NRT".to_string()),
+            estimated_arrival: Some("2025-11-16T14:30:00Z".to_string()),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            timetable: true,
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 90, 30);
+        assert!(text.contains("Arrives 06:30 SEA / 23:30 NRT / "));
+        assert!(text.contains(" local"));
+    }
+
+    #[test]
+    fn test_timetable_time_missing() {
+        assert_eq!(timetable_time(None), "—");
+        assert_eq!(timetable_time(Some("not a time")), "—");
+    }
+
+    #[test]
+    fn test_airport_line_with_iata_codes() {
+        let line = build_airport_line("LAX", "JFK", 21);
+        assert_eq!(line, format!("LAX{}JFK", " ".repeat(15)));
+    }
+
+    #[test]
+    fn test_airport_line_with_icao_fallback() {
+        // An odd width used to leave the destination one column short of the path's end
+        let line = build_airport_line("KLAX", "EGLL", 21);
+        assert_eq!(line.chars().count(), 21);
+        assert!(line.starts_with("KLAX "));
+        assert!(line.ends_with(" EGLL"));
+
+        let mixed = build_airport_line("LAX", "EGLL", 21);
+        assert_eq!(mixed.chars().count(), 21);
+        assert!(mixed.ends_with(" EGLL"));
+    }
+
+    #[test]
+    fn test_airport_line_truncates_long_codes() {
+        assert_eq!(build_airport_line("ABCDEF", "XYZ", 12), "ABCD     XYZ");
+        // Codes never run together, even when the path is narrower than both
+        assert_eq!(build_airport_line("KLAX", "EGLL", 6), "KLAX EGLL");
+    }
+
+    #[test]
+    fn test_airport_line_notes_home_airport() {
+        let note = "your home airport";
+        assert_eq!(
+            build_airport_line_with_home("SEA", "JFK", 30, Some("sea"), note),
+            format!("SEA (your home airport){}JFK", " ".repeat(4))
+        );
+        assert_eq!(
+            build_airport_line_with_home("LAX", "SEA", 30, Some("SEA"), note),
+            format!("LAX{}(your home airport) SEA", " ".repeat(4))
+        );
+        assert_eq!(
+            build_airport_line_with_home("LAX", "JFK", 21, Some("SEA"), note),
+            build_airport_line("LAX", "JFK", 21)
+        );
+        // Too narrow for the note
+        assert_eq!(
+            build_airport_line_with_home("SEA", "JFK", 21, Some("SEA"), note),
+            build_airport_line("SEA", "JFK", 21)
+        );
+    }
+
+    fn delay_history(delays: &[i64]) -> DelayHistory {
+        let start = chrono::Utc
+            .with_ymd_and_hms(2025, 11, 16, 10, 0, 0)
+            .unwrap();
+        let mut history = DelayHistory::default();
+        for (index, &delay_minutes) in delays.iter().enumerate() {
+            history.push(crate::delay_history::DelaySample {
+                at: start + chrono::Duration::minutes(5 * index as i64),
+                delay_minutes,
+            });
+        }
+        history
+    }
+
+    fn render_delay_history_to_string(history: &DelayHistory, width: u16, height: u16) -> String {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            ..Default::default()
+        };
+        let options = RenderOptions::default();
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|frame| {
+                render_flight_status(frame, &vm, false, None, &options);
+                render_delay_history(frame, history, &vm, None, &options, 0);
+            })
+            .unwrap();
+        buffer_text(terminal.backend().buffer())
+    }
+
+    #[test]
+    fn test_render_delay_history() {
+        let history = delay_history(&[5, 20, 35, 10, -3]);
+        let text = render_delay_history_to_string(&history, 60, 40);
+
+        assert!(text.contains("Arrival Delay since"));
+        let values = text
+            .lines()
+            .find(|line| line.contains("35m"))
+            .expect("delay values drawn");
+        let positions: Vec<usize> = ["5m", "20m", "35m", "10m", "-3m"]
+            .iter()
+            .map(|value| values.find(value).unwrap())
+            .collect();
+        assert!(positions.is_sorted(), "oldest on the left: {}", values);
+        // Bars are drawn up the panel in proportion to the delay
+        let bar_rows = text.lines().filter(|line| line.contains('█')).count();
+        assert!(bar_rows > 3);
+
+        // Too short a terminal leaves the panel out, and so does an empty history
+        assert!(!render_delay_history_to_string(&history, 60, 20).contains("Arrival Delay"));
+        let empty = render_delay_history_to_string(&DelayHistory::default(), 60, 40);
+        assert!(!empty.contains("Arrival Delay"));
+    }
+
+    #[test]
+    fn test_render_delay_history_keeps_latest_that_fit() {
+        let delays: Vec<i64> = (1..=20).collect();
+        let text = render_delay_history_to_string(&delay_history(&delays), 30, 40);
+        // Four bars fit in the panel, so only the last four samples are drawn
+        assert!(text.contains("17m") && text.contains("20m"));
+        assert!(!text.contains("16m"));
+    }
+
+    #[test]
+    fn test_render_delay_history_ascii() {
+        let history = delay_history(&[5, 20, 35, 10, -3]);
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            ascii: true,
+            ..Default::default()
+        };
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(60, 40)).unwrap();
+        terminal
+            .draw(|frame| {
+                render_flight_status(frame, &vm, false, None, &options);
+                render_delay_history(frame, &history, &vm, None, &options, 0);
+                finish_frame(frame.buffer_mut(), &options);
+            })
+            .unwrap();
+        let text = buffer_text(terminal.backend().buffer());
+
+        let chart: Vec<&str> = text
+            .lines()
+            .skip_while(|line| !line.contains("Arrival Delay since"))
+            .collect();
+        assert!(chart.len() > 3);
+        assert!(chart.iter().any(|line| line.contains('#')));
+        assert!(chart.iter().all(|line| !line.contains('?')));
+    }
+
+    #[test]
+    fn test_vertical_path_leaves_room_for_panels_below() {
+        let legs = three_legs();
+        let tracked = FlightStatusViewModel {
+            progress_percent: Some(50),
+            ..legs[1].clone()
+        };
+        let history = delay_history(&[5, 20, 35]);
+        let options = RenderOptions {
+            vertical_path: true,
+            below_status_rows: below_status_rows(&legs, Some(&history)),
+            ..Default::default()
+        };
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(30, 60)).unwrap();
+        terminal
+            .draw(|frame| {
+                render_flight_status(frame, &tracked, false, None, &options);
+                render_leg_timeline(frame, &legs, &tracked, None, &options);
+                render_delay_history(
+                    frame,
+                    &history,
+                    &tracked,
+                    None,
+                    &options,
+                    leg_timeline_height(&legs),
+                );
+            })
+            .unwrap();
+        let text = buffer_text(terminal.backend().buffer());
+        let rows: Vec<&str> = text.lines().collect();
+
+        let airplane = rows.iter().position(|row| row.contains('✈')).unwrap();
+        let origin = rows
+            .iter()
+            .rposition(|row| row.contains("SFO") && !row.contains('→'))
+            .unwrap();
+        let legs_row = rows.iter().position(|row| row.contains("Legs")).unwrap();
+        let chart = rows
+            .iter()
+            .position(|row| row.contains("Arrival Delay"))
+            .unwrap();
+        assert!(airplane < origin);
+        assert!(origin < legs_row, "{}", text);
+        assert!(legs_row < chart, "{}", text);
+        // The path still grows into the rest of the screen
+        assert!(origin > 30, "{}", text);
+    }
+
+    fn three_legs() -> Vec<FlightStatusViewModel> {
+        let leg = |id: &str, from: &str, to: &str, status| FlightStatusViewModel {
+            flight_number: "WN100".to_string(),
+            fa_flight_id: Some(id.to_string()),
+            origin_airport: Some(from.to_string()),
+            destination_airport: Some(to.to_string()),
+            status,
+            ..Default::default()
+        };
+        vec![
+            FlightStatusViewModel {
+                actual_arrival: Some("2025-11-16T10:00:00Z".to_string()),
+                actual_gate_arrival: Some("2025-11-16T10:05:00Z".to_string()),
+                ..leg("leg-1", "SEA", "SFO", FlightStatus::OnTime)
+            },
+            leg("leg-2", "SFO", "LAX", FlightStatus::EnRoute),
+            leg("leg-3", "LAX", "SAN", FlightStatus::OnTime),
+        ]
+    }
+
+    #[test]
+    fn test_render_leg_timeline_highlights_tracked_leg() {
+        let legs = three_legs();
+        let tracked = legs[1].clone();
+        let options = RenderOptions::default();
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(90, 30)).unwrap();
+        terminal
+            .draw(|frame| {
+                render_flight_status(frame, &tracked, false, None, &options);
+                render_leg_timeline(frame, &legs, &tracked, None, &options);
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let text = buffer_text(buffer);
+
+        let timeline = text
+            .lines()
+            .find(|line| line.contains("SEA→SFO"))
+            .expect("timeline drawn");
+        let order: Vec<usize> = ["SEA→SFO", "SFO→LAX", "LAX→SAN"]
+            .iter()
+            .map(|route| timeline.find(route).unwrap())
+            .collect();
+        assert!(order.is_sorted());
+        assert!(timeline.contains("SFO→LAX En Route"));
+
+        let highlighted = cell_of(buffer, "SFO→LAX");
+        assert!(highlighted.modifier.contains(Modifier::REVERSED));
+        assert!(
+            !cell_of(buffer, "LAX→SAN")
+                .modifier
+                .contains(Modifier::REVERSED)
+        );
+        assert_eq!(cell_of(buffer, "SEA→SFO").fg, Color::DarkGray);
+    }
+
+    #[test]
+    fn test_leg_timeline_needs_several_legs() {
+        let legs = three_legs();
+        assert_eq!(leg_timeline_height(&legs), 3);
+        assert_eq!(leg_timeline_height(&legs[..1]), 0);
+        assert_eq!(leg_timeline_height(&[]), 0);
+    }
+
+    #[test]
+    fn test_render_airline_name() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AAL100".to_string(),
+            operator_icao: Some("AAL".to_string()),
+            operator_iata: Some("AA".to_string()),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            airline_names: true,
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 60, 24);
+        assert!(text.contains("Flight: AAL100 • American Airlines"));
+        let text = render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24);
+        assert!(!text.contains("American Airlines"));
+
+        // An airline missing from the table shows its code instead
+        let unknown = FlightStatusViewModel {
+            operator_icao: Some("ZZZ".to_string()),
+            operator_iata: None,
+            ..vm
+        };
+        let text = render_to_string(&unknown, false, None, &options, 60, 24);
+        assert!(text.contains("Flight: AAL100 • ZZZ"));
+    }
+
+    #[test]
+    fn test_render_home_airport_on_flight_path() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            origin_airport: Some("SEA".to_string()),
+            destination_airport: Some("JFK".to_string()),
+            progress_percent: Some(40),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            home_airport: Some("SEA".to_string()),
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 60, 24);
+        assert!(text.contains("SEA (your home airport)"));
+        let text = render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24);
+        assert!(!text.contains("your home airport"));
+
+        let spanish = RenderOptions {
+            translations: Translations::for_language(crate::translations::Language::Es),
+            ..options
+        };
+        let text = render_to_string(&vm, false, None, &spanish, 60, 24);
+        assert!(text.contains("SEA (tu aeropuerto)"));
+    }
+
+    #[test]
+    fn test_calculate_progress_scheduled() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::OnTime,
+            scheduled_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            estimated_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            estimated_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            actual_departure: None,
+            actual_arrival: None,
+            progress_percent: Some(0),
+            origin_airport: None,
+            destination_airport: None,
+            ..Default::default()
+        };
+
+        assert_eq!(calculate_progress(&vm), 0.0);
+    }
+
+    #[test]
+    fn test_calculate_progress_enroute() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            scheduled_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            estimated_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            estimated_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            actual_departure: Some("2025-11-16T10:05:00Z".to_string()),
+            actual_arrival: None,
+            progress_percent: Some(0),
+            origin_airport: None,
+            destination_airport: None,
+            ..Default::default()
+        };
+
+        assert_eq!(calculate_progress(&vm), 50.0);
+    }
+
+    #[test]
+    fn test_calculate_progress_completed() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::OnTime,
+            scheduled_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            estimated_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            estimated_arrival: Some("2025-11-16T14:00:00Z".to_string()),
+            actual_departure: Some("2025-11-16T10:05:00Z".to_string()),
+            actual_arrival: Some("2025-11-16T14:10:00Z".to_string()),
+            progress_percent: Some(100),
+            origin_airport: None,
+            destination_airport: None,
+            ..Default::default()
+        };
+
+        assert_eq!(calculate_progress(&vm), 100.0);
+    }
+
+    #[test]
+    fn test_render_connection_panel() {
+        let inbound = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            estimated_arrival: Some("2025-11-16T14:55:00Z".to_string()),
+            ..Default::default()
+        };
+        let outbound = FlightStatusViewModel {
+            flight_number: "UA200".to_string(),
+            estimated_departure: Some("2025-11-16T15:30:00Z".to_string()),
+            ..Default::default()
+        };
+
+        let backend = ratatui::backend::TestBackend::new(80, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                render_flight_status(
+                    frame,
+                    &inbound,
+                    false,
+                    Some(&outbound),
+                    &RenderOptions::default(),
+                )
+            })
+            .unwrap();
+
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(text.contains("Connection to UA200"));
+        assert!(text.contains("Layover 35m — tight!"));
+    }
+
+    #[test]
+    fn test_render_redacted_hides_flight_numbers() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AAL100".to_string(),
+            ident_iata: Some("AA100".to_string()),
+            ident_icao: Some("AAL100".to_string()),
+            status: FlightStatus::EnRoute,
+            progress_percent: Some(40),
+            origin_airport: Some("LAX".to_string()),
+            destination_airport: Some("JFK".to_string()),
+            ..Default::default()
+        };
+        let connection = FlightStatusViewModel {
+            flight_number: "UA200".to_string(),
+            ..Default::default()
+        };
+
+        for ident_format in [IdentFormat::Raw, IdentFormat::Iata, IdentFormat::Icao] {
+            let options = RenderOptions {
+                ident_format,
+                redact: true,
+                ..Default::default()
+            };
+            let text = render_to_string(&vm, false, Some(&connection), &options, 60, 30);
+
+            assert!(!text.contains("AA100"));
+            assert!(!text.contains("AAL100"));
+            assert!(!text.contains("UA200"));
+            assert!(text.contains("Flight: FL###"));
+            assert!(text.contains("Connection to FL###"));
+            // Everything but the idents is left as is
+            assert!(text.contains("LAX"));
+            assert!(text.contains("40%"));
+        }
+
+        let flights = vec![("DL300".to_string(), None), ("AA100".to_string(), Some(vm))];
+        let options = RenderOptions {
+            redact: true,
+            ..Default::default()
+        };
+        let backend = ratatui::backend::TestBackend::new(70, 8);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_board(frame, &flights, None, &options))
+            .unwrap();
+        let board = buffer_text(terminal.backend().buffer());
+        assert!(!board.contains("DL300"));
+        assert!(!board.contains("AA100"));
+        assert_eq!(board.matches(REDACTED_IDENT).count(), 2);
+    }
+
+    #[test]
+    fn test_gate_change_banner_redacted() {
+        let change = GateChange {
+            from: "B12".to_string(),
+            to: "C7".to_string(),
+        };
+        let redacted = RenderOptions {
+            redact: true,
+            ..Default::default()
+        };
+
+        let backend = ratatui::backend::TestBackend::new(60, 6);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_banner(frame, &gate_change_text(&change, &redacted)))
+            .unwrap();
+        let text = buffer_text(terminal.backend().buffer());
+
+        assert!(!text.contains("B12"));
+        assert!(!text.contains("C7"));
+        assert!(text.contains("Gate changed: Gate ## → Gate ##"));
+        assert_eq!(
+            gate_change_text(&change, &RenderOptions::default()),
+            "Gate changed: B12 → C7"
+        );
+    }
+
+    #[test]
+    fn test_render_mono_uses_no_color() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::Cancelled,
+            progress_percent: Some(40),
+            origin_airport: Some("LAX".to_string()),
+            destination_airport: Some("JFK".to_string()),
+            foresight_predictions_available: true,
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            mono: true,
+            ..Default::default()
+        };
+
+        let backend = ratatui::backend::TestBackend::new(80, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                render_flight_status(frame, &vm, false, None, &options);
+                render_banner(frame, "API quota likely exceeded");
+                finish_frame(frame.buffer_mut(), &options);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        for cell in buffer.content() {
+            assert_eq!(cell.fg, Color::Reset);
+            assert_eq!(cell.bg, Color::Reset);
+            assert_eq!(cell.underline_color, Color::Reset);
+        }
+        // Cancelled was red, so it's reversed; the red banner is too
+        assert!(
+            cell_of(buffer, "Status: Cancelled")
+                .modifier
+                .contains(Modifier::REVERSED)
+        );
+        assert!(
+            cell_of(buffer, "API quota")
+                .modifier
+                .contains(Modifier::REVERSED)
+        );
+        assert!(
+            cell_of(buffer, "Predictions available")
+                .modifier
+                .contains(Modifier::DIM)
+        );
+    }
+
+    #[test]
+    fn test_render_dashboard_grid() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            progress_percent: Some(45),
+            origin_airport: Some("JFK".to_string()),
+            destination_airport: Some("LAX".to_string()),
+            gate_origin: Some("B12".to_string()),
+            departure_delay: Some(25 * 60),
+            actual_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            dashboard: true,
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 100, 30);
+        let lines: Vec<&str> = text.lines().collect();
+        let row_of = |label: &str| {
+            lines
+                .iter()
+                .position(|line| line.contains(&format!("┌{}", label)))
+                .unwrap()
+        };
+        let column_of = |label: &str| lines[row_of(label)].find(&format!("┌{}", label)).unwrap();
+
+        // Three tiles across, two down
+        assert_eq!(row_of("Flight"), row_of("Status"));
+        assert_eq!(row_of("Flight"), row_of("ETA"));
+        assert_eq!(row_of("Progress"), row_of("Gate"));
+        assert_eq!(row_of("Progress"), row_of("Delay"));
+        assert!(row_of("Progress") > row_of("Flight"));
+        assert!(column_of("Flight") < column_of("Status"));
+        assert!(column_of("Status") < column_of("ETA"));
+        assert_eq!(column_of("Flight"), column_of("Progress"));
+
+        // Values are drawn in big type
+        let big = |value: &str| big_text::render(value).unwrap();
+        for value in ["AA100", "45%", "B12", "+25m"] {
+            let rows = big(value);
+            let top = lines
+                .iter()
+                .position(|line| line.contains(&rows[0]))
+                .unwrap();
+            assert!(lines[top + 1].contains(&rows[1]), "{}", value);
+            assert!(lines[top + 2].contains(&rows[2]), "{}", value);
+        }
+        assert!(text.contains("JFK → LAX"));
+        assert!(text.contains("Departure"));
+        assert!(!text.contains("Flight Information"));
+    }
+
+    #[test]
+    fn test_dashboard_collapses_on_small_terminals() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            dashboard: true,
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 50, 30);
+        assert!(text.contains("Flight Information"));
+        assert!(text.contains("Flight: AA100"));
+    }
+
+    #[test]
+    fn test_dashboard_tile_falls_back_to_plain_text() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            dashboard: true,
+            ascii: true,
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 100, 30);
+        let flight_row = text
+            .lines()
+            .position(|line| line.contains("+Flight"))
+            .unwrap();
+        assert!(
+            text.lines()
+                .skip(flight_row)
+                .any(|line| line.contains("AA100"))
+        );
+        assert!(!text.contains('?'));
+    }
+
+    #[test]
+    fn test_render_dashboard_ascii() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            progress_percent: Some(45),
+            origin_airport: Some("JFK".to_string()),
+            destination_airport: Some("LAX".to_string()),
+            gate_origin: Some("B12".to_string()),
+            departure_delay: Some(25 * 60),
+            actual_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            dashboard: true,
+            ascii: true,
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 100, 30);
+        assert!(text.contains("+Gate"));
+        assert!(text.contains("B12"));
+        assert!(text.contains("+25m"));
+        assert!(text.is_ascii());
+        assert!(!text.contains('?'));
+
+        // The big type's half blocks have an ASCII stand-in too
+        let rows = big_text::render("10").unwrap();
+        let backend = ratatui::backend::TestBackend::new(10, 3);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget(
+                    Paragraph::new(rows.into_iter().map(Line::from).collect::<Vec<_>>()),
+                    frame.area(),
+                );
+                to_ascii(frame.buffer_mut());
+            })
+            .unwrap();
+        let big = buffer_text(terminal.backend().buffer());
+        assert!(!big.contains('?'));
+        assert!(big.contains('#'));
+    }
+
+    #[test]
+    fn test_render_dashboard_redacts_gate() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            gate_origin: Some("B12".to_string()),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            dashboard: true,
+            redact: true,
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 100, 30);
+        assert!(!text.contains("B12"));
+        assert!(text.contains(REDACTED_GATE));
+    }
+
+    #[test]
+    fn test_render_cancelled_replaces_progress_panels() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::Cancelled,
+            progress_percent: Some(40),
+            origin_airport: Some("LAX".to_string()),
+            destination_airport: Some("JFK".to_string()),
+            scheduled_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            scheduled_arrival: Some("2025-11-16T18:00:00Z".to_string()),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            block_times: true,
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 80, 30);
+        assert!(text.contains("Status: Cancelled"));
+        assert!(text.contains("This flight was cancelled"));
+        assert!(text.contains("Scheduled Departure: "));
+        assert!(text.contains("Scheduled Arrival: "));
+        assert!(text.contains("rebook"));
+        // No flight path, arrival estimate or block times
+        assert!(!text.contains("LAX"));
+        assert!(!text.contains('✈'));
+        assert!(!text.contains("40%"));
+        assert!(!text.contains("Estimated Arrival"));
+        assert!(!text.contains("Block Time"));
+        assert_eq!(
+            flight_status_height(&vm, None, &options),
+            3 + 3 + 6 + 2 * LAYOUT_MARGIN
+        );
+    }
+
+    #[test]
+    fn test_render_cancelled_without_scheduled_times() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::Cancelled,
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &RenderOptions::default(), 80, 24);
+        assert!(text.contains("This flight was cancelled"));
+        assert!(!text.contains("Scheduled"));
+        assert_eq!(
+            flight_status_height(&vm, None, &RenderOptions::default()),
+            3 + 3 + 4 + 2 * LAYOUT_MARGIN
+        );
+    }
+
+    #[test]
+    fn test_render_mono_delayed_is_bold_not_reversed() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::Delayed,
+            departure_delay: Some(45 * 60),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            mono: true,
+            ..Default::default()
+        };
+
+        let backend = ratatui::backend::TestBackend::new(60, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                render_flight_status(frame, &vm, false, None, &options);
+                finish_frame(frame.buffer_mut(), &options);
+            })
+            .unwrap();
+
+        let status = cell_of(terminal.backend().buffer(), "Status: Delayed");
+        assert!(status.modifier.contains(Modifier::BOLD));
+        assert!(!status.modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_render_double_border_while_en_route() {
+        let render = |status| {
+            let vm = FlightStatusViewModel {
+                flight_number: "AA100".to_string(),
+                status,
+                ..Default::default()
+            };
+            render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24)
+        };
+
+        let en_route = render(FlightStatus::EnRoute);
+        let flight_row = en_route
+            .lines()
+            .find(|line| line.contains("Flight Information"))
+            .unwrap();
+        assert!(flight_row.contains("╔Flight Information═"));
+        // Only the flight information block is highlighted
+        assert_eq!(en_route.matches('╔').count(), 1);
+
+        for status in [
+            FlightStatus::OnTime,
+            FlightStatus::Delayed,
+            FlightStatus::Cancelled,
+        ] {
+            let text = render(status);
+            assert!(text.contains("┌Flight Information─"));
+            assert!(!text.contains('╔'));
+        }
+    }
+
+    #[test]
+    fn test_render_boarding_countdown_next_to_status() {
+        let departure = chrono::Utc::now() + chrono::Duration::hours(2);
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            estimated_departure: Some(departure.to_rfc3339()),
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24);
+        assert!(text.contains("Status: On Time • Boarding in 1h 19m"));
+
+        let departed = FlightStatusViewModel {
+            actual_departure: Some(chrono::Utc::now().to_rfc3339()),
+            ..vm
+        };
+        let text = render_to_string(&departed, false, None, &RenderOptions::default(), 60, 24);
+        assert!(!text.contains("Boarding"));
+    }
+
+    #[test]
+    fn test_render_callsign_next_to_flight_number() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AS824".to_string(),
+            atc_ident: Some("ASA824".to_string()),
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24);
+        assert!(text.contains("Flight: AS824 • Callsign ASA824"));
+
+        let redacted = RenderOptions {
+            redact: true,
+            ..Default::default()
+        };
+        let text = render_to_string(&vm, false, None, &redacted, 60, 24);
+        assert!(!text.contains("ASA824"));
+    }
+
+    #[test]
+    fn test_render_phase_next_to_status() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            actual_departure: Some("2025-11-16T10:05:00Z".to_string()),
+            progress_percent: Some(50),
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24);
+        assert!(text.contains("Status: En Route • Cruising"));
+
+        let scheduled = FlightStatusViewModel {
+            actual_departure: None,
+            ..vm
+        };
+        let text = render_to_string(&scheduled, false, None, &RenderOptions::default(), 60, 24);
+        assert!(!text.contains("Pre-departure"));
+    }
+
+    #[test]
+    fn test_render_flash_inverts_status() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            ..Default::default()
+        };
+        let render = |flash_status| {
+            let options = RenderOptions {
+                flash_status,
+                ..Default::default()
+            };
+            let backend = ratatui::backend::TestBackend::new(60, 24);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| render_flight_status(frame, &vm, false, None, &options))
+                .unwrap();
+            cell_of(terminal.backend().buffer(), "Status: En Route").modifier
+        };
+
+        assert!(render(true).contains(Modifier::REVERSED));
+        assert!(!render(false).contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_render_local_flight_skips_straight_path() {
+        let vm = FlightStatusViewModel {
+            flight_number: "N123AB".to_string(),
+            progress_percent: Some(50),
+            origin_airport: Some("SFO".to_string()),
+            destination_airport: Some("SFO".to_string()),
+            ..Default::default()
+        };
+
+        let backend = ratatui::backend::TestBackend::new(60, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_flight_status(frame, &vm, false, None, &RenderOptions::default()))
+            .unwrap();
+
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(text.contains("Local flight (SFO)"));
+        assert!(text.contains("◑"));
+        assert!(!text.contains("✈"));
+    }
+
+    #[test]
+    fn test_flight_path_width_is_capped_on_wide_terminals() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            progress_percent: Some(50),
+            origin_airport: Some("JFK".to_string()),
+            destination_airport: Some("LAX".to_string()),
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &RenderOptions::default(), 300, 30);
+        let path_row = text
+            .lines()
+            .find(|line| line.contains("Flight Progress"))
+            .unwrap();
+        // The block is 80 columns wide, centered in the 296 inside the layout margin
+        let start = path_row.find('┌').unwrap();
+        assert_eq!(path_row[start..].chars().count(), 80);
+        assert_eq!(path_row[..start].chars().count(), 2 + 108);
+        let airports = text.lines().find(|line| line.contains("JFK")).unwrap();
+        assert!(airports.trim().chars().count() <= 80);
+
+        // The other panels still span the screen
+        let flight_row = text
+            .lines()
+            .find(|line| line.contains("Flight Information"))
+            .unwrap();
+        assert_eq!(flight_row.trim().chars().count(), 296);
+
+        let options = RenderOptions {
+            max_path_width: 200,
+            ..Default::default()
+        };
+        let text = render_to_string(&vm, false, None, &options, 300, 30);
+        let path_row = text
+            .lines()
+            .find(|line| line.contains("Flight Progress"))
+            .unwrap();
+        assert_eq!(path_row.trim().chars().count(), 200);
+    }
+
+    #[test]
+    fn test_render_to_string_snapshot() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            progress_percent: Some(45),
+            origin_airport: Some("JFK".to_string()),
+            destination_airport: Some("LAX".to_string()),
+            ..Default::default()
+        };
+
+        let snapshot = render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24);
+        let lines: Vec<&str> = snapshot.split('\n').collect();
+
+        assert_eq!(lines.len(), 24);
+        assert!(lines.iter().all(|line| line.chars().count() <= 60));
+        assert!(lines.iter().all(|line| !line.ends_with(' ')));
+        assert!(snapshot.contains("Flight: AA100"));
+        assert!(snapshot.contains("Status: En Route"));
+        assert!(snapshot.contains("JFK"));
+        assert!(snapshot.contains("✈"));
+    }
+
+    /// Foreground color of the first cell where `text` starts in the rendered buffer
+    fn color_of(buffer: &ratatui::buffer::Buffer, text: &str) -> Color {
+        cell_of(buffer, text).fg
+    }
+
+    /// The cell holding the first character of `text`
+    fn cell_of<'a>(buffer: &'a ratatui::buffer::Buffer, text: &str) -> &'a ratatui::buffer::Cell {
+        let width = buffer.area.width as usize;
+        let rendered = buffer_text(buffer);
+        let (row, line) = rendered
+            .split('\n')
+            .enumerate()
+            .find(|(_, line)| line.contains(text))
+            .expect("text not rendered");
+        let column = line[..line.find(text).unwrap()].chars().count();
+        &buffer.content()[row * width + column]
+    }
+
+    fn delayed_status_color(delay_minutes: i64) -> Color {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::Delayed,
+            departure_delay: Some(delay_minutes * 60),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            delay_color_threshold_minutes: 15,
+            ..Default::default()
+        };
+
+        let backend = ratatui::backend::TestBackend::new(60, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_flight_status(frame, &vm, false, None, &options))
+            .unwrap();
+
+        color_of(terminal.backend().buffer(), "Status: Delayed")
+    }
+
+    #[test]
+    fn test_render_splash() {
+        let backend = ratatui::backend::TestBackend::new(80, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_splash(frame, "AA100"))
+            .unwrap();
+
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(text.contains("Looking up AA100…"));
+        assert!(text.contains("Asking FlightAware for the latest data"));
+        // Vertically centered: the box starts halfway down, less half its height
+        let lines: Vec<&str> = text.split('\n').collect();
+        assert!(lines[10].starts_with("┌flui"));
+        assert!(lines[11].contains("Looking up AA100…"));
+    }
+
+    #[test]
+    fn test_render_connecting_state() {
+        let backend = ratatui::backend::TestBackend::new(80, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_connecting(frame, "AA100", None))
+            .unwrap();
+
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(text.contains("Connecting to FlightAware for AA100…"));
+        assert!(!text.contains("Last attempt"));
+
+        terminal
+            .draw(|frame| {
+                render_connecting(
+                    frame,
+                    "AA100",
+                    Some("No flights found for AA100 — check the flight number"),
+                )
+            })
+            .unwrap();
+
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(text.contains("Connecting to FlightAware for AA100…"));
+        assert!(text.contains("Last attempt: No flights found for AA100"));
+        assert!(text.contains("Retrying in the background"));
+    }
+
+    #[test]
+    fn test_render_block_times() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            scheduled_gate_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            scheduled_gate_arrival: Some("2025-11-16T13:00:00Z".to_string()),
+            actual_gate_departure: Some("2025-11-16T10:00:00Z".to_string()),
+            estimated_gate_arrival: Some("2025-11-16T13:45:00Z".to_string()),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            block_times: true,
+            ..Default::default()
+        };
+
+        // 64 columns less margins, borders, labels and durations leaves 40 for the bars
+        let text = render_to_string(&vm, false, None, &options, 64, 30);
+        let bar = |label: &str| {
+            text.lines()
+                .find(|line| line.contains(label))
+                .unwrap()
+                .matches('█')
+                .count()
+        };
+
+        assert!(text.contains("Block Time"));
+        // 3h against 3h 45m: the longer current block time fills the width
+        assert_eq!(bar("Scheduled"), 32);
+        assert_eq!(bar("Current"), 40);
+        assert!(text.contains("3h 00m"));
+        assert!(text.contains("3h 45m"));
+    }
+
+    #[test]
+    fn test_block_bar_lengths() {
+        let minutes = chrono::Duration::minutes;
+
+        assert_eq!(block_bar_lengths(minutes(120), minutes(90), 40), (40, 30));
+        assert_eq!(block_bar_lengths(minutes(0), minutes(0), 40), (0, 0));
+    }
+
+    #[test]
+    fn test_block_times_panel_is_optional() {
+        let vm = FlightStatusViewModel::default();
+        let text = render_to_string(&vm, false, None, &RenderOptions::default(), 60, 30);
+        assert!(!text.contains("Block Time"));
+    }
+
+    #[test]
+    fn test_render_in_spanish() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::Delayed,
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            translations: Translations::for_language(crate::translations::Language::Es),
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 60, 24);
+        assert!(text.contains("Vuelo: AA100"));
+        assert!(text.contains("Estado: Retrasado"));
+        assert!(text.contains("Llegada estimada: N/A"));
+        assert!(!text.contains("Status:"));
+    }
+
+    #[test]
+    fn test_landed_countdown_in_spanish() {
+        let landed = (chrono::Utc::now() - chrono::Duration::minutes(5)).to_rfc3339();
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            origin_airport: Some("LAX".to_string()),
+            destination_airport: Some("JFK".to_string()),
+            actual_departure: Some(landed.clone()),
+            actual_arrival: Some(landed),
+            progress_percent: Some(100),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            translations: Translations::for_language(crate::translations::Language::Es),
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 80, 30);
+        let progress_line = text
+            .lines()
+            .find(|line| line.contains("100%"))
+            .expect("progress line drawn");
+        assert!(progress_line.contains("Aterrizado"));
+        assert!(!text.contains("Landed"));
+    }
+
+    #[test]
+    fn test_dashboard_in_spanish() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            gate_origin: Some("B12".to_string()),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            dashboard: true,
+            translations: Translations::for_language(crate::translations::Language::Es),
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &options, 100, 30);
+        for label in ["Vuelo", "Estado", "Progreso", "Puerta", "Retraso"] {
+            assert!(text.contains(&format!("┌{}", label)), "{}", label);
+        }
+        assert!(text.contains("Salida"));
+        assert!(!text.contains("Progress"));
+    }
+
+    #[test]
+    fn test_early_status_in_green() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::Early,
+            arrival_delay: Some(-20 * 60),
+            ..Default::default()
+        };
+
+        let backend = ratatui::backend::TestBackend::new(60, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_flight_status(frame, &vm, false, None, &RenderOptions::default()))
+            .unwrap();
+
+        assert_eq!(
+            color_of(terminal.backend().buffer(), "Status: Early"),
+            Color::LightGreen
+        );
+    }
+
+    #[test]
+    fn test_delay_color_threshold_boundary() {
+        assert_eq!(delayed_status_color(14), Color::Green);
+        assert_eq!(delayed_status_color(15), Color::Yellow);
+    }
+
+    #[test]
+    fn test_render_warns_when_progress_disagrees_with_clock() {
+        let now = chrono::Utc::now();
+        let vm = |progress_percent| FlightStatusViewModel {
             flight_number: "AA100".to_string(),
             status: FlightStatus::EnRoute,
-            scheduled_departure: Some("2025-11-16T10:00:00Z".to_string()),
-            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
-            estimated_departure: Some("2025-11-16T10:00:00Z".to_string()),
-            estimated_arrival: Some("2025-11-16T14:00:00Z".to_string()),
-            actual_departure: Some("2025-11-16T10:05:00Z".to_string()),
-            actual_arrival: None,
+            origin_airport: Some("LAX".to_string()),
+            destination_airport: Some("JFK".to_string()),
+            actual_departure: Some((now - chrono::Duration::hours(3)).to_rfc3339()),
+            estimated_arrival: Some((now + chrono::Duration::hours(1)).to_rfc3339()),
+            progress_percent: Some(progress_percent),
+            ..Default::default()
+        };
+        let text = |progress_percent| {
+            render_to_string(
+                &vm(progress_percent),
+                false,
+                None,
+                &RenderOptions::default(),
+                80,
+                30,
+            )
+        };
+
+        assert!(text(20).contains("20% ⚠ •"));
+        assert!(text(75).contains("75% •"));
+        assert!(!text(75).contains('⚠'));
+    }
+
+    #[test]
+    fn test_progress_info_as_countdown_or_clock() {
+        let arrival = chrono::Utc::now() + chrono::Duration::minutes(130);
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            origin_airport: Some("LAX".to_string()),
+            destination_airport: Some("JFK".to_string()),
+            estimated_arrival: Some(arrival.to_rfc3339()),
+            progress_percent: Some(45),
+            ..Default::default()
+        };
+
+        let countdown = render_to_string(&vm, false, None, &RenderOptions::default(), 80, 30);
+        assert!(countdown.contains("45% • 2h 9m to touchdown"));
+
+        let options = RenderOptions {
+            eta_clock: true,
+            eta_timezone: Some(chrono_tz::UTC),
+            ..Default::default()
+        };
+        let clock = render_to_string(&vm, false, None, &options, 80, 30);
+        assert!(clock.contains(&format!("45% • arrives {}", arrival.format("%H:%M"))));
+        assert!(!clock.contains("to touchdown"));
+    }
+
+    #[test]
+    fn test_flight_path_without_either_airport_shows_only_progress() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            status: FlightStatus::EnRoute,
+            progress_percent: Some(40),
+            ..Default::default()
+        };
+
+        let text = render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24);
+        assert!(text.contains("Flight Progress"));
+        assert!(text.contains("40% • N/A"));
+        assert!(!text.contains("???"));
+        assert!(!text.contains('✈'));
+
+        // One known airport is enough for the full path
+        let half_known = FlightStatusViewModel {
+            origin_airport: Some("LAX".to_string()),
+            ..vm
+        };
+        let text = render_to_string(&half_known, false, None, &RenderOptions::default(), 60, 24);
+        assert!(text.contains("LAX"));
+        assert!(text.contains("???"));
+        assert!(text.contains('✈'));
+    }
+
+    #[test]
+    fn test_minimal_mode_omits_flight_path() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            origin_airport: Some("LAX".to_string()),
+            destination_airport: Some("JFK".to_string()),
+            progress_percent: Some(40),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            minimal: true,
+            ..Default::default()
+        };
+
+        let full = render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24);
+        assert!(full.contains("Flight Progress"));
+
+        let minimal = render_to_string(&vm, false, None, &options, 60, 24);
+        assert!(!minimal.contains("Flight Progress"));
+        assert!(!minimal.contains('✈'));
+        // Progress is still shown, inside the arrival block
+        assert!(minimal.contains("Estimated Arrival: N/A"));
+        assert!(minimal.contains("40% • N/A"));
+        // No empty box is left where the path used to be
+        assert_eq!(minimal.matches('┌').count(), 3);
+    }
+
+    #[test]
+    fn test_render_board_lists_every_flight() {
+        let flights = vec![
+            (
+                "AA100".to_string(),
+                Some(FlightStatusViewModel {
+                    flight_number: "AA100".to_string(),
+                    status: FlightStatus::EnRoute,
+                    origin_airport: Some("LAX".to_string()),
+                    destination_airport: Some("JFK".to_string()),
+                    progress_percent: Some(40),
+                    ..Default::default()
+                }),
+            ),
+            ("UA200".to_string(), None),
+        ];
+
+        let backend = ratatui::backend::TestBackend::new(70, 8);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_board(frame, &flights, None, &RenderOptions::default()))
+            .unwrap();
+
+        let rendered = buffer_text(terminal.backend().buffer());
+        assert!(rendered.contains("Tracking 2 flights"));
+        assert!(rendered.contains("LAX → JFK"));
+        assert!(rendered.contains("40%"));
+        assert!(rendered.contains("UA200"));
+        assert!(rendered.contains("Loading…"));
+    }
+
+    fn board_of(count: usize) -> Vec<(String, Option<FlightStatusViewModel>)> {
+        (0..count)
+            .map(|index| {
+                let flight_number = format!("AA{}", 100 + index);
+                let view_model = FlightStatusViewModel {
+                    flight_number: flight_number.clone(),
+                    status: FlightStatus::EnRoute,
+                    origin_airport: Some("LAX".to_string()),
+                    destination_airport: Some("JFK".to_string()),
+                    progress_percent: Some(50),
+                    ..Default::default()
+                };
+                (flight_number, Some(view_model))
+            })
+            .collect()
+    }
+
+    fn render_board_text(
+        flights: &[(String, Option<FlightStatusViewModel>)],
+        density: BoardDensity,
+        height: u16,
+    ) -> String {
+        let options = RenderOptions {
+            board_density: density,
+            ..Default::default()
+        };
+        let backend = ratatui::backend::TestBackend::new(70, height);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_board(frame, flights, None, &options))
+            .unwrap();
+        buffer_text(terminal.backend().buffer())
+    }
+
+    #[test]
+    fn test_render_compact_board_fits_a_flight_per_line() {
+        let flights = board_of(30);
+
+        // Borders and the header take three lines
+        let text = render_board_text(&flights, BoardDensity::Compact, 33);
+        assert!(text.contains("AA100"));
+        assert!(text.contains("AA129"));
+        assert!(!text.contains('█'));
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[2].contains("AA100"));
+        assert!(lines[3].contains("AA101"));
+    }
+
+    #[test]
+    fn test_board_highlights_and_scrolls_to_selection() {
+        let flights = board_of(30);
+        let backend = ratatui::backend::TestBackend::new(70, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_board(frame, &flights, Some(20), &RenderOptions::default()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text = buffer_text(buffer);
+        assert!(!text.contains("AA100"));
+        assert!(
+            cell_of(buffer, "AA120")
+                .modifier
+                .contains(Modifier::REVERSED)
+        );
+        assert!(
+            !cell_of(buffer, "AA119")
+                .modifier
+                .contains(Modifier::REVERSED)
+        );
+    }
+
+    #[test]
+    fn test_render_fractional_bar() {
+        // 10 cells are 80 eighths, so each eighth of a cell is 1.25%
+        assert_eq!(render_fractional_bar(10, 0.0), "");
+        assert_eq!(render_fractional_bar(10, 1.25), "▏");
+        assert_eq!(render_fractional_bar(10, 5.0), "▌");
+        assert_eq!(render_fractional_bar(10, 8.75), "▉");
+        assert_eq!(render_fractional_bar(10, 10.0), "█");
+        assert_eq!(render_fractional_bar(10, 33.75), "███▍");
+        assert_eq!(render_fractional_bar(10, 57.5), "█████▊");
+        assert_eq!(render_fractional_bar(10, 100.0), "█".repeat(10));
+        assert_eq!(render_fractional_bar(10, 150.0), "█".repeat(10));
+    }
+
+    #[test]
+    fn test_board_progress_bar_keeps_its_width() {
+        let text: String = board_progress_bar(33.75, 10)
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(text, format!("███▍{}", "─".repeat(6)));
+    }
+
+    #[test]
+    fn test_render_spacious_board_adds_progress_bars() {
+        let flights = board_of(3);
+
+        let text = render_board_text(&flights, BoardDensity::Spacious, 14);
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[2].contains("AA100"));
+        assert!(lines[3].contains(&format!("{}{}", "█".repeat(7), "─".repeat(7))));
+        assert!(lines[5].contains("AA101"));
+        assert!(lines[8].contains("AA102"));
+        assert_eq!(text.matches('█').count(), 21);
+
+        // Fewer flights fit in the same space
+        let text = render_board_text(&board_of(30), BoardDensity::Spacious, 33);
+        assert!(text.contains("AA109"));
+        assert!(!text.contains("AA110"));
+    }
+
+    #[test]
+    fn test_render_compare_shows_flights_side_by_side() {
+        let flights = [
+            (
+                "AA100".to_string(),
+                Some(FlightStatusViewModel {
+                    flight_number: "AA100".to_string(),
+                    status: FlightStatus::OnTime,
+                    origin_airport: Some("LAX".to_string()),
+                    destination_airport: Some("JFK".to_string()),
+                    ..Default::default()
+                }),
+            ),
+            (
+                "UA200".to_string(),
+                Some(FlightStatusViewModel {
+                    flight_number: "UA200".to_string(),
+                    status: FlightStatus::Delayed,
+                    origin_airport: Some("SFO".to_string()),
+                    destination_airport: Some("EWR".to_string()),
+                    arrival_delay: Some(45 * 60),
+                    ..Default::default()
+                }),
+            ),
+        ];
+
+        let backend = ratatui::backend::TestBackend::new(80, 8);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_compare(frame, &flights, &RenderOptions::default()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rendered = buffer_text(buffer);
+        let title_row = rendered.lines().next().unwrap();
+        assert!(title_row.find("AA100").unwrap() < 40);
+        assert!(title_row.find("UA200").unwrap() >= 40);
+        assert!(rendered.contains("LAX → JFK"));
+        assert!(rendered.contains("SFO → EWR"));
+        assert!(rendered.contains("Delay: On Time"));
+        assert!(rendered.contains("Delay: +45m"));
+        assert_eq!(color_of(buffer, "Delayed"), Color::Yellow);
+    }
+
+    #[test]
+    fn test_render_compare_when_a_flight_is_not_found() {
+        let flights = [
+            (
+                "AA100".to_string(),
+                Some(FlightStatusViewModel {
+                    flight_number: "AA100".to_string(),
+                    ..Default::default()
+                }),
+            ),
+            ("ZZ999".to_string(), None),
+        ];
+
+        let backend = ratatui::backend::TestBackend::new(80, 8);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_compare(frame, &flights, &RenderOptions::default()))
+            .unwrap();
+
+        let rendered = buffer_text(terminal.backend().buffer());
+        assert!(rendered.contains("ZZ999"));
+        assert!(rendered.contains("Not found"));
+        assert!(rendered.contains("ETA: N/A"));
+    }
+
+    #[test]
+    fn test_render_predictions_hint_only_when_available() {
+        let render = |available| {
+            let vm = FlightStatusViewModel {
+                flight_number: "AA100".to_string(),
+                foresight_predictions_available: available,
+                ..Default::default()
+            };
+            render_to_string(&vm, false, None, &RenderOptions::default(), 60, 24)
+        };
+
+        assert!(render(true).contains("Flight: AA100 • Predictions available"));
+        assert!(!render(false).contains("Predictions available"));
+    }
+
+    fn ident_view_model(
+        ident_iata: Option<&str>,
+        ident_icao: Option<&str>,
+    ) -> FlightStatusViewModel {
+        FlightStatusViewModel {
+            flight_number: "AAL100".to_string(),
+            ident_iata: ident_iata.map(str::to_string),
+            ident_icao: ident_icao.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_display_ident_formats() {
+        let vm = ident_view_model(Some("AA100"), Some("AAL100"));
+
+        assert_eq!(display_ident(&vm, IdentFormat::Raw), "AAL100");
+        assert_eq!(display_ident(&vm, IdentFormat::Iata), "AA100");
+        assert_eq!(display_ident(&vm, IdentFormat::Icao), "AAL100");
+    }
+
+    #[test]
+    fn test_display_ident_falls_back_to_raw() {
+        let vm = ident_view_model(None, None);
+
+        assert_eq!(display_ident(&vm, IdentFormat::Iata), "AAL100");
+        assert_eq!(display_ident(&vm, IdentFormat::Icao), "AAL100");
+    }
+
+    #[test]
+    fn test_ident_format_cycles() {
+        assert_eq!(IdentFormat::Raw.next(), IdentFormat::Iata);
+        assert_eq!(IdentFormat::Iata.next(), IdentFormat::Icao);
+        assert_eq!(IdentFormat::Icao.next(), IdentFormat::Raw);
+    }
+
+    fn airplane_column(line: &Line) -> usize {
+        line.spans
+            .iter()
+            .position(|span| span.content == "✈")
+            .expect("airplane not drawn")
+    }
+
+    #[test]
+    fn test_flight_path_airplane_replaces_origin_dot_at_zero() {
+        let line = build_flight_path(20, 0.0, 0);
+
+        assert_eq!(line.spans.len(), 20);
+        assert_eq!(airplane_column(&line), 0);
+        assert_eq!(line.spans[19].content, "●");
+    }
+
+    #[test]
+    fn test_flight_path_airplane_replaces_destination_dot_at_hundred() {
+        let line = build_flight_path(20, 100.0, 0);
+
+        assert_eq!(line.spans.len(), 20);
+        assert_eq!(airplane_column(&line), 19);
+        assert_eq!(line.spans[0].content, "●");
+    }
+
+    #[test]
+    fn test_flight_path_airplane_stays_on_path_in_between() {
+        assert_eq!(airplane_column(&build_flight_path(20, 1.0, 0)), 1);
+        assert_eq!(airplane_column(&build_flight_path(20, 50.0, 0)), 10);
+        assert_eq!(airplane_column(&build_flight_path(20, 99.0, 0)), 18);
+    }
+
+    #[test]
+    fn test_taxi_wiggle_stays_within_bounds() {
+        let offsets: std::collections::BTreeSet<usize> = (0..1000).map(taxi_wiggle).collect();
+        assert_eq!(offsets.into_iter().collect::<Vec<_>>(), [0, 1, 2]);
+        // Starts at the end of the path and holds each step for a few frames
+        assert_eq!(taxi_wiggle(0), 0);
+        assert_eq!(taxi_wiggle(TAXI_WIGGLE_FRAMES_PER_STEP), 1);
+    }
+
+    #[test]
+    fn test_flight_path_taxi_offset_stays_on_path() {
+        let taxiing_out = build_flight_path(20, 0.0, 2);
+        assert_eq!(airplane_column(&taxiing_out), 2);
+        assert_eq!(taxiing_out.spans[0].content, "●");
+
+        assert_eq!(airplane_column(&build_flight_path(20, 100.0, -2)), 17);
+        assert_eq!(airplane_column(&build_flight_path(20, 100.0, 5)), 19);
+        assert_eq!(airplane_column(&build_flight_path(20, 0.0, -5)), 0);
+    }
+
+    fn airplane_row(lines: &[Line]) -> usize {
+        lines
+            .iter()
+            .position(|line| line.spans.iter().any(|span| span.content == "✈"))
+            .expect("airplane not drawn")
+    }
+
+    #[test]
+    fn test_vertical_flight_path_climbs_from_origin() {
+        let departing = build_vertical_flight_path(11, 0.0);
+        assert_eq!(departing.len(), 11);
+        assert_eq!(airplane_row(&departing), 10);
+        assert_eq!(departing[0].spans[0].content, "●");
+
+        assert_eq!(airplane_row(&build_vertical_flight_path(11, 50.0)), 5);
+
+        let arrived = build_vertical_flight_path(11, 100.0);
+        assert_eq!(airplane_row(&arrived), 0);
+        assert_eq!(arrived[10].spans[0].content, "●");
+
+        assert!(build_vertical_flight_path(2, 50.0).is_empty());
+    }
+
+    #[test]
+    fn test_render_vertical_path_only_when_tall() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            origin_airport: Some("SEA".to_string()),
+            destination_airport: Some("JFK".to_string()),
+            progress_percent: Some(50),
+            ..Default::default()
+        };
+        let options = RenderOptions {
+            vertical_path: true,
+            ..Default::default()
+        };
+
+        let tall = render_to_string(&vm, false, None, &options, 30, 40);
+        let rows: Vec<&str> = tall.lines().collect();
+        let destination = rows.iter().position(|row| row.contains("JFK")).unwrap();
+        let airplane = rows.iter().position(|row| row.contains('✈')).unwrap();
+        let origin = rows.iter().position(|row| row.contains("SEA")).unwrap();
+        assert!(destination < airplane && airplane < origin);
+        assert!(origin - destination > 6);
+        assert!(!tall.contains("●──"));
+
+        // A wide terminal keeps the horizontal path
+        let wide = render_to_string(&vm, false, None, &options, 60, 24);
+        assert!(wide.contains("●──"));
+    }
+
+    fn taxiing_view_model() -> FlightStatusViewModel {
+        FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            origin_airport: Some("SFO".to_string()),
+            destination_airport: Some("LAX".to_string()),
+            actual_gate_departure: Some("2025-11-16T10:00:00Z".to_string()),
             progress_percent: Some(0),
-            origin_airport: None,
-            destination_airport: None,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_taxi_wiggle_only_on_the_ground() {
+        let options = RenderOptions::default();
+        let taxiing = taxiing_view_model();
+        assert!(is_taxi_wiggling(&taxiing, &options));
+
+        let landed = FlightStatusViewModel {
+            actual_departure: Some("2025-11-16T10:10:00Z".to_string()),
+            actual_arrival: Some("2025-11-16T11:10:00Z".to_string()),
+            ..taxiing_view_model()
         };
+        assert!(is_taxi_wiggling(&landed, &options));
 
-        assert_eq!(calculate_progress(&vm), 50.0);
+        let airborne = FlightStatusViewModel {
+            actual_arrival: None,
+            ..landed
+        };
+        assert!(!is_taxi_wiggling(&airborne, &options));
+
+        let still = RenderOptions {
+            animate: false,
+            ..Default::default()
+        };
+        assert!(!is_taxi_wiggling(&taxiing, &still));
     }
 
     #[test]
-    fn test_calculate_progress_completed() {
+    fn test_render_taxi_wiggle_moves_between_frames() {
+        let render = |frame_count, animate| {
+            let options = RenderOptions {
+                frame_count,
+                animate,
+                ..Default::default()
+            };
+            render_to_string(&taxiing_view_model(), false, None, &options, 60, 24)
+        };
+
+        assert_ne!(render(0, true), render(6, true));
+        assert_eq!(render(0, false), render(6, false));
+        assert_eq!(render(0, true), render(0, false));
+    }
+
+    #[test]
+    fn test_render_help_overlay() {
+        let lines = vec![
+            "q, Esc       Quit".to_string(),
+            "r            Refresh now".to_string(),
+        ];
+
+        let backend = ratatui::backend::TestBackend::new(60, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                render_flight_status(
+                    frame,
+                    &FlightStatusViewModel::default(),
+                    false,
+                    None,
+                    &RenderOptions::default(),
+                );
+                render_help(frame, &lines);
+            })
+            .unwrap();
+
+        let rendered = buffer_text(terminal.backend().buffer());
+        assert!(rendered.contains("Keys"));
+        assert!(rendered.contains("q, Esc       Quit"));
+        assert!(rendered.contains("r            Refresh now"));
+    }
+
+    #[test]
+    fn test_render_debug_scrolls() {
+        let json = (0..50)
+            .map(|i| format!("  \"field_{}\": {}", i, i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let render = |scroll| {
+            let backend = ratatui::backend::TestBackend::new(40, 10);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| render_debug(frame, &json, scroll, None))
+                .unwrap();
+            buffer_text(terminal.backend().buffer())
+        };
+
+        let top = render(0);
+        assert!(top.contains("Raw API JSON"));
+        assert!(top.contains("\"field_0\": 0"));
+
+        let scrolled = render(20);
+        assert!(!scrolled.contains("\"field_0\": 0"));
+        assert!(scrolled.contains("\"field_20\": 20"));
+
+        // Scrolling past the end keeps the last line on screen
+        assert!(render(500).contains("\"field_49\": 49"));
+    }
+
+    #[test]
+    fn test_render_debug_shows_latency() {
+        let backend = ratatui::backend::TestBackend::new(40, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_debug(frame, "{}", 0, Some(std::time::Duration::from_millis(240))))
+            .unwrap();
+
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(text.lines().last().unwrap().contains("API 240ms"));
+    }
+
+    #[test]
+    fn test_render_footer_turns_red_when_stale() {
+        let draw = |stale| {
+            let backend = ratatui::backend::TestBackend::new(60, 24);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| {
+                    render_footer(frame, std::time::Duration::from_secs(75), stale, None);
+                })
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+
+        let fresh = draw(false);
+        let text = buffer_text(&fresh);
+        assert!(text.lines().last().unwrap().ends_with("updated 1m ago"));
+        assert_eq!(color_of(&fresh, "updated 1m ago"), Color::DarkGray);
+        assert_eq!(color_of(&draw(true), "updated 1m ago"), Color::Red);
+    }
+
+    #[test]
+    fn test_render_footer_with_latency() {
+        let backend = ratatui::backend::TestBackend::new(60, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                render_footer(
+                    frame,
+                    std::time::Duration::from_secs(75),
+                    false,
+                    Some(std::time::Duration::from_millis(240)),
+                );
+            })
+            .unwrap();
+
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(
+            text.lines()
+                .last()
+                .unwrap()
+                .ends_with("API 240ms • updated 1m ago")
+        );
+    }
+
+    #[test]
+    fn test_render_complete_footer() {
+        let backend = ratatui::backend::TestBackend::new(60, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                render_complete_footer(frame);
+            })
+            .unwrap();
+
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(
+            text.lines()
+                .last()
+                .unwrap()
+                .ends_with("Flight complete — polling stopped")
+        );
+    }
+
+    #[test]
+    fn test_render_url_footer() {
+        let url = "https://www.flightaware.com/live/flight/id/AAL100-1763277826-airline-1033p";
+        let draw = |width, latency| {
+            let backend = ratatui::backend::TestBackend::new(width, 24);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| {
+                    let footer_width =
+                        render_footer(frame, std::time::Duration::from_secs(75), false, latency);
+                    render_url_footer(frame, url, footer_width);
+                })
+                .unwrap();
+            buffer_text(terminal.backend().buffer())
+        };
+
+        let wide = draw(120, None);
+        let last_line = wide.lines().last().unwrap();
+        assert!(last_line.starts_with(&format!(" {}", url)));
+        assert!(last_line.ends_with("updated 1m ago"));
+
+        // Rather than overlapping the update age, the link is left out
+        let narrow = draw(80, None);
+        assert!(!narrow.contains("flightaware.com"));
+        assert!(narrow.lines().last().unwrap().ends_with("updated 1m ago"));
+
+        // The latency takes room from the link too
+        let latency = Some(std::time::Duration::from_millis(240));
+        let with_latency = draw(100, latency);
+        assert!(!with_latency.contains("flightaware.com"));
+        assert!(
+            with_latency
+                .lines()
+                .last()
+                .unwrap()
+                .ends_with("API 240ms • updated 1m ago")
+        );
+
+        // The completion notice is wider than the update age
+        let backend = ratatui::backend::TestBackend::new(100, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                let footer_width = render_complete_footer(frame);
+                render_url_footer(frame, url, footer_width);
+            })
+            .unwrap();
+        let complete = buffer_text(terminal.backend().buffer());
+        assert!(!complete.contains("flightaware.com"));
+        assert!(
+            complete
+                .lines()
+                .last()
+                .unwrap()
+                .ends_with("Flight complete — polling stopped")
+        );
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(std::time::Duration::from_secs(42)), "42s");
+        assert_eq!(format_age(std::time::Duration::from_secs(185)), "3m");
+        assert_eq!(format_age(std::time::Duration::from_secs(3900)), "1h 5m");
+    }
+
+    #[test]
+    fn test_render_advisory_on_bottom_row() {
+        let backend = ratatui::backend::TestBackend::new(80, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                render_flight_status(
+                    frame,
+                    &FlightStatusViewModel::default(),
+                    false,
+                    None,
+                    &RenderOptions::default(),
+                );
+                render_advisory(
+                    frame,
+                    "Prediction: likely delayed — inbound aircraft running 40m late",
+                );
+            })
+            .unwrap();
+
+        let rendered = buffer_text(terminal.backend().buffer());
+        assert!(
+            rendered
+                .lines()
+                .last()
+                .unwrap()
+                .contains("Prediction: likely delayed — inbound aircraft running 40m late")
+        );
+    }
+
+    #[test]
+    fn test_render_banner_on_top_row() {
+        let backend = ratatui::backend::TestBackend::new(60, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                render_flight_status(
+                    frame,
+                    &FlightStatusViewModel::default(),
+                    false,
+                    None,
+                    &RenderOptions::default(),
+                );
+                render_banner(frame, "API quota likely exceeded");
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rendered = buffer_text(buffer);
+        assert!(
+            rendered
+                .lines()
+                .next()
+                .unwrap()
+                .contains("API quota likely exceeded")
+        );
+        assert_eq!(buffer.content()[0].bg, Color::Red);
+    }
+
+    #[test]
+    fn test_render_baggage_claim_after_landing() {
         let vm = FlightStatusViewModel {
             flight_number: "AA100".to_string(),
-            status: FlightStatus::OnTime,
-            scheduled_departure: Some("2025-11-16T10:00:00Z".to_string()),
-            scheduled_arrival: Some("2025-11-16T14:00:00Z".to_string()),
-            estimated_departure: Some("2025-11-16T10:00:00Z".to_string()),
-            estimated_arrival: Some("2025-11-16T14:00:00Z".to_string()),
-            actual_departure: Some("2025-11-16T10:05:00Z".to_string()),
+            baggage_claim: Some("4".to_string()),
+            ..Default::default()
+        };
+        let render = |vm: &FlightStatusViewModel| {
+            render_to_string(vm, false, None, &RenderOptions::default(), 80, 24)
+        };
+
+        assert!(!render(&vm).contains("Baggage"));
+
+        let landed = FlightStatusViewModel {
             actual_arrival: Some("2025-11-16T14:10:00Z".to_string()),
-            progress_percent: Some(100),
-            origin_airport: None,
-            destination_airport: None,
+            ..vm
         };
+        assert!(render(&landed).contains("Baggage: Carousel 4"));
+    }
 
-        assert_eq!(calculate_progress(&vm), 100.0);
+    #[test]
+    fn test_render_arrival_gate_diagram_after_landing() {
+        let vm = FlightStatusViewModel {
+            flight_number: "AA100".to_string(),
+            destination_airport: Some("LAX".to_string()),
+            terminal_destination: Some("2".to_string()),
+            gate_destination: Some("B12".to_string()),
+            ..Default::default()
+        };
+        let render = |vm: &FlightStatusViewModel, options: &RenderOptions| {
+            let backend = ratatui::backend::TestBackend::new(100, 24);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| {
+                    render_flight_status(frame, vm, false, None, options);
+                    finish_frame(frame.buffer_mut(), options);
+                })
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+
+        let airborne = render(&vm, &RenderOptions::default());
+        assert!(!buffer_text(&airborne).contains("[T2·B12]"));
+
+        let landed = FlightStatusViewModel {
+            actual_arrival: Some("2025-11-16T14:10:00Z".to_string()),
+            ..vm
+        };
+        let buffer = render(&landed, &RenderOptions::default());
+        assert!(buffer_text(&buffer).contains("LAX ══[T2·B12]══"));
+        let gate_cell = cell_of(&buffer, "[T2·B12]");
+        assert_eq!(gate_cell.bg, Color::Yellow);
+
+        let ascii = RenderOptions {
+            ascii: true,
+            ..Default::default()
+        };
+        assert!(buffer_text(&render(&landed, &ascii)).contains("LAX --[T2.B12]--"));
+
+        let redacted = RenderOptions {
+            redact: true,
+            ..Default::default()
+        };
+        let redacted_text = buffer_text(&render(&landed, &redacted));
+        assert!(!redacted_text.contains("B12"));
+        assert!(redacted_text.contains("LAX ══[Gate ##]══"));
+
+        let unknown_gate = FlightStatusViewModel {
+            terminal_destination: None,
+            gate_destination: None,
+            ..landed
+        };
+        assert!(!buffer_text(&render(&unknown_gate, &RenderOptions::default())).contains("══["));
+    }
+
+    #[test]
+    fn test_local_progress_glyph() {
+        assert_eq!(local_progress_glyph(0.0), "○");
+        assert_eq!(local_progress_glyph(50.0), "◑");
+        assert_eq!(local_progress_glyph(100.0), "●");
+    }
+
+    #[test]
+    fn test_progress_color_endpoints() {
+        assert_eq!(progress_color(0.0), Color::Rgb(255, 0, 0));
+        assert_eq!(progress_color(50.0), Color::Rgb(255, 255, 0));
+        assert_eq!(progress_color(100.0), Color::Rgb(0, 255, 0));
+    }
+
+    #[test]
+    fn test_progress_color_interpolates_and_clamps() {
+        assert_eq!(progress_color(25.0), Color::Rgb(255, 128, 0));
+        assert_eq!(progress_color(75.0), Color::Rgb(128, 255, 0));
+        assert_eq!(progress_color(-10.0), Color::Rgb(255, 0, 0));
+        assert_eq!(progress_color(150.0), Color::Rgb(0, 255, 0));
     }
 }
@@ -0,0 +1,122 @@
+use crate::paths::{self, OutputError};
+use crate::ui::{BoardDensity, IdentFormat, RenderOptions};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Name of the file the state is kept in, inside the output directory
+const FILE_NAME: &str = "ui_state.json";
+
+/// The display toggles that carry over from one run to the next
+/// Fields missing from an older file take their defaults, so adding a toggle doesn't reset the rest
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiState {
+    pub ident_format: IdentFormat,
+    pub minimal: bool,
+    pub timetable: bool,
+    pub block_times: bool,
+    pub board_density: BoardDensity,
+}
+
+impl UiState {
+    pub fn from_options(options: &RenderOptions) -> Self {
+        UiState {
+            ident_format: options.ident_format,
+            minimal: options.minimal,
+            timetable: options.timetable,
+            block_times: options.block_times,
+            board_density: options.board_density,
+        }
+    }
+}
+
+/// `ui_state.json` in the --output-dir, or in the default output directory without one
+/// None when there's no directory to keep it in, in which case nothing is remembered
+pub fn path(output_dir: Option<&Path>) -> Option<PathBuf> {
+    Some(paths::state_dir(output_dir)?.join(FILE_NAME))
+}
+
+/// The state saved at `path`, or the defaults when it's missing or can't be parsed
+pub fn load(path: &Path) -> UiState {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Write `state` to `path`, creating its directory if needed
+pub fn save(path: &Path, state: &UiState) -> Result<(), OutputError> {
+    let contents = serde_json::to_string_pretty(state).map_err(|error| OutputError {
+        path: path.to_path_buf(),
+        error: std::io::Error::other(error),
+    })?;
+    paths::write_file(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("flui-ui-state-{}-{}", name, std::process::id()))
+            .join("ui_state.json")
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let state = UiState {
+            ident_format: IdentFormat::Icao,
+            minimal: true,
+            timetable: true,
+            block_times: false,
+            board_density: BoardDensity::Spacious,
+        };
+        let path = temp_path("round-trip");
+
+        save(&path, &state).unwrap();
+        assert_eq!(load(&path), state);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["ident_format"], "icao");
+        assert_eq!(json["board_density"], "spacious");
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_missing_or_corrupt_file_falls_back_to_defaults() {
+        let path = temp_path("corrupt");
+        assert_eq!(load(&path), UiState::default());
+
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{\"minimal\": tru").unwrap();
+        assert_eq!(load(&path), UiState::default());
+
+        // A file from an older version keeps what it has and defaults the rest
+        std::fs::write(&path, "{\"minimal\": true, \"retired\": 1}").unwrap();
+        assert_eq!(
+            load(&path),
+            UiState {
+                minimal: true,
+                ..Default::default()
+            }
+        );
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_from_options() {
+        let options = RenderOptions {
+            ident_format: IdentFormat::Iata,
+            block_times: true,
+            ..Default::default()
+        };
+        let state = UiState::from_options(&options);
+        assert_eq!(state.ident_format, IdentFormat::Iata);
+        assert!(state.block_times);
+        assert!(!state.minimal);
+    }
+}
@@ -0,0 +1,63 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+
+#[test]
+fn test_exits_cleanly_without_a_terminal() {
+    let flights_file =
+        std::env::temp_dir().join(format!("flui-cli-test-{}.txt", std::process::id()));
+    std::fs::write(&flights_file, "AA100\nUA200\n").unwrap();
+
+    // assert_cmd pipes stdin and stdout, so the board view has no terminal to draw on
+    cargo_bin_cmd!("flui")
+        .args(["--api-key", "test-api-key", "--flights-file"])
+        .arg(&flights_file)
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "flui requires an interactive terminal",
+        ));
+
+    std::fs::remove_file(flights_file).unwrap();
+}
+
+#[test]
+fn test_blank_api_key_reports_the_error() {
+    cargo_bin_cmd!("flui")
+        .args(["--api-key", " ", "--flight-number", "AA100"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains("FlightAware API key is empty"))
+        .stderr(predicate::str::contains("panicked").not());
+}
+
+#[cfg(feature = "httpmock")]
+#[test]
+fn test_mock_server_banner_stays_out_of_stdout() {
+    cargo_bin_cmd!("flui")
+        .args([
+            "--api-key",
+            "test-api-key",
+            "--flight-number",
+            "HAL824",
+            "--once",
+        ])
+        .assert()
+        .stdout(predicate::str::contains("Mock FlightAware server").not())
+        .stdout(predicate::str::contains("args:").not())
+        .stdout(predicate::str::contains("test-api-key").not());
+
+    cargo_bin_cmd!("flui")
+        .args([
+            "--api-key",
+            "test-api-key",
+            "--flight-number",
+            "HAL824",
+            "--once",
+            "--verbose",
+        ])
+        .assert()
+        .stdout(predicate::str::contains("Mock FlightAware server").not())
+        .stderr(predicate::str::contains("Mock FlightAware server started"));
+}